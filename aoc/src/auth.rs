@@ -0,0 +1,200 @@
+use anyhow::{anyhow, Context, Result};
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::{env, fs, io};
+
+static NON_INTERACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Error out instead of prompting on stdin for missing credentials
+pub fn set_non_interactive() {
+    NON_INTERACTIVE.store(true, Ordering::Relaxed);
+}
+
+fn non_interactive() -> bool {
+    NON_INTERACTIVE.load(Ordering::Relaxed) || env::var_os("AOC_NON_INTERACTIVE").is_some()
+}
+
+/// A credential that's needed to talk to adventofcode.com: the session cookie, or the user agent
+/// that lets them contact us if our requests cause trouble.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Credential {
+    Session,
+    UserAgent,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Source {
+    Env(&'static str),
+    File(PathBuf),
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Env(var) => write!(f, "${var}"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+impl Credential {
+    pub const ALL: [Credential; 2] = [Credential::Session, Credential::UserAgent];
+
+    fn env_var(&self) -> &'static str {
+        match self {
+            Credential::Session => "AOC_SESSION",
+            Credential::UserAgent => "AOC_USER_AGENT",
+        }
+    }
+
+    pub fn path(&self) -> Result<PathBuf> {
+        let home = dirs::home_dir().context("Unable to resolve $HOME")?;
+        Ok(match self {
+            Credential::Session => home.join(".aoc_cookie"),
+            Credential::UserAgent => home.join(".aoc_uagent"),
+        })
+    }
+
+    fn prompt(&self) -> &'static str {
+        match self {
+            Credential::Session => "Enter your advent of code session cookie",
+            Credential::UserAgent => "Enter an email account that can be used to contact you",
+        }
+    }
+
+    /// Clean up what the user typed, and check that it looks sensible
+    pub fn validate(&self, value: &str) -> Result<String> {
+        match self {
+            Credential::Session => {
+                let cookie = value.trim().replace('"', "");
+                let cookie = cookie
+                    .trim_start_matches("session")
+                    .trim_start_matches([':', '='])
+                    .trim()
+                    .to_string();
+                if cookie.len() < 100 {
+                    Err(anyhow!(
+                        "Not a valid session cookie, it should be over 100 bytes"
+                    ))
+                } else {
+                    Ok(cookie)
+                }
+            }
+            Credential::UserAgent => {
+                if !value.contains('@') {
+                    Err(anyhow!("Not a valid email account: {value}"))
+                } else {
+                    Ok(value.trim().to_string())
+                }
+            }
+        }
+    }
+
+    /// Where the value comes from, the environment takes precedence over the file in $HOME
+    pub fn lookup(&self) -> Result<Option<(String, Source)>> {
+        let var = self.env_var();
+        if let Some(value) = env::var(var).ok().filter(|v| !v.trim().is_empty()) {
+            return Ok(Some((value.trim().to_string(), Source::Env(var))));
+        }
+        let path = self.path()?;
+        Ok(fs::read_to_string(&path)
+            .ok()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .map(|value| (value, Source::File(path))))
+    }
+
+    pub fn store(&self, value: &str) -> Result<()> {
+        let value = self.validate(value)?;
+        Ok(fs::write(self.path()?, value)?)
+    }
+
+    /// Remove the stored value, returns whether there was one
+    pub fn clear(&self) -> Result<bool> {
+        let path = self.path()?;
+        if path.exists() {
+            fs::remove_file(path)?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    fn read_from_stdin(&self) -> Result<String> {
+        println!("{}", self.prompt());
+        let mut buf = String::new();
+        io::stdin().read_line(&mut buf)?;
+        self.validate(&buf)
+    }
+
+    /// Look up the value, and ask for it on stdin if it's missing, unless non-interactive
+    pub fn obtain(&self) -> Result<String> {
+        if let Some((value, _)) = self.lookup()? {
+            Ok(value)
+        } else if non_interactive() {
+            Err(anyhow!(
+                "Missing {self}: set ${} or run `aoc auth set`",
+                self.env_var()
+            ))
+        } else {
+            let value = self.read_from_stdin()?;
+            self.store(&value)?;
+            Ok(value)
+        }
+    }
+
+    /// Ask for the value on stdin and store it
+    pub fn prompt_and_store(&self) -> Result<()> {
+        if non_interactive() {
+            return Err(anyhow!(
+                "Refusing to prompt for {self} in non-interactive mode"
+            ));
+        }
+        let value = self.read_from_stdin()?;
+        self.store(&value)
+    }
+
+    /// Something that can be printed without leaking the session
+    pub fn masked(&self, value: &str) -> String {
+        match self {
+            Credential::Session => {
+                let shown: String = value.chars().take(6).collect();
+                format!("{shown}… ({} bytes)", value.len())
+            }
+            Credential::UserAgent => value.to_string(),
+        }
+    }
+}
+
+impl Display for Credential {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Credential::Session => write!(f, "session cookie"),
+            Credential::UserAgent => write!(f, "user agent"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_session() {
+        let cookie = "53616c7465645f5f".repeat(8);
+        let pasted = format!("session=\"{cookie}\"\n");
+        assert_eq!(Credential::Session.validate(&pasted).unwrap(), cookie);
+        assert_eq!(Credential::Session.validate(&cookie).unwrap(), cookie);
+        assert!(Credential::Session.validate("abc").is_err());
+    }
+
+    #[test]
+    fn test_validate_user_agent() {
+        assert_eq!(
+            Credential::UserAgent.validate("me@example.com\n").unwrap(),
+            "me@example.com"
+        );
+        assert!(Credential::UserAgent.validate("me").is_err());
+    }
+}
@@ -0,0 +1,296 @@
+use crate::{read_input, released_days, solution, stages};
+use anyhow::{Context, Result};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::Path;
+use std::time::Instant;
+
+/// Summary statistics over a set of timings, in nanoseconds
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub mean_ns: u64,
+    pub std_dev_ns: u64,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[u64]) -> Stats {
+        assert!(!samples.is_empty(), "Need at least one sample");
+        let sorted: Vec<_> = samples.iter().copied().sorted().collect();
+        let n = sorted.len();
+        let mean = sorted.iter().map(|&s| s as f64).sum::<f64>() / n as f64;
+        let variance = if n > 1 {
+            sorted
+                .iter()
+                .map(|&s| (s as f64 - mean).powi(2))
+                .sum::<f64>()
+                / (n - 1) as f64
+        } else {
+            0.0
+        };
+        let median = if n % 2 == 0 {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+        // Nearest rank
+        let p95 = sorted[((0.95 * n as f64).ceil() as usize).max(1) - 1];
+
+        Stats {
+            mean_ns: mean.round() as u64,
+            std_dev_ns: variance.sqrt().round() as u64,
+            min_ns: sorted[0],
+            median_ns: median,
+            p95_ns: p95,
+        }
+    }
+}
+
+/// Like `shared::duration_string`, but with a decimal, since benchmarks are often about
+/// small differences
+pub fn format_ns(ns: u64) -> String {
+    if ns >= 2_000_000 {
+        format!("{:.1}ms", ns as f64 / 1_000_000.0)
+    } else {
+        format!("{:.1}μs", ns as f64 / 1_000.0)
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "mean {} ± {}, min {}, median {}, p95 {}",
+            format_ns(self.mean_ns),
+            format_ns(self.std_dev_ns),
+            format_ns(self.min_ns),
+            format_ns(self.median_ns),
+            format_ns(self.p95_ns)
+        )
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartBench {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub runs: usize,
+    pub stats: Stats,
+}
+
+impl PartBench {
+    fn key(&self) -> (u16, u8, u8) {
+        (self.year, self.day, self.part)
+    }
+
+    /// `parse` or `part N`
+    fn stage(&self) -> String {
+        match self.part {
+            0 => "parse".to_string(),
+            part => format!("part {part}"),
+        }
+    }
+}
+
+impl Display for PartBench {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} day {} {} ({} runs): {}",
+            self.year,
+            self.day,
+            self.stage(),
+            self.runs,
+            self.stats
+        )
+    }
+}
+
+/// Time `runs` calls to `f`, after `warmup` calls that aren't timed
+fn sample(warmup: usize, runs: usize, mut f: impl FnMut() -> Result<()>) -> Result<Vec<u64>> {
+    for _ in 0..warmup {
+        f()?;
+    }
+    (0..runs)
+        .map(|_| {
+            let now = Instant::now();
+            f().map(|_| now.elapsed().as_nanos() as u64)
+        })
+        .try_collect()
+}
+
+/// Benchmark both parts of `day`, or just `part` if given. Solutions that parse once for both
+/// parts get their parse step benchmarked as part 0, and the parts are timed on the parsed
+/// input, like `run` reports them.
+pub fn bench_day(
+    year: u16,
+    day: u8,
+    part: Option<u8>,
+    warmup: usize,
+    runs: usize,
+) -> Result<Vec<PartBench>> {
+    let solution = solution(year, day)?;
+    let parts: Vec<_> = stages(solution)
+        .into_iter()
+        .filter(|n| part.is_none_or(|part| part == *n))
+        .collect();
+    if parts.is_empty() {
+        return Ok(vec![]);
+    }
+    let input = read_input(year, day)?;
+    let runs = runs.max(1);
+    let prepared = solution.prepare(&input)?;
+
+    parts
+        .into_iter()
+        .map(|part| {
+            let samples = if part == 0 {
+                sample(warmup, runs, || solution.prepare(&input).map(|_| ()))?
+            } else {
+                sample(warmup, runs, || prepared.part(part).map(|_| ()))?
+            };
+            Ok(PartBench {
+                year,
+                day,
+                part,
+                runs,
+                stats: Stats::from_samples(&samples),
+            })
+        })
+        .collect()
+}
+
+/// Benchmark one day, or all released days of `year`. Days run one after another, so that
+/// they don't compete for the CPU.
+pub fn bench(
+    year: u16,
+    day: Option<u8>,
+    part: Option<u8>,
+    warmup: usize,
+    runs: usize,
+) -> Result<Vec<PartBench>> {
+    let days = match day {
+        Some(day) => vec![day],
+        None => released_days(year)?,
+    };
+    let mut results = vec![];
+    for day in days {
+        results.extend(bench_day(year, day, part, warmup, runs)?);
+    }
+    Ok(results)
+}
+
+pub fn load_baseline(path: &Path) -> Result<Vec<PartBench>> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Unable to read baseline {}", path.display()))?;
+    Ok(serde_json::from_str(&content)?)
+}
+
+/// Store `results` in the baseline at `path`, replacing earlier timings for the same parts
+/// and keeping the rest, so that baselines can be built up one day at a time.
+pub fn save_baseline(path: &Path, results: &[PartBench]) -> Result<()> {
+    let existing = if path.exists() {
+        load_baseline(path)?
+    } else {
+        vec![]
+    };
+    let merged: Vec<_> = existing
+        .into_iter()
+        .chain(results.iter().cloned())
+        .map(|bench| (bench.key(), bench))
+        .collect::<HashMap<_, _>>()
+        .into_values()
+        .sorted_by_key(PartBench::key)
+        .collect();
+    Ok(fs::write(path, serde_json::to_string_pretty(&merged)?)?)
+}
+
+/// A benchmark result next to the baseline for the same part, if there is one.
+/// Medians are compared, since they're less sensitive to the odd slow run than the mean.
+pub struct Comparison {
+    pub current: PartBench,
+    pub baseline: Option<Stats>,
+}
+
+impl Comparison {
+    /// Relative change of the median, in percent
+    pub fn change(&self) -> Option<f64> {
+        self.baseline.map(|baseline| {
+            let before = baseline.median_ns.max(1) as f64;
+            (self.current.stats.median_ns as f64 - before) / before * 100.0
+        })
+    }
+
+    pub fn regressed(&self, threshold: f64) -> bool {
+        self.change().is_some_and(|change| change > threshold)
+    }
+}
+
+pub fn compare(results: Vec<PartBench>, baseline: &[PartBench]) -> Vec<Comparison> {
+    let baseline: HashMap<_, _> = baseline.iter().map(|b| (b.key(), b.stats)).collect();
+    results
+        .into_iter()
+        .map(|current| Comparison {
+            baseline: baseline.get(&current.key()).copied(),
+            current,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples: Vec<_> = (1..=20).rev().collect();
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(stats.min_ns, 1);
+        assert_eq!(stats.median_ns, 10);
+        assert_eq!(stats.p95_ns, 19);
+        assert_eq!(stats.mean_ns, 11);
+        assert_eq!(stats.std_dev_ns, 6);
+    }
+
+    #[test]
+    fn test_stats_single_sample() {
+        let stats = Stats::from_samples(&[42]);
+        assert_eq!(stats.min_ns, 42);
+        assert_eq!(stats.median_ns, 42);
+        assert_eq!(stats.p95_ns, 42);
+        assert_eq!(stats.std_dev_ns, 0);
+    }
+
+    fn part(day: u8, median_ns: u64) -> PartBench {
+        PartBench {
+            year: 2024,
+            day,
+            part: 1,
+            runs: 1,
+            stats: Stats::from_samples(&[median_ns]),
+        }
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = vec![part(1, 1000), part(2, 1000)];
+        let comparisons = compare(vec![part(1, 1050), part(2, 1200), part(3, 10)], &baseline);
+        assert_eq!(comparisons[0].change().map(|c| c.round()), Some(5.0));
+        assert!(!comparisons[0].regressed(10.0));
+        assert!(comparisons[1].regressed(10.0));
+        assert_eq!(comparisons[2].change(), None);
+        assert!(!comparisons[2].regressed(10.0));
+    }
+
+    #[test]
+    fn test_format_ns() {
+        assert_eq!(format_ns(1_500), "1.5μs");
+        assert_eq!(format_ns(12_345_678), "12.3ms");
+    }
+}
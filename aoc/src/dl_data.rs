@@ -1,100 +1,91 @@
-use crate::YEARS;
-use anyhow::{anyhow, Context, Result};
-use reqwest::blocking::{Client, ClientBuilder};
+use crate::auth::Credential;
+use crate::{check_day, day_dir, days_in, input_path, is_unlocked, unlock_time, FIRST_YEAR};
+use anyhow::{anyhow, Result};
+use reqwest::blocking::{Client, ClientBuilder, RequestBuilder};
 use std::fs::File;
 use std::io::Write;
-use std::{fs, io};
-
-fn obtain_cookie_from_disk() -> Result<Option<String>> {
-    let home = dirs::home_dir().context("Unable to resolve $HOME")?;
-    let cookie_path = home.join(".aoc_cookie");
-    Ok(fs::read_to_string(cookie_path).ok())
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use std::{env, fs, thread};
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const DEFAULT_REQUEST_INTERVAL: Duration = Duration::from_secs(1);
+
+static LAST_REQUEST: Mutex<Option<Instant>> = Mutex::new(None);
+
+/// `$AOC_BASE_URL`, so that we can point the tool at a local stub server, or adventofcode.com
+pub fn base_url() -> String {
+    env::var("AOC_BASE_URL")
+        .ok()
+        .filter(|url| !url.is_empty())
+        .map(|url| url.trim_end_matches('/').to_string())
+        .unwrap_or_else(|| DEFAULT_BASE_URL.to_string())
 }
-fn write_cookie_to_disk(cookie: &str) -> Result<()> {
-    let home = dirs::home_dir().context("Unable to resolve $HOME")?;
-    let cookie_path = home.join(".aoc_cookie");
-    Ok(fs::write(cookie_path, cookie)?)
+
+/// Minimum time between requests, `$AOC_REQUEST_INTERVAL` seconds or 1 second
+fn request_interval() -> Duration {
+    env::var("AOC_REQUEST_INTERVAL")
+        .ok()
+        .and_then(|secs| Duration::try_from_secs_f64(secs.parse().ok()?).ok())
+        .unwrap_or(DEFAULT_REQUEST_INTERVAL)
 }
 
-fn cookie_fallback() -> Result<String> {
-    println!("Enter your advent of code session cookie");
-    let mut buf = String::new();
-    let read = io::stdin().read_line(&mut buf)?;
-    if read < 100 {
-        Err(anyhow!(
-            "Not a valid session cookie, it should be over 100 bytes"
-        ))
-    } else {
-        Ok(buf.trim().replace("session:", "").replace('\"', ""))
+/// Wait until it's been long enough since the previous request. The lock is held while
+/// sleeping, so concurrent requests line up behind each other.
+fn throttle() {
+    let mut last = LAST_REQUEST.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(previous) = *last {
+        thread::sleep(request_interval().saturating_sub(previous.elapsed()));
     }
+    *last = Some(Instant::now());
 }
 
-fn obtain_cookie() -> Result<String> {
-    if let Some(cookie) = obtain_cookie_from_disk()? {
-        Ok(cookie)
+/// Send a request for `path` on the advent of code site, throttled and with the session cookie
+pub(crate) fn send(
+    request: impl FnOnce(&str) -> RequestBuilder,
+    cookie: &str,
+    path: &str,
+) -> Result<String> {
+    throttle();
+    let url = format!("{}{path}", base_url());
+    let resp = request(url.as_str())
+        .header("Cookie", format!("session={cookie}"))
+        .send()?;
+    let status = resp.status();
+    if status.is_success() {
+        Ok(resp.text()?)
     } else {
-        let cookie = cookie_fallback()?;
-        write_cookie_to_disk(cookie.as_str())?;
-        Ok(cookie)
+        Err(anyhow!("Got response {status} from {url}"))
     }
 }
 
-fn obtain_user_agent_from_disk() -> Result<Option<String>> {
-    let home = dirs::home_dir().context("Unable to resolve $HOME")?;
-    let uagent_path = home.join(".aoc_uagent");
-    Ok(fs::read_to_string(uagent_path).ok())
+pub(crate) fn get(client: &Client, cookie: &str, path: &str) -> Result<String> {
+    send(|url| client.get(url), cookie, path)
 }
 
-fn write_user_agent_to_disk(uagent: &str) -> Result<()> {
-    let home = dirs::home_dir().context("Unable to resolve $HOME")?;
-    let uagent_path = home.join(".aoc_uagent");
-    Ok(fs::write(uagent_path, uagent)?)
-}
-
-fn obtain_user_agent() -> Result<String> {
-    if let Some(agent) = obtain_user_agent_from_disk()? {
-        Ok(agent)
-    } else {
-        println!("Enter an email account that can be used to contact you");
-        let mut buf = String::new();
-        io::stdin().read_line(&mut buf)?;
-        if !buf.contains('@') {
-            Err(anyhow!("Not a valid email account: {buf}"))
-        } else {
-            write_user_agent_to_disk(buf.trim())?;
-            Ok(buf.trim().to_string())
-        }
-    }
-}
-
-fn obtain_client() -> Result<Client> {
-    let user_agent = obtain_user_agent()?;
+pub(crate) fn obtain_client() -> Result<Client> {
+    let user_agent = Credential::UserAgent.obtain()?;
     let client = ClientBuilder::new().user_agent(user_agent).build()?;
     Ok(client)
 }
 
 fn download_day(client: &Client, cookie: &str, year: u16, day: u8) -> Result<String> {
     valid_data(year, day)?;
-
-    let url = format!("https://adventofcode.com/{year}/day/{day}/input");
-    let resp = client
-        .get(url)
-        .header("Cookie", format!("session={cookie}"))
-        .send()?;
-    let status = resp.status();
-    if status.is_success() {
-        let data = resp.text()?;
-        Ok(data)
-    } else {
-        Err(anyhow!("Got response {status} from aoc"))
-    }
+    get(client, cookie, &format!("/{year}/day/{day}/input"))
 }
 
-fn valid_data(year: u16, day: u8) -> Result<()> {
-    let years: Vec<_> = YEARS.iter().map(|(y, _)| *y).collect();
-    if !((1..=25).contains(&day) && years.contains(&year)) {
+/// `day` is on the calendar for `year` and has unlocked, whether there's a solution for it or not
+pub(crate) fn valid_data(year: u16, day: u8) -> Result<()> {
+    if year < FIRST_YEAR {
+        return Err(anyhow!(
+            "Advent of Code started in {FIRST_YEAR}, got {year}"
+        ));
+    }
+    check_day(year, day)?;
+    if !is_unlocked(year, day) {
         Err(anyhow!(
-            "Day should be between 1 and 25 and year from {years:?}, got {day} and {year}"
+            "{year} day {day} unlocks at {} UTC",
+            unlock_time(year, day)?
         ))
     } else {
         Ok(())
@@ -104,30 +95,86 @@ fn valid_data(year: u16, day: u8) -> Result<()> {
 fn put_day(year: u16, day: u8, day_content: String) -> Result<()> {
     valid_data(year, day)?;
 
-    let folder = format!("./input/{year}/day_{day:0>2}");
-    let dest = format!("{folder}/input");
-    fs::create_dir_all(folder)?;
-    let mut fp = File::create(dest.as_str())?;
+    fs::create_dir_all(day_dir(year, day))?;
+    let mut fp = File::create(input_path(year, day))?;
     fp.write_all(day_content.as_bytes())?;
     Ok(())
 }
 
-pub fn single_day(year: u16, day: u8) -> Result<()> {
+/// Download the input for `day`, unless we already have it and `force` is false
+pub fn single_day(year: u16, day: u8, force: bool) -> Result<()> {
+    if !force && input_path(year, day).is_file() {
+        return Ok(());
+    }
     let client = obtain_client()?;
-    let cookie = obtain_cookie()?;
+    let cookie = Credential::Session.obtain()?;
     let content = download_day(&client, cookie.as_str(), year, day)?;
     put_day(year, day, content)
 }
 
-pub fn all_days(year: u16) -> Result<()> {
+/// Download inputs for all days that have been unlocked, skipping those we already have,
+/// unless `force` is true
+pub fn all_days(year: u16, force: bool) -> Result<()> {
+    valid_data(year, 1)?;
+    let days: Vec<_> = (1..=days_in(year))
+        .filter(|&day| is_unlocked(year, day))
+        .filter(|&day| force || !input_path(year, day).is_file())
+        .collect();
+    if days.is_empty() {
+        return Ok(());
+    }
     let client = obtain_client()?;
-    let cookie = obtain_cookie()?;
+    let cookie = Credential::Session.obtain()?;
 
-    for day in 1..=25 {
-        let day = day as u8;
+    for day in days {
         let content = download_day(&client, cookie.as_str(), year, day)?;
         put_day(year, day, content)?;
     }
 
     Ok(())
 }
+
+/// Check that the session cookie is accepted, by asking for an input that is always available
+pub fn check_session() -> Result<()> {
+    let client = obtain_client()?;
+    let cookie = Credential::Session.obtain()?;
+    download_day(&client, cookie.as_str(), 2015, 1)
+        .map(|_| ())
+        .map_err(|e| anyhow!("Session cookie was not accepted: {e}"))
+}
+
+/// Download the puzzle page for `day`. It only has part 2 once part 1 is solved, which is why
+/// it needs the session cookie.
+pub fn puzzle_page(year: u16, day: u8) -> Result<String> {
+    valid_data(year, day)?;
+    let client = obtain_client()?;
+    let cookie = Credential::Session.obtain()?;
+    get(&client, cookie.as_str(), &format!("/{year}/day/{day}"))
+}
+
+/// Download the JSON for a private leaderboard. adventofcode.com asks that this is done at most
+/// once every 15 minutes, see `leaderboard::fetch` for the caching.
+pub fn leaderboard_json(year: u16, id: u64) -> Result<String> {
+    let client = obtain_client()?;
+    let cookie = Credential::Session.obtain()?;
+    get(
+        &client,
+        cookie.as_str(),
+        &format!("/{year}/leaderboard/private/view/{id}.json"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_data() {
+        // There are no solutions for 2017, but its puzzles are there to download
+        assert!(valid_data(2017, 25).is_ok());
+        assert!(valid_data(2014, 1).is_err());
+        assert!(valid_data(2025, 13).is_err());
+        assert!(valid_data(2024, 0).is_err());
+        assert!(valid_data(9999, 1).is_err());
+    }
+}
@@ -0,0 +1,223 @@
+use crate::dl_data::leaderboard_json;
+use crate::{input_dir, unlock_time};
+use anyhow::{Context, Result};
+use itertools::Itertools;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// adventofcode.com asks that private leaderboards are fetched at most once every 15 minutes
+const CACHE_TTL: Duration = Duration::from_secs(15 * 60);
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Leaderboard {
+    pub owner_id: u64,
+    pub members: HashMap<String, Member>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u32,
+    /// Day, then part, both as strings
+    #[serde(default)]
+    pub completion_day_level: HashMap<String, HashMap<String, Star>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Star {
+    pub get_star_ts: i64,
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    /// When `part` of `day` was solved, as a unix timestamp
+    pub fn solved_at(&self, day: u8, part: u8) -> Option<i64> {
+        self.completion_day_level
+            .get(&day.to_string())?
+            .get(&part.to_string())
+            .map(|star| star.get_star_ts)
+    }
+}
+
+pub fn cache_path(year: u16, id: u64) -> PathBuf {
+    input_dir()
+        .join(year.to_string())
+        .join(format!("leaderboard_{id}.json"))
+}
+
+/// The leaderboard JSON, from the cache if it's less than 15 minutes old, and how old it is
+pub fn fetch(year: u16, id: u64) -> Result<(String, Duration)> {
+    let path = cache_path(year, id);
+    let age = fs::metadata(&path)
+        .and_then(|meta| meta.modified())
+        .ok()
+        .and_then(|modified| modified.elapsed().ok());
+    if let Some(age) = age.filter(|&age| age < CACHE_TTL) {
+        return Ok((fs::read_to_string(&path)?, age));
+    }
+
+    let json = leaderboard_json(year, id)?;
+    // Without access, we get redirected to a login page instead of the JSON
+    serde_json::from_str::<Leaderboard>(&json)
+        .with_context(|| format!("No access to leaderboard {id} for {year}?"))?;
+    fs::create_dir_all(path.parent().unwrap())?;
+    fs::write(&path, &json)?;
+    Ok((json, Duration::ZERO))
+}
+
+/// Like 1:02:03, with hours going past 24 for those who start late
+pub fn format_duration(seconds: i64) -> String {
+    let seconds = seconds.max(0);
+    format!(
+        "{}:{:0>2}:{:0>2}",
+        seconds / 3600,
+        (seconds / 60) % 60,
+        seconds % 60
+    )
+}
+
+fn ranked(leaderboard: &Leaderboard) -> Vec<&Member> {
+    leaderboard
+        .members
+        .values()
+        .sorted_by_key(|m| {
+            (
+                std::cmp::Reverse(m.local_score),
+                std::cmp::Reverse(m.stars),
+                m.id,
+            )
+        })
+        .collect()
+}
+
+/// The standings, with one column per day: `*` for both stars, `.` for just the first one
+pub fn standings(leaderboard: &Leaderboard, days: &[u8]) -> String {
+    let members = ranked(leaderboard);
+    let calendar: String = days.iter().map(|day| (day % 10).to_string()).collect();
+    let mut out = format!(
+        "{:>4} {:>6} {:>5}  {calendar}  Name\n",
+        "Rank", "Score", "Stars"
+    );
+    for (ix, member) in members.iter().enumerate() {
+        let stars: String = days
+            .iter()
+            .map(
+                |&day| match (member.solved_at(day, 1), member.solved_at(day, 2)) {
+                    (Some(_), Some(_)) => '*',
+                    (Some(_), None) => '.',
+                    _ => ' ',
+                },
+            )
+            .collect();
+        out.push_str(&format!(
+            "{:>4} {:>6} {:>5}  {stars}  {}\n",
+            ix + 1,
+            member.local_score,
+            member.stars,
+            member.display_name()
+        ));
+    }
+    out
+}
+
+/// Completion times for each day, counted from when the puzzle unlocked, and how long
+/// part 2 took after part 1
+pub fn day_times(leaderboard: &Leaderboard, year: u16, days: &[u8]) -> Result<String> {
+    let mut out = String::new();
+    for &day in days {
+        let unlocked = unlock_time(year, day)?.unix_timestamp();
+        let solved: Vec<_> = leaderboard
+            .members
+            .values()
+            .filter_map(|m| Some((m, m.solved_at(day, 1)?, m.solved_at(day, 2))))
+            .sorted_by_key(|&(m, part_1, part_2)| (part_2.is_none(), part_2, part_1, m.id))
+            .collect();
+        if solved.is_empty() {
+            continue;
+        }
+        let width = solved
+            .iter()
+            .map(|(m, _, _)| m.display_name().chars().count())
+            .max()
+            .unwrap_or_default();
+        out.push_str(&format!("\nDay {day}\n"));
+        for (member, part_1, part_2) in solved {
+            let delta = part_2
+                .map(|part_2| format!("+{}", format_duration(part_2 - part_1)))
+                .unwrap_or_default();
+            let line = format!(
+                "  {:<width$}  {:>10}  {delta}",
+                member.display_name(),
+                format_duration(part_1 - unlocked),
+            );
+            out.push_str(line.trim_end());
+            out.push('\n');
+        }
+    }
+    Ok(out)
+}
+
+pub fn render(leaderboard: &Leaderboard, year: u16, days: &[u8]) -> Result<String> {
+    Ok(standings(leaderboard, days) + &day_times(leaderboard, year, days)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2023-12-01 05:00 UTC is 1701406800
+    const JSON: &str = r#"{"owner_id":1,"event":"2023","members":{
+"1":{"id":1,"name":"Alice","stars":3,"local_score":7,"global_score":0,"last_star_ts":1701500000,
+  "completion_day_level":{"1":{"1":{"get_star_ts":1701407100,"star_index":1},
+                               "2":{"get_star_ts":1701407400,"star_index":2}},
+                          "2":{"1":{"get_star_ts":1701500000,"star_index":3}}}},
+"2":{"id":2,"name":null,"stars":1,"local_score":1,"global_score":0,"last_star_ts":1701410400,
+  "completion_day_level":{"1":{"1":{"get_star_ts":1701410400,"star_index":4}}}},
+"3":{"id":3,"name":"Bob","stars":0,"local_score":0,"global_score":0,"last_star_ts":0,
+  "completion_day_level":{}}}}"#;
+
+    #[test]
+    fn test_standings() {
+        let leaderboard: Leaderboard = serde_json::from_str(JSON).unwrap();
+        assert_eq!(
+            standings(&leaderboard, &[1, 2, 3]),
+            "Rank  Score Stars  123  Name
+   1      7     3  *.   Alice
+   2      1     1  .    (anonymous user #2)
+   3      0     0       Bob
+"
+        );
+    }
+
+    #[test]
+    fn test_day_times() {
+        let leaderboard: Leaderboard = serde_json::from_str(JSON).unwrap();
+        assert_eq!(
+            day_times(&leaderboard, 2023, &[1, 2, 3]).unwrap(),
+            "
+Day 1
+  Alice                   0:05:00  +0:05:00
+  (anonymous user #2)     1:00:00
+
+Day 2
+  Alice     1:53:20
+"
+        );
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(59), "0:00:59");
+        assert_eq!(format_duration(90061), "25:01:01");
+    }
+}
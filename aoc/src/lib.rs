@@ -1,24 +1,39 @@
 use anyhow::{anyhow, Context, Result};
 use itertools::Itertools;
 use rayon::prelude::*;
-use shared::{elapsed_string, Answer};
-use std::fs;
-use std::ops::Sub;
-use std::time::Instant;
-use time::{Date, Duration, Month, OffsetDateTime};
+use report::{PartReport, Status, YearSummary};
+use shared::{duration_string, Solution};
+use std::any::Any;
+use std::io::Read;
+use std::panic::AssertUnwindSafe;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::RecvTimeoutError;
+use std::sync::OnceLock;
+use std::sync::{mpsc, Arc};
+use std::time::{Duration, Instant};
+use std::{env, fs, io, panic, thread};
+use time::{Date, Month, OffsetDateTime};
 
+pub mod auth;
+pub mod bench;
 pub mod dl_data;
+pub mod leaderboard;
+pub mod puzzle;
+pub mod report;
+pub mod status;
+pub mod submit;
+pub mod verify;
 
-pub const YEARS: &[(u16, &[Answer])] = &[
-    (2015, &y2015::SOLUTIONS),
-    (2016, &y2016::SOLUTIONS),
-    (2018, &y2018::SOLUTIONS),
-    (2019, &y2019::SOLUTIONS),
-    (2020, &y2020::SOLUTIONS),
-    (2021, &y2021::SOLUTIONS),
-    (2022, &y2022::SOLUTIONS),
-    (2023, &y2023::SOLUTIONS),
-    (2024, &y2024::SOLUTIONS),
+pub const YEARS: &[(u16, &[&dyn Solution])] = &[
+    (2015, y2015::SOLUTIONS),
+    (2016, y2016::SOLUTIONS),
+    (2018, y2018::SOLUTIONS),
+    (2019, y2019::SOLUTIONS),
+    (2020, y2020::SOLUTIONS),
+    (2021, y2021::SOLUTIONS),
+    (2022, y2022::SOLUTIONS),
+    (2023, y2023::SOLUTIONS),
+    (2024, y2024::SOLUTIONS),
     (2025, y2025::SOLUTIONS),
 ];
 
@@ -26,87 +41,474 @@ pub fn available_years() -> Vec<u16> {
     YEARS.iter().map(|(y, _)| y).copied().sorted().collect()
 }
 
-pub fn timed_solution(year: u16, day: u8) -> Result<String> {
-    let path = format!("./input/{year}/day_{day:0>2}/input");
-    let have_it = fs::read_to_string(path.as_str());
-    let content = if let Ok(found) = have_it {
-        found
+static INPUT_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Use `dir` for inputs, instead of looking it up. Must happen before any input is read.
+pub fn set_input_dir(dir: PathBuf) -> Result<()> {
+    INPUT_DIR
+        .set(dir)
+        .map_err(|dir| anyhow!("Input directory is already {}", dir.display()))
+}
+
+/// `$AOC_INPUT_DIR` if set, otherwise `./input` if it exists, so that running from the
+/// repository keeps working, and otherwise `aoc/input` in the XDG data directory,
+/// like `~/.local/share/aoc/input`.
+fn default_input_dir() -> PathBuf {
+    let local = PathBuf::from("./input");
+    if let Some(dir) = env::var_os("AOC_INPUT_DIR") {
+        PathBuf::from(dir)
+    } else if local.is_dir() {
+        local
     } else {
-        dl_data::single_day(year, day)?;
-        fs::read_to_string(path.as_str())?
-    };
+        dirs::data_dir()
+            .map(|data| data.join("aoc").join("input"))
+            .unwrap_or(local)
+    }
+}
+
+pub fn input_dir() -> &'static Path {
+    INPUT_DIR.get_or_init(default_input_dir)
+}
+
+pub fn day_dir(year: u16, day: u8) -> PathBuf {
+    input_dir()
+        .join(year.to_string())
+        .join(format!("day_{day:0>2}"))
+}
+
+pub fn input_path(year: u16, day: u8) -> PathBuf {
+    day_dir(year, day).join("input")
+}
+
+pub fn read_input(year: u16, day: u8) -> Result<String> {
+    let path = input_path(year, day);
+    let have_it = fs::read_to_string(&path);
+    if let Ok(found) = have_it {
+        Ok(found)
+    } else {
+        dl_data::single_day(year, day, false)?;
+        Ok(fs::read_to_string(&path)?)
+    }
+}
+
+/// Days of `year` that have an input in the input directory
+pub fn cached_days(year: u16) -> Vec<u8> {
+    (1..=days_in(year))
+        .filter(|&day| input_path(year, day).is_file())
+        .collect()
+}
+
+/// Read input from a file, or from stdin if `path` is `-`
+pub fn read_input_from(path: &str) -> Result<String> {
+    if path == "-" {
+        let mut buf = String::new();
+        io::stdin().read_to_string(&mut buf)?;
+        Ok(buf)
+    } else {
+        fs::read_to_string(path).with_context(|| format!("Unable to read input from {path}"))
+    }
+}
 
-    let solution_set = &YEARS
+pub fn solutions_for(year: u16) -> Result<&'static [&'static dyn Solution]> {
+    Ok(YEARS
         .iter()
         .find(|(y, _)| *y == year)
         .with_context(|| anyhow!("No solutions for {year} yet"))?
-        .1;
+        .1)
+}
 
-    let candidate = solution_set
+pub fn solution(year: u16, day: u8) -> Result<&'static dyn Solution> {
+    solutions_for(year)?
         .iter()
-        .enumerate()
-        .find(|(day_ix, _)| (*day_ix + 1) as u8 == day)
-        .map(|(_, sol)| sol)
-        .context(format!("Error: no solution for day: {day}"))?;
-    let now = Instant::now();
+        .find(|solution| solution.day() == day)
+        .copied()
+        .context(format!("Error: no solution for day: {day}"))
+}
 
-    if let Answer::SolvedBoth { part_1, part_2, .. } = candidate {
-        let p1_sol = part_1(content.as_str())?;
-        let p1_ts = elapsed_string(now);
-        let now = Instant::now();
-        let p2_sol = part_2(content.as_str())?;
-        let p2_ts = elapsed_string(now);
-        Ok(format!(
-            "Day {day} part 1: {p1_ts}\n{p1_sol}\nDay {day} part 2: {p2_ts}\n{p2_sol}\n"
-        ))
-    } else if let Answer::SolvedOne { part_1 } = candidate {
-        let p1_sol = part_1(content.as_str())?;
-        let p1_ts = elapsed_string(now);
-
-        Ok(format!(
-            "Day {day} part 1: {p1_ts}\n{p1_sol}\nDay {day} part 2: Not implemented yet.\n"
-        ))
+/// Days of `year` that have a solution entry and have been released on adventofcode.com
+pub fn released_days(year: u16) -> Result<Vec<u8>> {
+    Ok(solutions_for(year)?
+        .iter()
+        .map(|solution| solution.day())
+        .filter(|&day| is_unlocked(year, day))
+        .sorted()
+        .collect())
+}
+
+/// Days of `year` that have been released on adventofcode.com, whether they're solved or not
+pub fn unlocked_days(year: u16) -> Vec<u8> {
+    (1..=days_in(year))
+        .filter(|&day| is_unlocked(year, day))
+        .collect()
+}
+
+/// How many puzzles the calendar has, it was cut down to 12 from 2025 on
+pub fn days_in(year: u16) -> u8 {
+    if year >= 2025 {
+        12
     } else {
-        Ok(format!("Day {day} not implemented yet\n"))
+        25
     }
 }
 
-pub fn timed_all_solutions(year: u16) -> Result<()> {
-    let now = Instant::now();
-    let today = OffsetDateTime::now_utc().sub(Duration::hours(4)).date();
+/// `day` if the calendar for `year` has it
+pub fn check_day(year: u16, day: u8) -> Result<u8> {
+    if (1..=days_in(year)).contains(&day) {
+        Ok(day)
+    } else {
+        Err(anyhow!("{year} has days 1 to {}, not {day}", days_in(year)))
+    }
+}
 
-    let solution_set = &YEARS
+/// The most recent year with solutions that has started, or the first one if none have
+pub fn default_year() -> u16 {
+    let years = available_years();
+    years
         .iter()
-        .find(|(y, _)| *y == year)
-        .with_context(|| anyhow!("No solutions for {year} yet"))?
-        .1;
+        .rev()
+        .find(|&&year| is_unlocked(year, 1))
+        .or(years.first())
+        .copied()
+        .unwrap_or(FIRST_YEAR)
+}
 
-    println!("Run all implemented solutions for {year}");
-    let mut outputs = vec![];
+pub(crate) const FIRST_YEAR: u16 = 2015;
 
-    let solution_set: Vec<_> = solution_set
-        .iter()
-        .enumerate()
-        .filter(|(day_ix, _)| {
-            Date::from_calendar_date(year as i32, Month::December, (*day_ix + 1) as u8).unwrap()
-                <= today
+/// The most recent puzzle that had unlocked at `now`, going by the calendar
+pub fn latest_unlocked_at(now: OffsetDateTime) -> Option<(u16, u8)> {
+    let unlocked = |year: u16, day: u8| unlock_time(year, day).is_ok_and(|t| t <= now);
+    (FIRST_YEAR..=now.year() as u16).rev().find_map(|year| {
+        (1..=days_in(year))
+            .rev()
+            .find(|&day| unlocked(year, day))
+            .map(|day| (year, day))
+    })
+}
+
+pub fn latest_unlocked() -> Result<(u16, u8)> {
+    latest_unlocked_at(OffsetDateTime::now_utc()).context("No puzzle has unlocked yet")
+}
+
+/// Puzzles unlock at midnight in the US east coast timezone, UTC-5 in December
+pub fn unlock_time(year: u16, day: u8) -> Result<OffsetDateTime> {
+    let date = Date::from_calendar_date(year as i32, Month::December, day)?;
+    Ok(date.with_hms(5, 0, 0)?.assume_utc())
+}
+
+pub fn is_unlocked(year: u16, day: u8) -> bool {
+    unlock_time(year, day).is_ok_and(|unlock| unlock <= OffsetDateTime::now_utc())
+}
+
+pub fn timed_solution(year: u16, day: u8) -> Result<Vec<PartReport>> {
+    solution(year, day)?;
+    let content = read_input(year, day)?;
+    timed_solution_with_input(year, day, &content)
+}
+
+/// Like `timed_solution`, but for any input, for example the examples from the puzzle text
+pub fn timed_solution_with_input(year: u16, day: u8, content: &str) -> Result<Vec<PartReport>> {
+    let solution = solution(year, day)?;
+    let parts: Vec<_> = (1..=solution.parts()).collect();
+    Ok(timed_parts(solution, &parts, content))
+}
+
+/// Run a single part, without paying for the other one. Solutions that parse once also
+/// report the parse step.
+pub fn timed_part(year: u16, day: u8, part: u8, content: &str) -> Result<Vec<PartReport>> {
+    let solution = solution(year, day)?;
+    if !(1..=solution.parts()).contains(&part) {
+        return Err(anyhow!("No solution for {year} day {day} part {part}"));
+    }
+    Ok(timed_parts(solution, &[part], content))
+}
+
+fn timed_parts(solution: &dyn Solution, parts: &[u8], content: &str) -> Vec<PartReport> {
+    let mut reports = vec![];
+    run_stages(solution, parts, content, |report| {
+        reports.push(report);
+        true
+    });
+    reports
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(msg) = payload.downcast_ref::<&str>() {
+        msg.to_string()
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg.clone()
+    } else {
+        "Panicked".to_string()
+    }
+}
+
+/// The parse step, if the solution has one of its own, and then `parts`, each of them timed
+/// and with panics caught, so that a panic only spoils the report for that part. Stops early
+/// if `report` returns false.
+fn run_stages(
+    solution: &dyn Solution,
+    parts: &[u8],
+    input: &str,
+    mut report: impl FnMut(PartReport) -> bool,
+) {
+    let (year, day) = (solution.year(), solution.day());
+    let now = Instant::now();
+    let prepared = match panic::catch_unwind(AssertUnwindSafe(|| solution.prepare(input))) {
+        Ok(prepared) => prepared.map_err(|e| (Status::Error, format!("{e:#}"))),
+        Err(payload) => Err((Status::Panic, panic_message(payload))),
+    };
+    if solution.parses_once() {
+        let parsed = match &prepared {
+            Ok(_) => PartReport::parsed(year, day, now.elapsed()),
+            Err((status, msg)) => {
+                PartReport::failed(year, day, 0, *status, msg.clone(), now.elapsed())
+            }
+        };
+        if !report(parsed) {
+            return;
+        }
+    }
+
+    for &part in parts {
+        let now = Instant::now();
+        let part_report = match &prepared {
+            Ok(prepared) => match panic::catch_unwind(AssertUnwindSafe(|| prepared.part(part))) {
+                Ok(result) => PartReport::new(year, day, part, result, now.elapsed()),
+                Err(payload) => {
+                    let msg = panic_message(payload);
+                    PartReport::failed(year, day, part, Status::Panic, msg, now.elapsed())
+                }
+            },
+            Err((status, msg)) => {
+                PartReport::failed(year, day, part, *status, msg.clone(), Duration::ZERO)
+            }
+        };
+        if !report(part_report) {
+            return;
+        }
+    }
+}
+
+/// What gets a report: the parse step as part 0, if the solution has one of its own, and the parts
+fn stages(solution: &dyn Solution) -> Vec<u8> {
+    solution
+        .parses_once()
+        .then_some(0)
+        .into_iter()
+        .chain(1..=solution.parts())
+        .collect()
+}
+
+/// Run a day on a thread of its own, so that a panic, or a part that never finishes, only
+/// spoils the reports for that day. Each stage gets `timeout` to finish, and when one times
+/// out, it's left running in the background and the rest of the day is given up on.
+fn isolated_stages(
+    solution: &'static dyn Solution,
+    input: Arc<str>,
+    timeout: Option<Duration>,
+) -> Vec<PartReport> {
+    let (year, day) = (solution.year(), solution.day());
+    let parts: Vec<_> = (1..=solution.parts()).collect();
+    let stages = stages(solution);
+    let (tx, rx) = mpsc::channel();
+    let spawned = thread::Builder::new()
+        .name(format!("{year} day {day}"))
+        // Same as the main thread, some solutions recurse deeply
+        .stack_size(8 * 1024 * 1024)
+        .spawn(move || run_stages(solution, &parts, &input, |report| tx.send(report).is_ok()));
+    let given_up = |status: Status, msg: String, elapsed: Duration| {
+        stages
+            .iter()
+            .map(|&part| PartReport::failed(year, day, part, status, msg.clone(), elapsed))
+            .collect::<Vec<_>>()
+    };
+    if let Err(e) = spawned {
+        return given_up(Status::Error, e.to_string(), Duration::ZERO);
+    }
+
+    let mut reports = vec![];
+    while reports.len() < stages.len() {
+        let received = match timeout {
+            Some(timeout) => rx.recv_timeout(timeout),
+            None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+        let failed = match received {
+            Ok(report) => {
+                reports.push(report);
+                continue;
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                let timeout = timeout.unwrap_or_default();
+                let msg = format!("Gave up after {}", duration_string(timeout));
+                given_up(Status::Timeout, msg, timeout)
+            }
+            Err(RecvTimeoutError::Disconnected) => {
+                let msg = "Thread exited without an answer".to_string();
+                given_up(Status::Panic, msg, Duration::ZERO)
+            }
+        };
+        reports.extend(failed.into_iter().skip(reports.len()));
+    }
+    reports
+}
+
+fn isolated_day(year: u16, day: u8, timeout: Option<Duration>) -> Vec<PartReport> {
+    let Ok(solution) = solution(year, day) else {
+        return vec![];
+    };
+
+    match read_input(year, day) {
+        Ok(input) => isolated_stages(solution, input.into(), timeout),
+        Err(e) => missing_input(solution, &e),
+    }
+}
+
+/// Every stage fails when there's no input to run it on
+fn missing_input(solution: &dyn Solution, e: &anyhow::Error) -> Vec<PartReport> {
+    let (year, day) = (solution.year(), solution.day());
+    stages(solution)
+        .into_iter()
+        .map(|part| {
+            PartReport::failed(
+                year,
+                day,
+                part,
+                Status::Error,
+                format!("{e:#}"),
+                Duration::ZERO,
+            )
         })
-        .collect();
+        .collect()
+}
 
-    solution_set
+/// Run all released days of `year` in parallel. Every part is isolated from the others,
+/// so errors, panics and parts running longer than `timeout` show up in the reports
+/// instead of stopping the run.
+pub fn timed_all_solutions(year: u16, timeout: Option<Duration>) -> Result<Vec<PartReport>> {
+    let mut outputs = vec![];
+
+    released_days(year)?
         .into_par_iter()
-        .map(|(day_ix, _)| (day_ix + 1, timed_solution(year, (day_ix + 1) as u8)))
+        .map(|day| (day, isolated_day(year, day, timeout)))
         .collect_into_vec(&mut outputs);
 
-    let ts = elapsed_string(now);
-    let r: Result<Vec<_>> = outputs
+    Ok(outputs
         .into_iter()
         .sorted_by_key(|tup| tup.0)
-        .map(|tup| tup.1)
-        .collect();
-    let r = r?;
-    print!("{}", r.join(""));
+        .flat_map(|tup| tup.1)
+        .collect())
+}
 
-    println!("All implemented solutions took: {}", ts);
-    Ok(())
+/// Run every registered year, one year at a time, returning totals for each year and all the parts
+pub fn timed_all_years(timeout: Option<Duration>) -> Result<(Vec<YearSummary>, Vec<PartReport>)> {
+    let mut summaries = vec![];
+    let mut reports = vec![];
+    for year in available_years() {
+        let now = Instant::now();
+        let year_reports = timed_all_solutions(year, timeout)?;
+        summaries.push(YearSummary::new(year, &year_reports, now.elapsed()));
+        reports.extend(year_reports);
+    }
+    Ok((summaries, reports))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::{AnswerValue, Day, Owned, Parsed};
+
+    fn answers(input: &str) -> Result<AnswerValue> {
+        Ok(input.len().into())
+    }
+
+    fn panics(_input: &str) -> Result<AnswerValue> {
+        panic!("boom")
+    }
+
+    fn sleeps(_input: &str) -> Result<AnswerValue> {
+        thread::sleep(Duration::from_secs(5));
+        Ok("Too late".into())
+    }
+
+    fn length(input: &str) -> Result<usize> {
+        Ok(input.len())
+    }
+
+    fn double(length: &usize) -> Result<AnswerValue> {
+        Ok((length * 2).into())
+    }
+
+    static ISOLATED: Day = Day::both(2015, 1, panics, answers);
+    static SLOW: Day = Day::both(2015, 2, sleeps, answers);
+    static PARSED: Parsed<Owned<usize>> = Parsed::<Owned<usize>>::one(2015, 3, length, double);
+
+    #[test]
+    fn test_sparse_days() {
+        assert_eq!(released_days(2016).unwrap(), (1..=15).collect::<Vec<_>>());
+        assert!(solution(2016, 16).is_err());
+        assert_eq!(solution(2025, 12).unwrap().parts(), 1);
+        assert_eq!(unlocked_days(2016), (1..=25).collect::<Vec<_>>());
+        assert_eq!(unlocked_days(2017).len(), 25);
+        assert!(released_days(2017).is_err());
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        let expected = OffsetDateTime::from_unix_timestamp(1701406800).unwrap();
+        assert_eq!(unlock_time(2023, 1).unwrap(), expected);
+        assert!(is_unlocked(2015, 25));
+        assert!(!is_unlocked(2015, 32));
+    }
+
+    #[test]
+    fn test_calendar() {
+        let at = |unix| OffsetDateTime::from_unix_timestamp(unix).unwrap();
+        // 2023-12-01 05:00 UTC
+        assert_eq!(latest_unlocked_at(at(1701406800)), Some((2023, 1)));
+        assert_eq!(latest_unlocked_at(at(1701406799)), Some((2022, 25)));
+        // 2026-01-01, after the 12 days of 2025
+        assert_eq!(latest_unlocked_at(at(1767225600)), Some((2025, 12)));
+        assert_eq!(latest_unlocked_at(at(0)), None);
+        assert_eq!(check_day(2024, 25).unwrap(), 25);
+        assert!(check_day(2025, 13).is_err());
+        assert!(available_years().contains(&default_year()));
+    }
+
+    #[test]
+    fn test_isolated_stages() {
+        let input: Arc<str> = "abc".into();
+
+        let reports = isolated_stages(&ISOLATED, input.clone(), None);
+        assert_eq!(reports[0].status, Status::Panic);
+        assert_eq!(reports[0].error.as_deref(), Some("boom"));
+        assert_eq!(reports[1].status, Status::Ok);
+        assert_eq!(reports[1].answer, Some(AnswerValue::Integer(3)));
+
+        let reports = isolated_stages(&SLOW, input.clone(), Some(Duration::from_millis(50)));
+        assert_eq!(reports.len(), 2);
+        assert!(reports.iter().all(|r| r.status == Status::Timeout));
+
+        let reports = isolated_stages(&PARSED, input, None);
+        let parts: Vec<_> = reports.iter().map(|r| r.part).collect();
+        assert_eq!(parts, vec![0, 1]);
+        assert_eq!(reports[0].answer, None);
+        assert_eq!(reports[1].answer, Some(AnswerValue::Integer(6)));
+    }
+
+    #[test]
+    fn test_missing_input() {
+        let e = anyhow!("No input");
+        let parts = |reports: Vec<PartReport>| reports.iter().map(|r| r.part).collect::<Vec<_>>();
+        assert_eq!(parts(missing_input(&ISOLATED, &e)), vec![1, 2]);
+        let reports = missing_input(&PARSED, &e);
+        assert!(reports.iter().all(|r| r.status == Status::Error));
+        assert_eq!(parts(reports), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_timed_part_reports_parse() {
+        let reports = timed_parts(&PARSED, &[1], "abcd");
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0].status, Status::Ok);
+        assert_eq!(reports[1].answer, Some(AnswerValue::Integer(8)));
+    }
 }
@@ -1,69 +1,499 @@
-use anyhow::Result;
-use aoc::{available_years, dl_data, timed_all_solutions, timed_solution};
-use clap::{arg, value_parser, Command};
+use anyhow::{anyhow, Context, Result};
+use aoc::auth::{self, Credential};
+use aoc::bench::PartBench;
+use aoc::leaderboard::{self, Leaderboard};
+use aoc::puzzle::{self, puzzle_title, Style};
+use aoc::report::{self, render, year_table, Format, PartReport, YearSummary};
+use aoc::{
+    available_years, bench, cached_days, check_day, days_in, default_year, dl_data, input_dir,
+    latest_unlocked, read_input, read_input_from, set_input_dir, status, submit,
+    timed_all_solutions, timed_all_years, timed_part, timed_solution, timed_solution_with_input,
+    unlocked_days, verify,
+};
+use clap::parser::ValueSource;
+use clap::{arg, value_parser, ArgAction, ArgMatches, Command};
+use shared::{duration_string, elapsed_string};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// A day of the calendar, or `today` for the most recent puzzle
+#[derive(Debug, Copy, Clone)]
+enum DayArg {
+    Today,
+    Day(u8),
+}
+
+fn parse_day(arg: &str) -> Result<DayArg, String> {
+    if arg == "today" {
+        return Ok(DayArg::Today);
+    }
+    let max_day = available_years().into_iter().map(days_in).max().unwrap();
+    match arg.parse() {
+        Ok(day) if (1..=max_day).contains(&day) => Ok(DayArg::Day(day)),
+        _ => Err(format!("expected today or a day from 1 to {max_day}")),
+    }
+}
 
 fn cli() -> Command {
-    let max_solution: i64 = 25;
+    let max_day = available_years().into_iter().map(days_in).max().unwrap() as i64;
     let ymin = *available_years().iter().min().unwrap() as i64;
     let ymax = *available_years().iter().max().unwrap() as i64;
     let year_arg = arg!([year] "Which year of advent of code")
-        .default_value("2023")
+        .default_value(default_year().to_string())
         .value_parser(value_parser!(u16).range(ymin..=ymax));
-    let day_arg = arg!(<day> "Day number of the advent calendar")
+    // Inputs can be downloaded for any year on the calendar, not just those with solutions,
+    // so the downloader checks the year instead
+    let calendar_year_arg = arg!([year] "Which year of advent of code")
+        .default_value(default_year().to_string())
+        .value_parser(value_parser!(u16));
+    let day_arg = arg!(<day> "Day number of the advent calendar, or today for the latest puzzle")
         .required(true)
-        .value_parser(value_parser!(u8).range(1..=max_solution));
+        .value_parser(parse_day);
+    let format_arg = arg!(--format <FORMAT> "Output format")
+        .value_parser(Format::NAMES)
+        .default_value("text");
+    let force_arg =
+        arg!(--force "Download again, even if we already have it").action(ArgAction::SetTrue);
+    let part_arg =
+        arg!(--part <PART> "Only run this part").value_parser(value_parser!(u8).range(1..=2));
+    let optional_day_arg = arg!([day] "Day number of the advent calendar, all days if omitted")
+        .value_parser(value_parser!(u8).range(1..=max_day));
 
     Command::new("aoc")
         .about("Advent of Code toolset")
         .subcommand_required(true)
         .arg_required_else_help(true)
+        .arg(
+            arg!(--"input-dir" <DIR> "Where to keep inputs, overrides $AOC_INPUT_DIR")
+                .value_parser(value_parser!(PathBuf))
+                .global(true),
+        )
+        .arg(
+            arg!(--"non-interactive" "Fail instead of prompting for credentials")
+                .action(ArgAction::SetTrue)
+                .global(true),
+        )
         .subcommand(
             Command::new("day-data")
-                .about("Get data for day (dump to input/year/day_nn/input")
+                .about("Get data for day (dump to input/year/day_nn/input)")
                 .arg(day_arg.clone())
-                .arg(year_arg.clone()),
+                .arg(calendar_year_arg.clone())
+                .arg(force_arg.clone()),
         )
         .subcommand(
             Command::new("data")
-                .about("Get data for all days")
-                .arg(year_arg.clone()),
+                .about("Get data for all unlocked days")
+                .arg(calendar_year_arg)
+                .arg(force_arg),
         )
         .subcommand(
             Command::new("run")
-                .about("Run solution, both parts, with timing")
-                .arg(day_arg)
-                .arg(year_arg.clone()),
+                .about("Run solution, both parts or just one, with timing")
+                .arg(day_arg.clone())
+                .arg(year_arg.clone())
+                .arg(format_arg.clone())
+                .arg(arg!(--input <FILE> "Read input from FILE, - for stdin"))
+                .arg(part_arg.clone()),
         )
         .subcommand(
             Command::new("runall")
                 .about("Run all known solutions, with individual and total timing")
-                .arg(year_arg),
+                .arg(year_arg.clone())
+                .arg(format_arg)
+                .arg(
+                    arg!(--"all-years" "Run every registered year, and summarize per year")
+                        .action(ArgAction::SetTrue),
+                )
+                .arg(
+                    arg!(--slowest <N> "With --all-years, list the N slowest parts")
+                        .value_parser(value_parser!(usize))
+                        .default_value("10"),
+                )
+                .arg(
+                    arg!(--timeout <SECONDS> "Give up on parts that run for longer than this")
+                        .value_parser(value_parser!(f64)),
+                ),
+        )
+        .subcommand(
+            Command::new("submit")
+                .about("Compute the answer for one part and submit it to adventofcode.com")
+                .arg(day_arg.clone())
+                .arg(year_arg.clone())
+                .arg(part_arg.clone().required(true)),
+        )
+        .subcommand(
+            Command::new("puzzle")
+                .about("Download the puzzle description, and store it next to the input")
+                .arg(day_arg)
+                .arg(year_arg.clone())
+                .arg(
+                    arg!(--format <FORMAT> "Render the description as")
+                        .value_parser(Style::NAMES)
+                        .default_value("markdown"),
+                ),
+        )
+        .subcommand(
+            Command::new("leaderboard")
+                .about("Show a private leaderboard, fetched at most every 15 minutes")
+                .arg(
+                    arg!(<id> "Id of the leaderboard, the number at the end of its URL")
+                        .value_parser(value_parser!(u64)),
+                )
+                .arg(year_arg.clone())
+                .arg(
+                    arg!(--json "Print the JSON from adventofcode.com").action(ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Command::new("auth")
+                .about("Manage the session cookie and user agent for adventofcode.com")
+                .subcommand_required(true)
+                .subcommand(
+                    Command::new("set")
+                        .about("Store credentials in $HOME, prompts for those not given")
+                        .arg(arg!(--session <COOKIE> "Session cookie"))
+                        .arg(arg!(--"user-agent" <EMAIL> "Contact email for the user agent")),
+                )
+                .subcommand(Command::new("show").about("Show credentials and where they come from"))
+                .subcommand(Command::new("clear").about("Remove stored credentials"))
+                .subcommand(
+                    Command::new("check").about("Check that adventofcode.com accepts the session"),
+                ),
         )
+        .subcommand(
+            Command::new("inputs")
+                .about("List the input directory and which inputs are downloaded")
+                .arg(
+                    arg!([year] "Which year of advent of code, all years if omitted")
+                        .value_parser(value_parser!(u16).range(ymin..=ymax)),
+                ),
+        )
+        .subcommand(
+            Command::new("status")
+                .about("Show solved days, downloaded inputs and known answers for every year"),
+        )
+        .subcommand(
+            Command::new("verify")
+                .about("Check answers against input/year/day_nn/answers, for one day or all days")
+                .arg(year_arg.clone())
+                .arg(optional_day_arg.clone())
+                .arg(
+                    arg!(--save "Record answers for parts that have no known answer yet")
+                        .action(ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Command::new("bench")
+                .about("Benchmark solutions, one part at a time, with warmup and repetitions")
+                .arg(year_arg)
+                .arg(optional_day_arg)
+                .arg(part_arg)
+                .arg(
+                    arg!(--warmup <N> "Runs to discard before measuring")
+                        .value_parser(value_parser!(usize))
+                        .default_value("3"),
+                )
+                .arg(
+                    arg!(--runs <N> "Measured runs per part")
+                        .value_parser(value_parser!(usize))
+                        .default_value("20"),
+                )
+                .arg(
+                    arg!(--"save-baseline" <FILE> "Store timings in FILE, for later comparison")
+                        .value_parser(value_parser!(PathBuf)),
+                )
+                .arg(
+                    arg!(--baseline <FILE> "Compare timings to a saved baseline")
+                        .value_parser(value_parser!(PathBuf)),
+                )
+                .arg(
+                    arg!(--threshold <PERCENT> "Flag parts this much slower than the baseline")
+                        .value_parser(value_parser!(f64))
+                        .default_value("10"),
+                ),
+        )
+}
+
+/// The year and day to work on. `today` is the latest puzzle in the calendar, which picks
+/// the year too.
+fn year_and_day(sub_matches: &ArgMatches) -> Result<(u16, u8)> {
+    let year = *sub_matches.get_one::<u16>("year").unwrap();
+    match sub_matches.get_one::<DayArg>("day").unwrap() {
+        DayArg::Today if sub_matches.value_source("year") == Some(ValueSource::CommandLine) => Err(
+            anyhow!("today is the latest puzzle, which decides the year"),
+        ),
+        DayArg::Today => latest_unlocked(),
+        &DayArg::Day(day) => Ok((year, check_day(year, day)?)),
+    }
+}
+
+/// The year, and the day if one was given, which must be in the calendar for that year
+fn year_and_optional_day(sub_matches: &ArgMatches) -> Result<(u16, Option<u8>)> {
+    let year = *sub_matches.get_one::<u16>("year").unwrap();
+    let day = sub_matches
+        .get_one::<u8>("day")
+        .map(|&day| check_day(year, day))
+        .transpose()?;
+    Ok((year, day))
+}
+
+fn format(sub_matches: &ArgMatches) -> Result<Format> {
+    sub_matches.get_one::<String>("format").unwrap().parse()
+}
+
+fn timeout(sub_matches: &ArgMatches) -> Result<Option<Duration>> {
+    sub_matches
+        .get_one::<f64>("timeout")
+        .map(|&secs| Duration::try_from_secs_f64(secs))
+        .transpose()
+        .context("Invalid --timeout")
+}
+
+fn check_errors(reports: &[PartReport]) -> Result<()> {
+    let failed = reports.iter().filter(|r| r.error.is_some()).count();
+    if failed > 0 {
+        Err(anyhow!("{failed} part(s) failed"))
+    } else {
+        Ok(())
+    }
+}
+
+fn compare_to_baseline(
+    results: Vec<PartBench>,
+    baseline: &[PartBench],
+    threshold: f64,
+) -> Result<()> {
+    let comparisons = bench::compare(results, baseline);
+    for comparison in comparisons.iter() {
+        match comparison.change() {
+            Some(change) if comparison.regressed(threshold) => {
+                println!("{} ({change:+.1}%, SLOWER)", comparison.current)
+            }
+            Some(change) => println!("{} ({change:+.1}%)", comparison.current),
+            None => println!("{} (no baseline)", comparison.current),
+        }
+    }
+    let (before, after) = comparisons
+        .iter()
+        .filter_map(|c| c.baseline.map(|b| (b.median_ns, c.current.stats.median_ns)))
+        .fold((0, 0), |(before, after), (b, a)| (before + b, after + a));
+    println!(
+        "Sum of medians for parts in baseline: {} -> {}",
+        bench::format_ns(before),
+        bench::format_ns(after)
+    );
+
+    let regressed = comparisons
+        .iter()
+        .filter(|c| c.regressed(threshold))
+        .count();
+    if regressed > 0 {
+        Err(anyhow!(
+            "{regressed} part(s) got more than {threshold}% slower than the baseline"
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn print_all_years(summaries: &[YearSummary], reports: &[PartReport], slowest: usize) {
+    print!("{}", year_table(summaries));
+    println!();
+    println!("Slowest {slowest} parts:");
+    for (rank, r) in report::slowest(reports, slowest).into_iter().enumerate() {
+        let ts = duration_string(r.duration());
+        println!(
+            "{:>3}. {} day {} {}: {ts}",
+            rank + 1,
+            r.year,
+            r.day,
+            r.stage()
+        );
+    }
+    for r in reports.iter().filter(|r| r.error.is_some()) {
+        let e = r.error.as_deref().unwrap_or_default();
+        println!("Error in {} day {} {}: {e}", r.year, r.day, r.stage());
+    }
+}
+
+fn auth_command(matches: &ArgMatches) -> Result<()> {
+    match matches.subcommand() {
+        Some(("set", sub_matches)) => {
+            for (credential, arg) in Credential::ALL.into_iter().zip(["session", "user-agent"]) {
+                match sub_matches.get_one::<String>(arg) {
+                    Some(value) => credential.store(value)?,
+                    None => credential.prompt_and_store()?,
+                }
+            }
+            Ok(())
+        }
+        Some(("show", _)) => {
+            for credential in Credential::ALL {
+                match credential.lookup()? {
+                    Some((value, source)) => {
+                        println!("{credential}: {} from {source}", credential.masked(&value))
+                    }
+                    None => println!("{credential}: not set"),
+                }
+            }
+            Ok(())
+        }
+        Some(("clear", _)) => {
+            for credential in Credential::ALL {
+                if credential.clear()? {
+                    println!("Removed {}", credential.path()?.display());
+                }
+            }
+            Ok(())
+        }
+        Some(("check", _)) => {
+            dl_data::check_session()?;
+            println!("Session cookie is valid");
+            Ok(())
+        }
+        _ => unreachable!(),
+    }
 }
 
 fn main() -> Result<()> {
     let matches = cli().get_matches();
+    if let Some(dir) = matches.get_one::<PathBuf>("input-dir") {
+        set_input_dir(dir.clone())?;
+    }
+    if matches.get_flag("non-interactive") {
+        auth::set_non_interactive();
+    }
 
     match matches.subcommand() {
         Some(("day-data", sub_matches)) => {
-            let day = *sub_matches.get_one::<u8>("day").unwrap();
-            let year = *sub_matches.get_one::<u16>("year").unwrap();
-            dl_data::single_day(year, day)
+            let (year, day) = year_and_day(sub_matches)?;
+            dl_data::single_day(year, day, sub_matches.get_flag("force"))
         }
         Some(("data", sub_matches)) => {
             let year = *sub_matches.get_one::<u16>("year").unwrap();
-            dl_data::all_days(year)
+            dl_data::all_days(year, sub_matches.get_flag("force"))
         }
         Some(("run", sub_matches)) => {
-            let day = *sub_matches.get_one::<u8>("day").unwrap();
+            let (year, day) = year_and_day(sub_matches)?;
+            let format = format(sub_matches)?;
+            let input = match sub_matches.get_one::<String>("input") {
+                Some(path) => Some(read_input_from(path)?),
+                None => None,
+            };
+            let reports = match (sub_matches.get_one::<u8>("part"), input) {
+                (Some(&part), Some(input)) => timed_part(year, day, part, &input)?,
+                (Some(&part), None) => timed_part(year, day, part, &read_input(year, day)?)?,
+                (None, Some(input)) => timed_solution_with_input(year, day, &input)?,
+                (None, None) => timed_solution(year, day)?,
+            };
+            if format == Format::Text {
+                if let Some(title) = puzzle_title(year, day) {
+                    println!("--- Day {day}: {title} ---");
+                }
+            }
+            print!("{}", render(&reports, format)?);
+            check_errors(&reports)
+        }
+        Some(("runall", sub_matches)) if sub_matches.get_flag("all-years") => {
+            let format = format(sub_matches)?;
+            let slowest = *sub_matches.get_one::<usize>("slowest").unwrap();
+            let (summaries, reports) = timed_all_years(timeout(sub_matches)?)?;
+            if format == Format::Text {
+                print_all_years(&summaries, &reports, slowest);
+            } else {
+                print!("{}", render(&reports, format)?);
+            }
+            check_errors(&reports)
+        }
+        Some(("runall", sub_matches)) => {
             let year = *sub_matches.get_one::<u16>("year").unwrap();
-            let rep = timed_solution(year, day)?;
-            println!("{}", rep);
+            let format = format(sub_matches)?;
+            let now = Instant::now();
+            if format == Format::Text {
+                println!("Run all implemented solutions for {year}");
+            }
+            let reports = timed_all_solutions(year, timeout(sub_matches)?)?;
+            let ts = elapsed_string(now);
+            print!("{}", render(&reports, format)?);
+            if format == Format::Text {
+                println!("All implemented solutions took: {}", ts);
+            }
+            check_errors(&reports)
+        }
+        Some(("submit", sub_matches)) => {
+            let (year, day) = year_and_day(sub_matches)?;
+            let part = *sub_matches.get_one::<u8>("part").unwrap();
+            let outcome = submit::submit(year, day, part)?;
+            println!("{outcome}");
             Ok(())
         }
-        Some(("runall", sub_matches)) => {
+        Some(("puzzle", sub_matches)) => {
+            let (year, day) = year_and_day(sub_matches)?;
+            let style = sub_matches.get_one::<String>("format").unwrap().parse()?;
+            print!("{}", puzzle::fetch_puzzle(year, day, style)?);
+            Ok(())
+        }
+        Some(("leaderboard", sub_matches)) => {
+            let id = *sub_matches.get_one::<u64>("id").unwrap();
             let year = *sub_matches.get_one::<u16>("year").unwrap();
-            timed_all_solutions(year)
+            let (json, age) = leaderboard::fetch(year, id)?;
+            if sub_matches.get_flag("json") {
+                println!("{json}");
+            } else {
+                let board: Leaderboard = serde_json::from_str(&json)?;
+                println!(
+                    "Leaderboard {id} for {year}, fetched {} minutes ago\n",
+                    age.as_secs() / 60
+                );
+                print!(
+                    "{}",
+                    leaderboard::render(&board, year, &unlocked_days(year))?
+                );
+            }
+            Ok(())
+        }
+        Some(("auth", sub_matches)) => auth_command(sub_matches),
+        Some(("inputs", sub_matches)) => {
+            let years = match sub_matches.get_one::<u16>("year") {
+                Some(&year) => vec![year],
+                None => available_years(),
+            };
+            println!("Inputs in {}", input_dir().display());
+            for year in years {
+                let days = cached_days(year);
+                println!("{year}: {} days {days:?}", days.len());
+            }
+            Ok(())
+        }
+        Some(("status", _)) => {
+            println!("Inputs in {}\n", input_dir().display());
+            print!("{}", status::render());
+            Ok(())
+        }
+        Some(("verify", sub_matches)) => {
+            let (year, day) = year_and_optional_day(sub_matches)?;
+            let save = sub_matches.get_flag("save");
+            verify::verify(year, day, save)
+        }
+        Some(("bench", sub_matches)) => {
+            let (year, day) = year_and_optional_day(sub_matches)?;
+            let warmup = *sub_matches.get_one::<usize>("warmup").unwrap();
+            let runs = *sub_matches.get_one::<usize>("runs").unwrap();
+            let part = sub_matches.get_one::<u8>("part").copied();
+            let results = bench::bench(year, day, part, warmup, runs)?;
+            if let Some(path) = sub_matches.get_one::<PathBuf>("save-baseline") {
+                bench::save_baseline(path, &results)?;
+            }
+            if let Some(path) = sub_matches.get_one::<PathBuf>("baseline") {
+                let threshold = *sub_matches.get_one::<f64>("threshold").unwrap();
+                let baseline = bench::load_baseline(path)?;
+                compare_to_baseline(results, &baseline, threshold)
+            } else {
+                for result in results {
+                    println!("{result}");
+                }
+                Ok(())
+            }
         }
         _ => unreachable!(),
     }
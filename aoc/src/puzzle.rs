@@ -0,0 +1,435 @@
+use crate::dl_data::{base_url, puzzle_page};
+use crate::{day_dir, solution};
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+use regex::Regex;
+use shared::examples::{format_answers, Example};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// The handful of HTML we need to deal with on the puzzle pages, which is just a few tags
+/// without much nesting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Token<'a> {
+    Open(&'a str, &'a str),
+    Close(&'a str),
+    Text(String),
+}
+
+pub(crate) fn decode_entities(text: &str) -> String {
+    let numeric = Regex::new(r"&#(x?)([0-9a-fA-F]+);").unwrap();
+    let text = numeric.replace_all(text, |caps: &regex::Captures| {
+        let radix = if caps[1].is_empty() { 10 } else { 16 };
+        u32::from_str_radix(&caps[2], radix)
+            .ok()
+            .and_then(char::from_u32)
+            .map(String::from)
+            .unwrap_or_default()
+    });
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+pub(crate) fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+    while !rest.is_empty() {
+        if let Some(after_lt) = rest.strip_prefix('<') {
+            let Some((tag, after)) = after_lt.split_once('>') else {
+                tokens.push(Token::Text(decode_entities(rest)));
+                break;
+            };
+            if let Some(name) = tag.strip_prefix('/') {
+                tokens.push(Token::Close(name.trim()));
+            } else if !tag.starts_with('!') {
+                let tag = tag.trim_end_matches('/').trim();
+                let (name, attributes) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+                tokens.push(Token::Open(name, attributes));
+            }
+            rest = after;
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            tokens.push(Token::Text(decode_entities(&rest[..end])));
+            rest = &rest[end..];
+        }
+    }
+    tokens
+}
+
+/// The `<article>` elements of a puzzle page, one for each part that's unlocked
+pub fn articles(html: &str) -> Vec<&str> {
+    let mut found = vec![];
+    let mut rest = html;
+    while let Some(start) = rest.find("<article") {
+        let Some(len) = rest[start..].find("</article>") else {
+            break;
+        };
+        let end = start + len + "</article>".len();
+        found.push(&rest[start..end]);
+        rest = &rest[end..];
+    }
+    found
+}
+
+/// The title of the puzzle, like `Not Quite Lisp`
+pub fn title(html: &str) -> Option<String> {
+    let heading = Regex::new(r"<h2[^>]*>--- Day \d+: (.*?) ---</h2>").unwrap();
+    heading.captures(html).map(|caps| decode_entities(&caps[1]))
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Style {
+    Markdown,
+    Text,
+}
+
+impl Style {
+    pub const NAMES: [&'static str; 2] = ["markdown", "text"];
+
+    fn file_name(&self) -> &'static str {
+        match self {
+            Style::Markdown => "puzzle.md",
+            Style::Text => "puzzle.txt",
+        }
+    }
+}
+
+impl FromStr for Style {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "markdown" => Ok(Style::Markdown),
+            "text" => Ok(Style::Text),
+            _ => Err(anyhow!(
+                "Unknown format {s}, expected one of {:?}",
+                Style::NAMES
+            )),
+        }
+    }
+}
+
+fn push_text(out: &mut String, text: &str) {
+    let collapsed = text.split_whitespace().join(" ");
+    let leading = text.starts_with(char::is_whitespace);
+    let trailing = text.ends_with(char::is_whitespace) && !collapsed.is_empty();
+    let at_line_start = out.is_empty() || out.ends_with('\n');
+    if leading && !at_line_start && !out.ends_with(' ') {
+        out.push(' ');
+    }
+    out.push_str(&collapsed);
+    if trailing {
+        out.push(' ');
+    }
+}
+
+fn start_block(out: &mut String) {
+    while out.ends_with(' ') {
+        out.pop();
+    }
+}
+
+/// Render an article as Markdown or plain text. Code blocks are kept verbatim, since they're
+/// usually the examples.
+pub fn render(article: &str, style: Style) -> String {
+    let md = style == Style::Markdown;
+    let mut out = String::new();
+    let mut in_pre = false;
+    let mut in_code = false;
+    let mut links = vec![];
+    let href_attribute = Regex::new(r#"href="([^"]*)""#).unwrap();
+
+    for token in tokenize(article) {
+        match token {
+            Token::Open("h2", _) => {
+                start_block(&mut out);
+                if md {
+                    out.push_str("## ");
+                }
+            }
+            Token::Open("p", _) | Token::Open("ul", _) => start_block(&mut out),
+            Token::Close("h2") | Token::Close("p") | Token::Close("ul") => out.push_str("\n\n"),
+            Token::Open("pre", _) => {
+                start_block(&mut out);
+                if md {
+                    out.push_str("```\n");
+                }
+                in_pre = true;
+            }
+            Token::Close("pre") => {
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                if md {
+                    out.push_str("```\n");
+                }
+                out.push('\n');
+                in_pre = false;
+            }
+            Token::Open("code", _) if !in_pre => {
+                in_code = true;
+                if md {
+                    out.push('`');
+                }
+            }
+            Token::Close("code") if !in_pre => {
+                in_code = false;
+                if md {
+                    out.push('`');
+                }
+            }
+            Token::Open("em", _) | Token::Close("em") if md && !in_pre && !in_code => out.push('*'),
+            Token::Open("li", _) => {
+                start_block(&mut out);
+                out.push_str("- ");
+            }
+            Token::Close("li") => {
+                start_block(&mut out);
+                out.push('\n');
+            }
+            Token::Open("a", attributes) => {
+                let href = href_attribute
+                    .captures(attributes)
+                    .map(|caps| caps[1].to_string());
+                links.push(href);
+                if md {
+                    out.push('[');
+                }
+            }
+            Token::Close("a") => {
+                let href = links.pop().flatten();
+                match href {
+                    Some(href) if md && href.starts_with('/') => {
+                        out.push_str(&format!("]({}{href})", base_url()))
+                    }
+                    Some(href) if md => out.push_str(&format!("]({href})")),
+                    _ if md => out.push(']'),
+                    _ => {}
+                }
+            }
+            Token::Text(text) if in_pre => out.push_str(&text),
+            Token::Text(text) => push_text(&mut out, &text),
+            _ => {}
+        }
+    }
+
+    out.lines()
+        .map(str::trim_end)
+        .coalesce(|a, b| {
+            if a.is_empty() && b.is_empty() {
+                Ok(a)
+            } else {
+                Err((a, b))
+            }
+        })
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+/// Pick the examples out of the articles, which is every `<pre><code>` block. The answer to an
+/// example is the last emphasized `<code>` that follows it, so for part 2 it's the answer for
+/// the latest example in the description, which is usually the one from part 1.
+pub fn extract_examples(articles: &[&str]) -> Vec<Example> {
+    let mut examples: Vec<Example> = vec![];
+    let mut pre: Option<String> = None;
+    let mut code: Option<(String, bool)> = None;
+    let mut in_em = false;
+
+    for (ix, article) in articles.iter().take(2).enumerate() {
+        for token in tokenize(article) {
+            match (token, pre.as_mut(), code.as_mut()) {
+                (Token::Open("pre", _), _, _) => pre = Some(String::new()),
+                (Token::Close("pre"), Some(input), _) => {
+                    examples.push(Example {
+                        input: std::mem::take(input),
+                        answers: Default::default(),
+                    });
+                    pre = None;
+                }
+                (Token::Text(text), Some(input), _) => input.push_str(&text),
+                (Token::Open("code", _), None, _) => code = Some((String::new(), in_em)),
+                (Token::Close("code"), None, Some((answer, true))) => {
+                    if let Some(example) = examples.last_mut() {
+                        example.answers[ix] = Some(answer.trim().to_string());
+                    }
+                    code = None;
+                }
+                (Token::Close("code"), None, _) => code = None,
+                (Token::Open("em", _), None, Some((_, emphasized))) => *emphasized = true,
+                (Token::Open("em", _), _, _) => in_em = true,
+                (Token::Close("em"), _, _) => in_em = false,
+                (Token::Text(text), None, Some((answer, _))) => answer.push_str(&text),
+                _ => {}
+            }
+        }
+    }
+    examples
+}
+
+/// Write the examples to `example_N`, and the answers to `example_N.answer`, counting from 1
+pub fn save_examples(year: u16, day: u8, examples: &[Example]) -> Result<()> {
+    write_examples(&day_dir(year, day), examples)
+}
+
+/// Replaces all the examples in `dir`, so none are left over from an earlier, longer list
+fn write_examples(dir: &Path, examples: &[Example]) -> Result<()> {
+    fs::create_dir_all(dir)?;
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_name().to_string_lossy().starts_with("example_") {
+            fs::remove_file(entry.path())?;
+        }
+    }
+    for (n, example) in (1..).zip(examples) {
+        fs::write(dir.join(format!("example_{n}")), &example.input)?;
+        if example.answers.iter().any(Option::is_some) {
+            fs::write(
+                dir.join(format!("example_{n}.answer")),
+                format_answers(&example.answers),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// The articles from the puzzle page, as they were downloaded
+pub fn puzzle_html_path(year: u16, day: u8) -> PathBuf {
+    day_dir(year, day).join("puzzle.html")
+}
+
+/// The title of the puzzle, from the solution if it knows it, or from the downloaded puzzle
+pub fn puzzle_title(year: u16, day: u8) -> Option<String> {
+    solution(year, day)
+        .ok()
+        .and_then(|s| s.title())
+        .map(str::to_string)
+        .or_else(|| title(&fs::read_to_string(puzzle_html_path(year, day)).ok()?))
+}
+
+/// Download the puzzle description, and store it next to the input, both as the original
+/// HTML and rendered in `style`, along with the examples
+pub fn fetch_puzzle(year: u16, day: u8, style: Style) -> Result<String> {
+    let page = puzzle_page(year, day)?;
+    let found = articles(&page);
+    if found.is_empty() {
+        return Err(anyhow!("No puzzle description for {year} day {day}"));
+    }
+    fs::create_dir_all(day_dir(year, day))?;
+    fs::write(puzzle_html_path(year, day), found.join("\n"))?;
+    save_examples(year, day, &extract_examples(&found))?;
+
+    let rendered = found.iter().map(|a| render(a, style)).join("\n\n") + "\n";
+    fs::write(day_dir(year, day).join(style.file_name()), &rendered)?;
+    Ok(rendered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Not Quite Lisp ---</h2><p>Santa was hoping for a white Christmas, but his <a href="/2015/about">weather machine</a> is broken.</p>
+<p>For example:</p>
+<ul>
+<li><code>(())</code> and <code>()()</code> both result in floor <code>0</code>.</li>
+<li><code>)))</code> results in floor <code>-3</code>.</li>
+</ul>
+<pre><code>1 &lt; 2
+  <em>3</em>
+</code></pre>
+<p>To <em>what floor</em> do the instructions take Santa? The answer is <code><em>0</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>74</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Now find the <em>position</em>.</p>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn test_articles_and_title() {
+        let found = articles(PAGE);
+        assert_eq!(found.len(), 2);
+        assert!(found[1].contains("Part Two"));
+        assert_eq!(title(PAGE), Some("Not Quite Lisp".to_string()));
+        assert_eq!(
+            puzzle_title(2016, 1),
+            Some("No Time for a Taxicab".to_string())
+        );
+    }
+
+    #[test]
+    fn test_render_markdown() {
+        let md = render(articles(PAGE)[0], Style::Markdown);
+        assert_eq!(
+            md,
+            "## --- Day 1: Not Quite Lisp ---
+
+Santa was hoping for a white Christmas, but his [weather machine](https://adventofcode.com/2015/about) is broken.
+
+For example:
+
+- `(())` and `()()` both result in floor `0`.
+- `)))` results in floor `-3`.
+
+```
+1 < 2
+  3
+```
+
+To *what floor* do the instructions take Santa? The answer is `0`."
+        );
+    }
+
+    #[test]
+    fn test_extract_examples() {
+        let examples = extract_examples(&articles(PAGE));
+        assert_eq!(
+            examples,
+            vec![Example {
+                input: "1 < 2\n  3\n".to_string(),
+                answers: [Some("0".to_string()), None],
+            }]
+        );
+        let part_2 =
+            "<article><p>With the same example, it's <em><code>12</code></em>.</p></article>";
+        let examples = extract_examples(&[articles(PAGE)[0], part_2]);
+        assert_eq!(examples[0].answer(1), Some("0"));
+        assert_eq!(examples[0].answer(2), Some("12"));
+    }
+
+    #[test]
+    fn test_write_examples_removes_stale_ones() {
+        let dir = std::env::temp_dir().join(format!("aoc-examples-{}", std::process::id()));
+        let example = |input: &str, answer: Option<&str>| Example {
+            input: input.to_string(),
+            answers: [answer.map(str::to_string), None],
+        };
+        write_examples(
+            &dir,
+            &[example("(())", Some("0")), example(")))", Some("-3"))],
+        )
+        .unwrap();
+        fs::write(dir.join("input"), "()").unwrap();
+        write_examples(&dir, &[example("()()", None)]).unwrap();
+
+        let files: Vec<_> = fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .sorted()
+            .collect();
+        assert_eq!(files, vec!["example_1", "input"]);
+        assert_eq!(fs::read_to_string(dir.join("example_1")).unwrap(), "()()");
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_render_text() {
+        let text = render(articles(PAGE)[1], Style::Text);
+        assert_eq!(text, "--- Part Two ---\n\nNow find the position.");
+    }
+}
@@ -0,0 +1,344 @@
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+use serde::Serialize;
+use shared::{duration_string, AnswerValue};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Status {
+    Ok,
+    Error,
+    Panic,
+    Timeout,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Ok => write!(f, "OK"),
+            Status::Error => write!(f, "ERROR"),
+            Status::Panic => write!(f, "PANIC"),
+            Status::Timeout => write!(f, "TIMEOUT"),
+        }
+    }
+}
+
+/// The outcome of running one part of one day. Part 0 is the parse step, for solutions
+/// that parse once for both parts.
+#[derive(Debug, Clone, Serialize)]
+pub struct PartReport {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub status: Status,
+    pub answer: Option<AnswerValue>,
+    pub duration_ns: u64,
+    pub error: Option<String>,
+}
+
+impl PartReport {
+    pub fn new(
+        year: u16,
+        day: u8,
+        part: u8,
+        result: Result<AnswerValue>,
+        elapsed: Duration,
+    ) -> Self {
+        match result {
+            Ok(answer) => PartReport {
+                year,
+                day,
+                part,
+                status: Status::Ok,
+                answer: Some(answer),
+                duration_ns: elapsed.as_nanos() as u64,
+                error: None,
+            },
+            Err(e) => PartReport::failed(year, day, part, Status::Error, format!("{e:#}"), elapsed),
+        }
+    }
+
+    pub fn parsed(year: u16, day: u8, elapsed: Duration) -> Self {
+        PartReport {
+            year,
+            day,
+            part: 0,
+            status: Status::Ok,
+            answer: None,
+            duration_ns: elapsed.as_nanos() as u64,
+            error: None,
+        }
+    }
+
+    pub fn failed(
+        year: u16,
+        day: u8,
+        part: u8,
+        status: Status,
+        error: String,
+        elapsed: Duration,
+    ) -> Self {
+        PartReport {
+            year,
+            day,
+            part,
+            status,
+            answer: None,
+            duration_ns: elapsed.as_nanos() as u64,
+            error: Some(error),
+        }
+    }
+
+    pub fn timed(year: u16, day: u8, part: u8, f: impl FnOnce() -> Result<AnswerValue>) -> Self {
+        let now = Instant::now();
+        let result = f();
+        PartReport::new(year, day, part, result, now.elapsed())
+    }
+
+    /// `parse` or `part N`
+    pub fn stage(&self) -> String {
+        match self.part {
+            0 => "parse".to_string(),
+            part => format!("part {part}"),
+        }
+    }
+
+    pub fn duration(&self) -> Duration {
+        Duration::from_nanos(self.duration_ns)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl Format {
+    pub const NAMES: [&'static str; 3] = ["text", "json", "csv"];
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(anyhow!(
+                "Unknown format {s}, expected one of {:?}",
+                Format::NAMES
+            )),
+        }
+    }
+}
+
+fn text(reports: &[PartReport]) -> String {
+    reports
+        .iter()
+        .map(|r| {
+            let ts = duration_string(r.duration());
+            let stage = r.stage();
+            match (&r.answer, &r.error) {
+                (Some(answer), _) => format!("Day {} {stage}: {ts}\n{answer}\n", r.day),
+                (None, Some(e)) => format!("Day {} {stage}: {ts}\n{}: {e}\n", r.day, r.status),
+                (None, None) => format!("Day {} {stage}: {ts}\n", r.day),
+            }
+        })
+        .join("")
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn csv(reports: &[PartReport]) -> String {
+    let rows = reports.iter().map(|r| {
+        [
+            r.year.to_string(),
+            r.day.to_string(),
+            r.part.to_string(),
+            r.status.to_string(),
+            csv_field(
+                &r.answer
+                    .as_ref()
+                    .map(ToString::to_string)
+                    .unwrap_or_default(),
+            ),
+            r.duration_ns.to_string(),
+            csv_field(r.error.as_deref().unwrap_or_default()),
+        ]
+        .join(",")
+    });
+    std::iter::once("year,day,part,status,answer,duration_ns,error".to_string())
+        .chain(rows)
+        .map(|row| row + "\n")
+        .join("")
+}
+
+pub fn render(reports: &[PartReport], format: Format) -> Result<String> {
+    match format {
+        Format::Text => Ok(text(reports)),
+        Format::Json => Ok(serde_json::to_string_pretty(reports)? + "\n"),
+        Format::Csv => Ok(csv(reports)),
+    }
+}
+
+/// Totals for one year of a run over several years
+#[derive(Debug, Clone, Serialize)]
+pub struct YearSummary {
+    pub year: u16,
+    pub parts: usize,
+    pub errors: usize,
+    /// Sum of the time spent in each part
+    pub total_ns: u64,
+    /// Wall clock time for the whole year, with days running in parallel
+    pub elapsed_ns: u64,
+}
+
+impl YearSummary {
+    pub fn new(year: u16, reports: &[PartReport], elapsed: Duration) -> Self {
+        YearSummary {
+            year,
+            parts: reports.iter().filter(|r| r.part > 0).count(),
+            errors: reports.iter().filter(|r| r.error.is_some()).count(),
+            total_ns: reports.iter().map(|r| r.duration_ns).sum(),
+            elapsed_ns: elapsed.as_nanos() as u64,
+        }
+    }
+}
+
+pub fn year_table(summaries: &[YearSummary]) -> String {
+    let row = |year: &str, parts: usize, errors: usize, total_ns: u64, elapsed_ns: u64| {
+        format!(
+            "{year:<6}{parts:>6}{errors:>8}{:>14}{:>12}\n",
+            duration_string(Duration::from_nanos(total_ns)),
+            duration_string(Duration::from_nanos(elapsed_ns))
+        )
+    };
+    let header = format!(
+        "{:<6}{:>6}{:>8}{:>14}{:>12}\n",
+        "Year", "Parts", "Errors", "Sum of parts", "Wall time"
+    );
+    let rows = summaries.iter().map(|s| {
+        row(
+            &s.year.to_string(),
+            s.parts,
+            s.errors,
+            s.total_ns,
+            s.elapsed_ns,
+        )
+    });
+    let total = row(
+        "Total",
+        summaries.iter().map(|s| s.parts).sum(),
+        summaries.iter().map(|s| s.errors).sum(),
+        summaries.iter().map(|s| s.total_ns).sum(),
+        summaries.iter().map(|s| s.elapsed_ns).sum(),
+    );
+    std::iter::once(header).chain(rows).chain([total]).join("")
+}
+
+pub fn slowest(reports: &[PartReport], n: usize) -> Vec<&PartReport> {
+    reports
+        .iter()
+        .sorted_by_key(|r| std::cmp::Reverse(r.duration_ns))
+        .take(n)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Vec<PartReport> {
+        vec![
+            PartReport {
+                year: 2021,
+                day: 13,
+                part: 1,
+                status: Status::Ok,
+                answer: Some(AnswerValue::Integer(735)),
+                duration_ns: 323_000,
+                error: None,
+            },
+            PartReport {
+                year: 2021,
+                day: 13,
+                part: 2,
+                status: Status::Ok,
+                answer: Some(AnswerValue::from(vec![
+                    "#  #".to_string(),
+                    "\"##\"".to_string(),
+                ])),
+                duration_ns: 364_000,
+                error: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!(
+            render(&example(), Format::Text).unwrap(),
+            "Day 13 part 1: 323μs\n735\nDay 13 part 2: 364μs\n#  #\n\"##\"\n"
+        );
+    }
+
+    #[test]
+    fn test_text_parse_step() {
+        let reports = vec![
+            PartReport::parsed(2023, 5, Duration::from_micros(120)),
+            example().remove(0),
+        ];
+        assert_eq!(
+            render(&reports, Format::Text).unwrap(),
+            "Day 5 parse: 120μs\nDay 13 part 1: 323μs\n735\n"
+        );
+        let summary = YearSummary::new(2023, &reports, Duration::from_micros(500));
+        assert_eq!(summary.parts, 1);
+        assert_eq!(summary.total_ns, 443_000);
+    }
+
+    #[test]
+    fn test_csv_quotes_multiline_answers() {
+        assert_eq!(
+            render(&example(), Format::Csv).unwrap(),
+            "year,day,part,status,answer,duration_ns,error
+2021,13,1,OK,735,323000,
+2021,13,2,OK,\"#  #\n\"\"##\"\"\",364000,
+"
+        );
+    }
+
+    #[test]
+    fn test_year_summary() {
+        let reports = example();
+        let summary = YearSummary::new(2021, &reports, Duration::from_micros(400));
+        assert_eq!(summary.parts, 2);
+        assert_eq!(summary.errors, 0);
+        assert_eq!(summary.total_ns, 687_000);
+        assert_eq!(slowest(&reports, 1)[0].part, 2);
+    }
+
+    #[test]
+    fn test_json() {
+        let json: serde_json::Value =
+            serde_json::from_str(&render(&example(), Format::Json).unwrap()).unwrap();
+        assert_eq!(json[0]["answer"], 735);
+        assert_eq!(json[1]["answer"][1], "\"##\"");
+        assert_eq!(json[1]["duration_ns"], 364_000);
+        assert!(json[1]["error"].is_null());
+        assert_eq!(json[1]["status"], "OK");
+    }
+}
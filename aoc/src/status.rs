@@ -0,0 +1,135 @@
+use crate::verify::known_answers;
+use crate::{days_in, input_path, YEARS};
+use itertools::Itertools;
+
+/// What we have for one day of the calendar, without running anything
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct DayStatus {
+    pub day: u8,
+    /// Parts with a solution, 0 when the day isn't solved yet
+    pub parts: u8,
+    pub input: bool,
+    /// Solved parts that have a known answer to verify against
+    pub verified: u8,
+}
+
+impl DayStatus {
+    fn stars(&self) -> &'static str {
+        match self.parts {
+            0 => "  ",
+            1 => "* ",
+            _ => "**",
+        }
+    }
+
+    fn input(&self) -> char {
+        if self.input {
+            '#'
+        } else {
+            ' '
+        }
+    }
+
+    fn verified(&self) -> char {
+        match self.verified {
+            0 => ' ',
+            n if n < self.parts => '.',
+            _ => 'v',
+        }
+    }
+}
+
+pub fn year_status(year: u16, solutions: &[&dyn shared::Solution]) -> Vec<DayStatus> {
+    (1..=days_in(year))
+        .map(|day| {
+            let parts = solutions
+                .iter()
+                .find(|s| s.day() == day)
+                .map_or(0, |s| s.parts());
+            let verified = known_answers(year, day)
+                .iter()
+                .take(parts as usize)
+                .filter(|answer| answer.is_some())
+                .count() as u8;
+            DayStatus {
+                day,
+                parts,
+                input: input_path(year, day).is_file(),
+                verified,
+            }
+        })
+        .collect()
+}
+
+/// One block per year, with a column per day: solved parts as stars, `#` when the input is
+/// downloaded, and `v` when every solved part has a known answer (`.` if only some do)
+pub fn render_year(year: u16, days: &[DayStatus]) -> String {
+    let stars: u32 = days.iter().map(|d| d.parts as u32).sum();
+    let inputs = days.iter().filter(|d| d.input).count();
+    let verified = days
+        .iter()
+        .filter(|d| d.parts > 0 && d.verified == d.parts)
+        .count();
+    let row = |label: &str, cells: String| format!("  {label:<8}{cells}").trim_end().to_string();
+    [
+        format!(
+            "{year}: {stars}/{} stars, {inputs} inputs, {verified} days verified",
+            days.len() * 2
+        ),
+        row("day", days.iter().map(|d| format!("{:>3}", d.day)).join("")),
+        row(
+            "stars",
+            days.iter().map(|d| format!(" {}", d.stars())).join(""),
+        ),
+        row(
+            "input",
+            days.iter().map(|d| format!("  {}", d.input())).join(""),
+        ),
+        row(
+            "verify",
+            days.iter().map(|d| format!("  {}", d.verified())).join(""),
+        ),
+    ]
+    .iter()
+    .map(|line| format!("{line}\n"))
+    .join("")
+}
+
+/// The status of every year in `aoc::YEARS`
+pub fn render() -> String {
+    YEARS
+        .iter()
+        .sorted_by_key(|(year, _)| *year)
+        .map(|&(year, solutions)| render_year(year, &year_status(year, solutions)))
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_year() {
+        let status = |day, parts, input, verified| DayStatus {
+            day,
+            parts,
+            input,
+            verified,
+        };
+        let days = [
+            status(1, 2, true, 2),
+            status(2, 2, true, 1),
+            status(3, 1, false, 0),
+            status(4, 0, false, 0),
+        ];
+        assert_eq!(
+            render_year(2016, &days),
+            "2016: 5/8 stars, 2 inputs, 1 days verified
+  day       1  2  3  4
+  stars    ** ** *
+  input     #  #
+  verify    v  .
+"
+        );
+    }
+}
@@ -0,0 +1,238 @@
+use crate::auth::Credential;
+use crate::dl_data::{obtain_client, send, valid_data};
+use crate::verify::{known_answers, save_answers};
+use crate::{day_dir, read_input, timed_part};
+use anyhow::{anyhow, Context, Result};
+use itertools::Itertools;
+use regex::Regex;
+use shared::AnswerValue;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::PathBuf;
+
+/// What adventofcode.com said about a submitted answer
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Outcome {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    RateLimited(String),
+    AlreadySolved,
+    Unrecognized(String),
+}
+
+impl Outcome {
+    fn name(&self) -> &'static str {
+        match self {
+            Outcome::Right => "right",
+            Outcome::Wrong => "wrong",
+            Outcome::TooHigh => "too high",
+            Outcome::TooLow => "too low",
+            Outcome::RateLimited(_) => "rate limited",
+            Outcome::AlreadySolved => "already solved",
+            Outcome::Unrecognized(_) => "unrecognized",
+        }
+    }
+
+    /// The outcomes that say something about the answer, and are worth remembering
+    fn from_name(name: &str) -> Option<Outcome> {
+        match name {
+            "right" => Some(Outcome::Right),
+            "wrong" => Some(Outcome::Wrong),
+            "too high" => Some(Outcome::TooHigh),
+            "too low" => Some(Outcome::TooLow),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::RateLimited(wait) => write!(f, "rate limited, {wait} left to wait"),
+            Outcome::Unrecognized(text) => write!(f, "unrecognized response: {text}"),
+            _ => write!(f, "{}", self.name()),
+        }
+    }
+}
+
+fn article_text(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map(|(article, _)| article)
+        .unwrap_or(html);
+    let tags = Regex::new(r"<[^>]*>").unwrap();
+    tags.replace_all(article, "").split_whitespace().join(" ")
+}
+
+pub fn parse_outcome(html: &str) -> Outcome {
+    let text = article_text(html);
+    if text.contains("That's the right answer") {
+        Outcome::Right
+    } else if text.contains("That's not the right answer") {
+        if text.contains("too high") {
+            Outcome::TooHigh
+        } else if text.contains("too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Wrong
+        }
+    } else if text.contains("You gave an answer too recently") {
+        let wait = Regex::new(r"You have (.+?) left to wait")
+            .unwrap()
+            .captures(&text)
+            .map(|caps| caps[1].to_string())
+            .unwrap_or_else(|| "some time".to_string());
+        Outcome::RateLimited(wait)
+    } else if text.contains("You don't seem to be solving the right level") {
+        Outcome::AlreadySolved
+    } else {
+        Outcome::Unrecognized(text)
+    }
+}
+
+/// Every answer that has been submitted, one per line: `part<TAB>outcome<TAB>answer`
+pub fn submissions_path(year: u16, day: u8) -> PathBuf {
+    day_dir(year, day).join("submissions")
+}
+
+pub fn parse_submissions(content: &str) -> Vec<(u8, Outcome, String)> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let (part, outcome, answer) = line.splitn(3, '\t').collect_tuple()?;
+            Some((
+                part.parse().ok()?,
+                Outcome::from_name(outcome)?,
+                answer.to_string(),
+            ))
+        })
+        .collect()
+}
+
+fn submissions(year: u16, day: u8) -> Vec<(u8, Outcome, String)> {
+    fs::read_to_string(submissions_path(year, day))
+        .map(|content| parse_submissions(&content))
+        .unwrap_or_default()
+}
+
+fn record_submission(year: u16, day: u8, part: u8, outcome: &Outcome, answer: &str) -> Result<()> {
+    let mut content = fs::read_to_string(submissions_path(year, day)).unwrap_or_default();
+    content.push_str(&format!("{part}\t{}\t{answer}\n", outcome.name()));
+    Ok(fs::write(submissions_path(year, day), content)?)
+}
+
+/// Refuse answers that we already know are wrong, or can tell are wrong from earlier hints
+pub fn check_previous(
+    previous: &[(u8, Outcome, String)],
+    part: u8,
+    answer: &AnswerValue,
+) -> Result<()> {
+    let number = answer.as_integer();
+    for (_, outcome, earlier) in previous.iter().filter(|(p, _, _)| *p == part) {
+        let earlier_number = AnswerValue::parse_like(earlier, answer).as_integer();
+        let known_bad = match (outcome, number, earlier_number) {
+            (Outcome::Right, _, _) => {
+                return Err(anyhow!("Part {part} was already solved with {earlier}"))
+            }
+            (_, _, _) if *earlier == answer.to_string() => true,
+            (Outcome::TooHigh, Some(n), Some(limit)) => n >= limit,
+            (Outcome::TooLow, Some(n), Some(limit)) => n <= limit,
+            _ => false,
+        };
+        if known_bad {
+            return Err(anyhow!(
+                "Not submitting {answer}, {earlier} was {}",
+                outcome.name()
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Compute the answer for `part` and submit it, unless it's known to be wrong. The outcome is
+/// recorded next to the input, and right answers also go in the known answers for `verify`.
+pub fn submit(year: u16, day: u8, part: u8) -> Result<Outcome> {
+    valid_data(year, day)?;
+    let input = read_input(year, day)?;
+    let report = timed_part(year, day, part, &input)?
+        .pop()
+        .context("No report for the part")?;
+    let answer = match (report.answer, report.error) {
+        (Some(answer), _) => answer,
+        (None, error) => return Err(anyhow!("Part {part} failed: {}", error.unwrap_or_default())),
+    };
+    if answer.is_multiline() || answer.to_string().is_empty() {
+        return Err(anyhow!(
+            "Refusing to submit an answer that isn't a single line:\n{answer}"
+        ));
+    }
+    println!("{year} day {day} part {part}: {answer}");
+    check_previous(&submissions(year, day), part, &answer)?;
+    let answer = answer.to_string();
+
+    let client = obtain_client()?;
+    let cookie = Credential::Session.obtain()?;
+    let level = part.to_string();
+    let html = send(
+        |url| {
+            client
+                .post(url)
+                .form(&[("level", level.as_str()), ("answer", answer.as_str())])
+        },
+        &cookie,
+        &format!("/{year}/day/{day}/answer"),
+    )?;
+
+    let outcome = parse_outcome(&html);
+    if Outcome::from_name(outcome.name()).is_some() {
+        record_submission(year, day, part, &outcome, &answer)?;
+    }
+    if outcome == Outcome::Right {
+        let mut known = known_answers(year, day);
+        known[(part - 1) as usize] = Some(answer);
+        save_answers(year, day, &known)?;
+    }
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_outcome() {
+        let right = "<main><article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article></main>";
+        assert_eq!(parse_outcome(right), Outcome::Right);
+        let high = "<article><p>That's not the right answer; your answer is too high. Please wait one minute.</p></article>";
+        assert_eq!(parse_outcome(high), Outcome::TooHigh);
+        let low = "<article><p>That's not the right answer; your answer is too low.</p></article>";
+        assert_eq!(parse_outcome(low), Outcome::TooLow);
+        let wrong = "<article><p>That's not the right answer. If you're stuck...</p></article>";
+        assert_eq!(parse_outcome(wrong), Outcome::Wrong);
+        let limited = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 34s left to wait.</p></article>";
+        assert_eq!(
+            parse_outcome(limited),
+            Outcome::RateLimited("34s".to_string())
+        );
+        let solved = "<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>";
+        assert_eq!(parse_outcome(solved), Outcome::AlreadySolved);
+    }
+
+    #[test]
+    fn test_check_previous() {
+        let previous = parse_submissions("1\ttoo high\t100\n1\twrong\t42\n2\tright\t7\n");
+        assert_eq!(previous.len(), 3);
+        let check = |part, answer: i32| check_previous(&previous, part, &answer.into());
+        assert!(check(1, 42).is_err());
+        assert!(check(1, 150).is_err());
+        assert!(check(1, 99).is_ok());
+        assert!(check(2, 8).is_err());
+        // A code made of digits isn't compared as a number
+        let code = AnswerValue::from("150");
+        assert!(check_previous(&previous, 1, &code).is_ok());
+    }
+}
@@ -0,0 +1,216 @@
+use crate::{day_dir, read_input, released_days, solution};
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+use rayon::prelude::*;
+pub use shared::examples::{format_answers, normalize, parse_answers};
+use shared::AnswerValue;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::PathBuf;
+
+/// Known answers live next to the input, in the format of `shared::examples::parse_answers`
+pub fn answers_path(year: u16, day: u8) -> PathBuf {
+    day_dir(year, day).join("answers")
+}
+
+pub fn known_answers(year: u16, day: u8) -> [Option<String>; 2] {
+    fs::read_to_string(answers_path(year, day))
+        .map(|content| parse_answers(&content))
+        .unwrap_or_default()
+}
+
+pub(crate) fn save_answers(year: u16, day: u8, answers: &[Option<String>; 2]) -> Result<()> {
+    Ok(fs::write(answers_path(year, day), format_answers(answers))?)
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Unknown,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Pass => write!(f, "PASS"),
+            Status::Fail => write!(f, "FAIL"),
+            Status::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+pub struct Verdict {
+    pub year: u16,
+    pub day: u8,
+    /// 0 when the whole day failed before any part ran
+    pub part: u8,
+    pub status: Status,
+    pub expected: Option<AnswerValue>,
+    pub actual: Result<AnswerValue>,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Verdict {
+            year, day, part, ..
+        } = self;
+        if *part == 0 {
+            write!(f, "{year} day {day}: {}", self.status)?;
+        } else {
+            write!(f, "{year} day {day} part {part}: {}", self.status)?;
+        }
+        match (&self.actual, &self.expected) {
+            (Err(e), _) => write!(f, " error: {e}"),
+            (Ok(actual), Some(expected)) if self.status == Status::Fail => {
+                write!(f, "\nexpected:\n{expected}\ngot:\n{actual}")
+            }
+            (Ok(actual), None) if actual.is_multiline() => write!(f, " got:\n{actual}"),
+            (Ok(actual), None) => write!(f, " got: {actual}"),
+            _ => Ok(()),
+        }
+    }
+}
+
+pub fn verify_day(year: u16, day: u8) -> Result<Vec<Verdict>> {
+    let solution = solution(year, day)?;
+    let input = read_input(year, day)?;
+    let known = known_answers(year, day);
+    let prepared = solution.prepare(&input);
+
+    Ok((1..=solution.parts())
+        .zip(known)
+        .map(|(part, expected)| {
+            let actual = match &prepared {
+                Ok(prepared) => prepared.part(part),
+                Err(e) => Err(anyhow!("{e:#}")),
+            };
+            // The saved answers are plain text, so they're read as whatever kind the solution gives
+            let expected = expected.map(|expected| match &actual {
+                Ok(actual) => AnswerValue::parse_like(&expected, actual),
+                Err(_) => AnswerValue::parse(&expected),
+            });
+            let status = match (&actual, &expected) {
+                (Err(_), _) => Status::Fail,
+                (Ok(_), None) => Status::Unknown,
+                (Ok(actual), Some(expected)) if actual == expected => Status::Pass,
+                _ => Status::Fail,
+            };
+            Verdict {
+                year,
+                day,
+                part,
+                status,
+                expected,
+                actual,
+            }
+        })
+        .collect())
+}
+
+/// Verify every day, a day that can't run at all fails on its own instead of stopping the rest
+fn verify_days(year: u16, days: Vec<u8>) -> Vec<Verdict> {
+    days.into_par_iter()
+        .flat_map_iter(|day| {
+            verify_day(year, day).unwrap_or_else(|e| {
+                vec![Verdict {
+                    year,
+                    day,
+                    part: 0,
+                    status: Status::Fail,
+                    expected: None,
+                    actual: Err(e),
+                }]
+            })
+        })
+        .collect()
+}
+
+/// Record answers for parts that have no known answer yet
+fn save_unknown(verdicts: &[Verdict]) -> Result<()> {
+    for ((year, day), day_verdicts) in &verdicts.iter().chunk_by(|v| (v.year, v.day)) {
+        let mut known = known_answers(year, day);
+        let mut changed = false;
+        for verdict in day_verdicts {
+            if let (Status::Unknown, Ok(actual)) = (verdict.status, &verdict.actual) {
+                known[(verdict.part - 1) as usize] = Some(actual.to_string());
+                changed = true;
+            }
+        }
+        if changed {
+            save_answers(year, day, &known)?;
+        }
+    }
+    Ok(())
+}
+
+/// Check answers for one day, or all released days of `year`, against the known answers.
+/// Every day is reported, and then it fails if any day or part produced an error or a wrong answer.
+pub fn verify(year: u16, day: Option<u8>, save: bool) -> Result<()> {
+    let days = match day {
+        Some(day) => vec![day],
+        None => released_days(year)?,
+    };
+    let verdicts = verify_days(year, days);
+
+    for verdict in verdicts.iter() {
+        println!("{verdict}");
+    }
+    if save {
+        save_unknown(&verdicts)?;
+    }
+
+    let failed = verdicts.iter().filter(|v| v.status == Status::Fail).count();
+    if failed > 0 {
+        Err(anyhow!("{failed} part(s) failed verification"))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = "part 1: 735
+part 2:
+#  # ####
+#  # #   \n";
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = parse_answers(ANSWERS);
+        assert_eq!(answers[0], Some("735".to_string()));
+        assert_eq!(answers[1], Some("#  # ####\n#  # #".to_string()));
+        assert_eq!(
+            parse_answers("part 2: 12\n"),
+            [None, Some("12".to_string())]
+        );
+        assert_eq!(parse_answers(""), [None, None]);
+    }
+
+    #[test]
+    fn test_failed_days_dont_stop_the_rest() {
+        // 2016 has no solutions after day 15
+        let verdicts = verify_days(2016, vec![16, 17]);
+        assert_eq!(verdicts.len(), 2);
+        assert!(verdicts
+            .iter()
+            .all(|v| v.status == Status::Fail && v.part == 0 && v.actual.is_err()));
+        assert_eq!(
+            verdicts[1].to_string(),
+            "2016 day 17: FAIL error: Error: no solution for day: 17"
+        );
+    }
+
+    #[test]
+    fn test_format_answers_roundtrip() {
+        let answers = parse_answers(ANSWERS);
+        assert_eq!(parse_answers(&format_answers(&answers)), answers);
+    }
+
+    #[test]
+    fn test_normalize_keeps_leading_whitespace() {
+        assert_eq!(normalize("\n ##  #\n#  # \n\n"), " ##  #\n#  #");
+    }
+}
@@ -0,0 +1,218 @@
+use crate::examples::normalize;
+use serde::de::{Error, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// An answer to one part of a puzzle. Most answers are numbers, some are a word or a code, and
+/// some are drawn as an image of letters that has to be read by a human (or by OCR).
+///
+/// In JSON, integers are numbers, text is a string and grids are an array of rows.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Ord, PartialOrd, Serialize)]
+#[serde(untagged)]
+pub enum AnswerValue {
+    Integer(i128),
+    Text(String),
+    Grid(Vec<String>),
+}
+
+impl AnswerValue {
+    /// Classify an answer from how it's written down, for answers that come from a file or the
+    /// command line rather than from a solution. Leading blank lines and trailing whitespace
+    /// are ignored, like `normalize` does, and only integers written the way `Display` writes
+    /// them count as integers, so a code like `0042` stays text.
+    pub fn parse(answer: &str) -> AnswerValue {
+        let answer = normalize(answer);
+        if answer.contains('\n') {
+            return AnswerValue::Grid(answer.lines().map(String::from).collect());
+        }
+        let answer = answer.trim();
+        match answer.parse::<i128>() {
+            Ok(n) if n.to_string() == answer => AnswerValue::Integer(n),
+            _ => AnswerValue::Text(answer.to_string()),
+        }
+    }
+
+    /// Read a written answer as the same kind of answer as `like`, so that a saved answer can
+    /// be compared with what a solution returns. A code made only of digits stays text when the
+    /// solution says it's text.
+    pub fn parse_like(answer: &str, like: &AnswerValue) -> AnswerValue {
+        let answer = normalize(answer);
+        match like {
+            AnswerValue::Integer(_) => AnswerValue::parse(&answer),
+            AnswerValue::Text(_) => AnswerValue::Text(answer.trim().to_string()),
+            AnswerValue::Grid(_) => AnswerValue::Grid(answer.lines().map(String::from).collect()),
+        }
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            AnswerValue::Integer(_) => "integer",
+            AnswerValue::Text(_) => "text",
+            AnswerValue::Grid(_) => "grid",
+        }
+    }
+
+    pub fn as_integer(&self) -> Option<i128> {
+        match self {
+            AnswerValue::Integer(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    /// Grids span several lines, everything else fits on one
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, AnswerValue::Grid(_))
+    }
+}
+
+impl Display for AnswerValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnswerValue::Integer(n) => write!(f, "{n}"),
+            AnswerValue::Text(text) => write!(f, "{text}"),
+            AnswerValue::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+impl FromStr for AnswerValue {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(AnswerValue::parse(s))
+    }
+}
+
+/// Strings are always text, even when they are made of digits
+impl From<&str> for AnswerValue {
+    fn from(answer: &str) -> Self {
+        AnswerValue::Text(answer.to_string())
+    }
+}
+
+impl From<String> for AnswerValue {
+    fn from(answer: String) -> Self {
+        AnswerValue::Text(answer)
+    }
+}
+
+/// One string per row
+impl From<Vec<String>> for AnswerValue {
+    fn from(rows: Vec<String>) -> Self {
+        AnswerValue::Grid(rows)
+    }
+}
+
+struct AnswerVisitor;
+
+impl<'de> Visitor<'de> for AnswerVisitor {
+    type Value = AnswerValue;
+
+    fn expecting(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "an integer, a string or an array of rows")
+    }
+
+    fn visit_i64<E: Error>(self, n: i64) -> Result<AnswerValue, E> {
+        Ok(AnswerValue::Integer(n as i128))
+    }
+
+    fn visit_u64<E: Error>(self, n: u64) -> Result<AnswerValue, E> {
+        Ok(AnswerValue::Integer(n as i128))
+    }
+
+    fn visit_i128<E: Error>(self, n: i128) -> Result<AnswerValue, E> {
+        Ok(AnswerValue::Integer(n))
+    }
+
+    fn visit_str<E: Error>(self, text: &str) -> Result<AnswerValue, E> {
+        Ok(AnswerValue::Text(text.to_string()))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<AnswerValue, A::Error> {
+        let mut rows = Vec::new();
+        while let Some(row) = seq.next_element()? {
+            rows.push(row);
+        }
+        Ok(AnswerValue::Grid(rows))
+    }
+}
+
+// Untagged enums can't deserialize i128, so this is done by hand
+impl<'de> Deserialize<'de> for AnswerValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(AnswerVisitor)
+    }
+}
+
+macro_rules! from_integer {
+    ($($t:ty),*) => {
+        $(impl From<$t> for AnswerValue {
+            fn from(n: $t) -> Self {
+                AnswerValue::Integer(n as i128)
+            }
+        })*
+    };
+}
+
+from_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<u128> for AnswerValue {
+    fn from(n: u128) -> Self {
+        AnswerValue::Integer(i128::try_from(n).expect("Answers fit in an i128"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(AnswerValue::parse("1791\n"), AnswerValue::Integer(1791));
+        assert_eq!(AnswerValue::parse("-12"), AnswerValue::Integer(-12));
+        assert_eq!(AnswerValue::parse("0042").kind(), "text");
+        assert_eq!(
+            AnswerValue::parse(" abc-def "),
+            AnswerValue::from("abc-def")
+        );
+        assert_eq!(AnswerValue::from("97289").kind(), "text");
+        let code = AnswerValue::from("0042");
+        assert_eq!(AnswerValue::parse_like("0042\n", &code), code);
+        assert_eq!(
+            AnswerValue::parse_like("12", &AnswerValue::Integer(1)),
+            AnswerValue::Integer(12)
+        );
+        assert_eq!(
+            AnswerValue::parse_like("12", &AnswerValue::from("7")),
+            AnswerValue::from("12")
+        );
+        assert_eq!(
+            AnswerValue::parse("\n#  # \n#### \n"),
+            AnswerValue::from(vec!["#  #".to_string(), "####".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_display_roundtrip() {
+        for answer in ["18446744073709551616", "RGZEHURK", "##.\n.##"] {
+            let value = AnswerValue::parse(answer);
+            assert_eq!(value.to_string(), answer);
+            assert_eq!(AnswerValue::parse(&value.to_string()), value);
+        }
+        assert_eq!(AnswerValue::from(42usize), AnswerValue::parse("42"));
+    }
+
+    #[test]
+    fn test_serde() {
+        let values = vec![
+            AnswerValue::from(735u64),
+            AnswerValue::from("abc"),
+            AnswerValue::parse("#.\n.#"),
+        ];
+        let json = serde_json::to_string(&values).unwrap();
+        assert_eq!(json, r##"[735,"abc",["#.",".#"]]"##);
+        let back: Vec<AnswerValue> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, values);
+    }
+}
@@ -0,0 +1,137 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// An example from the puzzle description, with the answers the description gives for it
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Example {
+    pub input: String,
+    pub answers: [Option<String>; 2],
+}
+
+impl Example {
+    pub fn answer(&self, part: u8) -> Option<&str> {
+        let ix = (part as usize).checked_sub(1)?;
+        self.answers.get(ix)?.as_deref()
+    }
+}
+
+/// Trailing whitespace and leading blank lines don't count when comparing answers
+pub fn normalize(answer: &str) -> String {
+    let lines: Vec<_> = answer
+        .lines()
+        .map(str::trim_end)
+        .skip_while(|line| line.is_empty())
+        .collect();
+    lines.join("\n").trim_end().to_string()
+}
+
+/// Answers are stored like this, with multiline answers starting on the line after the header:
+///
+/// ```text
+/// part 1: 1791
+/// part 2:
+/// #  # ####
+/// #  # #
+/// ```
+pub fn parse_answers(content: &str) -> [Option<String>; 2] {
+    let mut answers: [Option<String>; 2] = Default::default();
+    let mut current = None;
+
+    for line in content.lines() {
+        let header = line
+            .strip_prefix("part 1:")
+            .map(|rest| (0, rest))
+            .or_else(|| line.strip_prefix("part 2:").map(|rest| (1, rest)));
+        if let Some((ix, rest)) = header {
+            answers[ix] = Some(rest.trim().to_string());
+            current = Some(ix);
+        } else if let Some(answer) = current.and_then(|ix| answers[ix].as_mut()) {
+            answer.push('\n');
+            answer.push_str(line);
+        }
+    }
+
+    answers.map(|answer| answer.map(|a| normalize(&a)).filter(|a| !a.is_empty()))
+}
+
+pub fn format_answers(answers: &[Option<String>; 2]) -> String {
+    answers
+        .iter()
+        .enumerate()
+        .filter_map(|(ix, answer)| answer.as_ref().map(|a| (ix + 1, normalize(a))))
+        .map(|(part, answer)| {
+            if answer.contains('\n') {
+                format!("part {part}:\n{answer}\n")
+            } else {
+                format!("part {part}: {answer}\n")
+            }
+        })
+        .collect()
+}
+
+/// `example_N` holds the input, `example_N.answer` the answers, if the description had any
+pub fn example_from(dir: &Path, n: usize) -> Option<Example> {
+    let input = fs::read_to_string(dir.join(format!("example_{n}"))).ok()?;
+    let answers = fs::read_to_string(dir.join(format!("example_{n}.answer")))
+        .map(|content| parse_answers(&content))
+        .unwrap_or_default();
+    Some(Example { input, answers })
+}
+
+/// Tests run in the directory of the crate, so look for `input` there and in the parents,
+/// unless `$AOC_INPUT_DIR` says where it is
+fn input_dir() -> Option<PathBuf> {
+    if let Some(dir) = env::var_os("AOC_INPUT_DIR") {
+        return Some(PathBuf::from(dir));
+    }
+    let cwd = env::current_dir().ok()?;
+    cwd.ancestors()
+        .map(|dir| dir.join("input"))
+        .find(|dir| dir.is_dir())
+}
+
+/// Load example `n` (counting from 1) that `aoc puzzle` extracted from the description.
+/// Inputs aren't checked in, so tests should skip the example when this is `None`.
+pub fn load_example(year: u16, day: u8, n: usize) -> Option<Example> {
+    let dir = input_dir()?
+        .join(year.to_string())
+        .join(format!("day_{day:0>2}"));
+    example_from(&dir, n)
+}
+
+/// All the extracted examples for `day`, in the order they appear in the description
+pub fn load_examples(year: u16, day: u8) -> Vec<Example> {
+    (1..).map_while(|n| load_example(year, day, n)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answers_roundtrip() {
+        let content = "part 1: 1791\npart 2:\n#  # ####\n#  # #  \n\n";
+        let answers = parse_answers(content);
+        assert_eq!(answers[0].as_deref(), Some("1791"));
+        assert_eq!(answers[1].as_deref(), Some("#  # ####\n#  # #"));
+        assert_eq!(parse_answers(&format_answers(&answers)), answers);
+    }
+
+    #[test]
+    fn test_example_from() {
+        let dir = env::temp_dir().join(format!("shared-examples-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("example_1"), "(())\n").unwrap();
+        fs::write(dir.join("example_1.answer"), "part 1: 0\n").unwrap();
+        fs::write(dir.join("example_2"), "()())\n").unwrap();
+
+        let first = example_from(&dir, 1).unwrap();
+        assert_eq!(first.input, "(())\n");
+        assert_eq!(first.answer(1), Some("0"));
+        assert_eq!(first.answer(2), None);
+        assert_eq!(example_from(&dir, 2).unwrap().answers, [None, None]);
+        assert!(example_from(&dir, 3).is_none());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
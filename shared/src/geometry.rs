@@ -0,0 +1,866 @@
+use std::array;
+use std::ops::{Add, Div, Mul, Sub};
+
+#[derive(PartialEq, Eq, Debug, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct Point2d<T> {
+    pub x: T,
+    pub y: T,
+}
+
+pub trait Abs<T>
+where
+    T: Copy,
+{
+    fn abs(self) -> Self;
+}
+impl Abs<i32> for i32 {
+    fn abs(self) -> Self {
+        self.abs()
+    }
+}
+impl Abs<i64> for i64 {
+    fn abs(self) -> Self {
+        self.abs()
+    }
+}
+impl Abs<i128> for i128 {
+    fn abs(self) -> Self {
+        self.abs()
+    }
+}
+impl Abs<isize> for isize {
+    fn abs(self) -> Self {
+        self.abs()
+    }
+}
+impl Abs<f32> for f32 {
+    fn abs(self) -> Self {
+        self.abs()
+    }
+}
+impl Abs<f64> for f64 {
+    fn abs(self) -> Self {
+        self.abs()
+    }
+}
+pub trait Sqrt<T> {
+    fn sqrt(self) -> Self;
+}
+impl Sqrt<f64> for f64 {
+    fn sqrt(self: f64) -> Self {
+        self.sqrt()
+    }
+}
+impl Sqrt<f32> for f32 {
+    fn sqrt(self: f32) -> Self {
+        self.sqrt()
+    }
+}
+
+/// The numbers that points are made of
+pub trait Coord:
+    Copy
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Abs<Self>
+    + From<i32>
+    + PartialOrd<Self>
+{
+}
+
+impl<T> Coord for T where
+    T: Copy
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + Div<Output = T>
+        + Abs<T>
+        + From<i32>
+        + PartialOrd<T>
+{
+}
+
+// std::cmp::{min, max} need Ord, which floats don't have
+fn lesser<T: PartialOrd>(a: T, b: T) -> T {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+fn greater<T: PartialOrd>(a: T, b: T) -> T {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash)]
+pub struct Rect<T> {
+    pub northwest: Point2d<T>,
+    pub southeast: Point2d<T>,
+}
+impl<T: PartialOrd<T> + Copy + From<i32> + Sub<Output = T> + Add<Output = T> + Mul<Output = T>>
+    Rect<T>
+{
+    pub fn contains(&self, point: Point2d<T>) -> bool {
+        (self.northwest.x..=self.southeast.x).contains(&point.x)
+            && (self.northwest.y..=self.southeast.y).contains(&point.y)
+    }
+    pub fn area(&self) -> T {
+        let x = self.southeast.x - self.northwest.x + 1.into();
+        let y = self.southeast.y - self.northwest.y + 1.into();
+        x * y
+    }
+    pub fn dims(&self) -> (T, T) {
+        let xdim = (self.southeast.x - self.northwest.x) + 1.into();
+        let ydim = (self.southeast.y - self.northwest.y) + 1.into();
+        (xdim, ydim)
+    }
+    /// The smallest rectangle that holds all the points, or just the origin if there are none
+    pub fn bound(mut points: impl Iterator<Item = Point2d<T>>) -> Rect<T> {
+        let Some(first) = points.next() else {
+            let origin = Point2d {
+                x: 0.into(),
+                y: 0.into(),
+            };
+            return Rect {
+                northwest: origin,
+                southeast: origin,
+            };
+        };
+        points.fold(
+            Rect {
+                northwest: first,
+                southeast: first,
+            },
+            |rect, p| Rect {
+                northwest: Point2d {
+                    x: lesser(rect.northwest.x, p.x),
+                    y: lesser(rect.northwest.y, p.y),
+                },
+                southeast: Point2d {
+                    x: greater(rect.southeast.x, p.x),
+                    y: greater(rect.southeast.y, p.y),
+                },
+            },
+        )
+    }
+}
+
+impl<T> From<(T, T)> for Point2d<T> {
+    fn from(t: (T, T)) -> Self {
+        Self { x: t.0, y: t.1 }
+    }
+}
+
+pub trait Rem<T> {
+    fn rem_euclid(self, divisor: T) -> T;
+}
+impl Rem<i32> for i32 {
+    fn rem_euclid(self, divisor: i32) -> i32 {
+        self.rem_euclid(divisor)
+    }
+}
+impl Rem<i64> for i64 {
+    fn rem_euclid(self, divisor: i64) -> i64 {
+        self.rem_euclid(divisor)
+    }
+}
+impl Rem<usize> for usize {
+    fn rem_euclid(self, divisor: usize) -> usize {
+        self.rem_euclid(divisor)
+    }
+}
+impl Rem<u32> for u32 {
+    fn rem_euclid(self, divisor: u32) -> u32 {
+        self.rem_euclid(divisor)
+    }
+}
+impl Rem<u64> for u64 {
+    fn rem_euclid(self, divisor: u64) -> u64 {
+        self.rem_euclid(divisor)
+    }
+}
+
+impl<T> Point2d<T>
+where
+    T: Rem<T>,
+{
+    pub fn wrap(self, xmax: T, ymax: T) -> Self {
+        Self {
+            x: self.x.rem_euclid(xmax),
+            y: self.y.rem_euclid(ymax),
+        }
+    }
+}
+
+impl<T> Point2d<T>
+where
+    T: Add<Output = T>
+        + Mul<Output = T>
+        + Copy
+        + Abs<T>
+        + Sub<Output = T>
+        + Div<Output = T>
+        + From<i32>
+        + PartialOrd<T>,
+{
+    pub fn origin() -> Self {
+        Self::new(0.into(), 0.into())
+    }
+
+    pub fn northwest(self) -> Self {
+        Self {
+            x: self.x - 1.into(),
+            y: self.y - 1.into(),
+        }
+    }
+
+    pub fn north(self) -> Self {
+        Self {
+            x: self.x,
+            y: self.y - 1.into(),
+        }
+    }
+    pub fn northeast(self) -> Self {
+        Self {
+            x: self.x + 1.into(),
+            y: self.y - 1.into(),
+        }
+    }
+    pub fn west(self) -> Self {
+        Self {
+            x: self.x - 1.into(),
+            y: self.y,
+        }
+    }
+    pub fn southwest(self) -> Self {
+        Self {
+            x: self.x - 1.into(),
+            y: self.y + 1.into(),
+        }
+    }
+    pub fn east(self) -> Self {
+        Self {
+            x: self.x + 1.into(),
+            y: self.y,
+        }
+    }
+    pub fn southeast(self) -> Self {
+        Self {
+            x: self.x + 1.into(),
+            y: self.y + 1.into(),
+        }
+    }
+    pub fn south(self) -> Self {
+        Self {
+            x: self.x,
+            y: self.y + 1.into(),
+        }
+    }
+    pub fn around(self) -> Vec<Self> {
+        vec![
+            self.northwest(),
+            self.north(),
+            self.northeast(),
+            self.west(),
+            self.east(),
+            self.southwest(),
+            self.south(),
+            self.southeast(),
+        ]
+    }
+    pub fn shift(&mut self, offset: &Self) {
+        *self = *self + *offset;
+    }
+
+    pub fn clamp(self, xbound: (T, T), ybound: (T, T)) -> Self {
+        Self {
+            x: if self.x < xbound.0 {
+                xbound.0
+            } else if self.x > xbound.1 {
+                xbound.1
+            } else {
+                self.x
+            },
+            y: if self.y < ybound.0 {
+                ybound.0
+            } else if self.y > ybound.1 {
+                ybound.1
+            } else {
+                self.y
+            },
+        }
+    }
+
+    pub fn new(x: T, y: T) -> Self {
+        Point2d { x, y }
+    }
+    pub fn scale(&self, factor: T) -> Self {
+        *self * Self::new(factor, factor)
+    }
+    pub fn invscale(&self, divisor: T) -> Self {
+        Self::new(self.x / divisor, self.y / divisor)
+    }
+    pub fn abs(self) -> Point2d<T> {
+        Self::new(self.x.abs(), self.y.abs())
+    }
+    pub fn sum(self) -> T {
+        self.x + self.y
+    }
+    pub fn manhattan(self) -> T {
+        self.abs().sum()
+    }
+    pub fn chebyshev(self) -> T {
+        let abs = self.abs();
+        greater(abs.x, abs.y)
+    }
+    /// One step in `dir`, with y growing downwards like in the puzzle inputs
+    pub fn step(self, dir: Direction) -> Self {
+        self + dir.delta()
+    }
+    /// Rotate 90 degrees clockwise around the origin, as seen on screen with y growing downwards
+    pub fn turn_right(self) -> Self {
+        Self::new(T::from(0) - self.y, self.x)
+    }
+    pub fn turn_left(self) -> Self {
+        Self::new(self.y, T::from(0) - self.x)
+    }
+    pub fn square(self) -> Self {
+        self * self
+    }
+}
+
+impl<T: Sqrt<T> + Add<Output = T> + Mul<Output = T> + Copy> Point2d<T> {
+    pub fn euclid(self) -> T {
+        (self.x * self.x + self.y * self.y).sqrt()
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point2d<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point2d<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl<T: Mul<Output = T>> Mul for Point2d<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x * rhs.x,
+            y: self.y * rhs.y,
+        }
+    }
+}
+
+impl<T: Div<Output = T>> Div for Point2d<T> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x / rhs.x,
+            y: self.y / rhs.y,
+        }
+    }
+}
+
+/// The 4 directions on a map, with north up
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Clockwise, from north
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Direction {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    /// The step to take, with y growing downwards
+    pub fn delta<T: From<i32>>(self) -> Point2d<T> {
+        let (x, y) = match self {
+            Direction::North => (0, -1),
+            Direction::East => (1, 0),
+            Direction::South => (0, 1),
+            Direction::West => (-1, 0),
+        };
+        Point2d {
+            x: x.into(),
+            y: y.into(),
+        }
+    }
+
+    /// Arrows like `^>v<`, compass letters like `NESW` and `UDLR` all show up in inputs
+    pub fn from_char(ch: char) -> Option<Direction> {
+        match ch {
+            '^' | 'N' | 'U' => Some(Direction::North),
+            '>' | 'E' | 'R' => Some(Direction::East),
+            'v' | 'S' | 'D' => Some(Direction::South),
+            '<' | 'W' | 'L' => Some(Direction::West),
+            _ => None,
+        }
+    }
+}
+
+/// The 8 directions on a map, including the diagonals
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// Clockwise, from north
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    /// The step to take, with y growing downwards
+    pub fn delta<T: From<i32>>(self) -> Point2d<T> {
+        let (x, y) = match self {
+            Direction8::North => (0, -1),
+            Direction8::NorthEast => (1, -1),
+            Direction8::East => (1, 0),
+            Direction8::SouthEast => (1, 1),
+            Direction8::South => (0, 1),
+            Direction8::SouthWest => (-1, 1),
+            Direction8::West => (-1, 0),
+            Direction8::NorthWest => (-1, -1),
+        };
+        Point2d {
+            x: x.into(),
+            y: y.into(),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(dir: Direction) -> Self {
+        Direction8::ALL[dir as usize * 2]
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, PartialOrd, Ord, Clone, Copy, Hash, Default)]
+pub struct Point3d<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Coord> Point3d<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Point3d { x, y, z }
+    }
+
+    pub fn origin() -> Self {
+        Self::new(0.into(), 0.into(), 0.into())
+    }
+
+    pub fn abs(self) -> Self {
+        Self::new(self.x.abs(), self.y.abs(), self.z.abs())
+    }
+
+    pub fn manhattan(self) -> T {
+        let abs = self.abs();
+        abs.x + abs.y + abs.z
+    }
+
+    pub fn chebyshev(self) -> T {
+        let abs = self.abs();
+        greater(greater(abs.x, abs.y), abs.z)
+    }
+
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn cross(self, other: Self) -> Self {
+        Self::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    pub fn scale(self, factor: T) -> Self {
+        Self::new(self.x * factor, self.y * factor, self.z * factor)
+    }
+
+    /// The 6 points that share a face with this one, in a grid of cubes
+    pub fn faces(self) -> [Self; 6] {
+        let (zero, one) = (T::from(0), T::from(1));
+        [
+            Self::new(one, zero, zero),
+            Self::new(zero - one, zero, zero),
+            Self::new(zero, one, zero),
+            Self::new(zero, zero - one, zero),
+            Self::new(zero, zero, one),
+            Self::new(zero, zero, zero - one),
+        ]
+        .map(|delta| self + delta)
+    }
+
+    /// Rotate 90 degrees around the x axis, counterclockwise when looking from positive x
+    pub fn rotate_x(self) -> Self {
+        Self::new(self.x, T::from(0) - self.z, self.y)
+    }
+
+    /// Rotate 90 degrees around the y axis, counterclockwise when looking from positive y
+    pub fn rotate_y(self) -> Self {
+        Self::new(self.z, self.y, T::from(0) - self.x)
+    }
+
+    /// Rotate 90 degrees around the z axis, counterclockwise when looking from positive z
+    pub fn rotate_z(self) -> Self {
+        Self::new(T::from(0) - self.y, self.x, self.z)
+    }
+
+    /// All 24 orientations of this point, when it's turned around the origin in steps of 90
+    /// degrees. The rotations come in the same order for every point, so the nth rotation of
+    /// one point and the nth rotation of another were turned the same way.
+    pub fn rotations(self) -> Vec<Self> {
+        let mut out = Vec::with_capacity(24);
+        let mut p = self;
+        for _ in 0..2 {
+            for _ in 0..3 {
+                p = p.rotate_x();
+                out.push(p);
+                for _ in 0..3 {
+                    p = p.rotate_z();
+                    out.push(p);
+                }
+            }
+            p = p.rotate_x().rotate_z().rotate_x();
+        }
+        out
+    }
+}
+
+impl<T: Sqrt<T> + Coord> Point3d<T> {
+    pub fn euclid(self) -> T {
+        self.dot(self).sqrt()
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point3d<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point3d<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3d<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Point3d { x, y, z }
+    }
+}
+
+impl<T> From<Point3d<T>> for Point<T, 3> {
+    fn from(p: Point3d<T>) -> Self {
+        Point([p.x, p.y, p.z])
+    }
+}
+
+/// A point with any number of dimensions, for the puzzles that go to 4D and beyond
+#[derive(PartialEq, Eq, Debug, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct Point<T, const N: usize>(pub [T; N]);
+
+impl<T: Coord, const N: usize> Point<T, N> {
+    pub fn origin() -> Self {
+        Point([0.into(); N])
+    }
+
+    pub fn manhattan(self) -> T {
+        self.0.iter().fold(0.into(), |sum, &c| sum + c.abs())
+    }
+
+    pub fn chebyshev(self) -> T {
+        self.0
+            .iter()
+            .fold(0.into(), |max, &c| greater(max, c.abs()))
+    }
+
+    /// The square of the euclidean length, which is enough to compare distances
+    pub fn norm_squared(self) -> T {
+        self.0.iter().fold(0.into(), |sum, &c| sum + c * c)
+    }
+
+    /// The 3^N - 1 points that touch this one, diagonals included
+    pub fn around(self) -> Vec<Self> {
+        let mut deltas = vec![[T::from(0); N]];
+        for axis in 0..N {
+            deltas = deltas
+                .into_iter()
+                .flat_map(|delta| {
+                    [-1, 0, 1].map(|d| {
+                        let mut delta = delta;
+                        delta[axis] = d.into();
+                        delta
+                    })
+                })
+                .collect();
+        }
+        deltas
+            .into_iter()
+            .map(Point)
+            .filter(|delta| *delta != Self::origin())
+            .map(|delta| self + delta)
+            .collect()
+    }
+}
+
+impl<T: Sqrt<T> + Coord, const N: usize> Point<T, N> {
+    pub fn euclid(self) -> T {
+        self.norm_squared().sqrt()
+    }
+}
+
+impl<T: Add<Output = T> + Copy, const N: usize> Add for Point<T, N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Point(array::from_fn(|i| self.0[i] + rhs.0[i]))
+    }
+}
+
+impl<T: Sub<Output = T> + Copy, const N: usize> Sub for Point<T, N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Point(array::from_fn(|i| self.0[i] - rhs.0[i]))
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Point<T, N> {
+    fn from(coords: [T; N]) -> Self {
+        Point(coords)
+    }
+}
+
+/// An axis aligned box in any number of dimensions, where both corners are inside the box
+#[derive(PartialEq, Eq, Debug, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct Cuboid<T, const N: usize> {
+    pub min: Point<T, N>,
+    pub max: Point<T, N>,
+}
+
+impl<T: Coord, const N: usize> Cuboid<T, N> {
+    /// The box with `a` and `b` in opposite corners
+    pub fn new(a: Point<T, N>, b: Point<T, N>) -> Self {
+        Cuboid {
+            min: Point(array::from_fn(|i| lesser(a.0[i], b.0[i]))),
+            max: Point(array::from_fn(|i| greater(a.0[i], b.0[i]))),
+        }
+    }
+
+    /// The smallest box that holds all the points
+    pub fn bound(mut points: impl Iterator<Item = Point<T, N>>) -> Option<Self> {
+        let first = points.next()?;
+        Some(points.fold(Cuboid::new(first, first), |bounds, p| {
+            bounds.union(&Cuboid::new(p, p))
+        }))
+    }
+
+    pub fn contains(&self, p: Point<T, N>) -> bool {
+        (0..N).all(|i| self.min.0[i] <= p.0[i] && p.0[i] <= self.max.0[i])
+    }
+
+    /// The part that is inside both boxes, if they overlap
+    pub fn intersect(&self, other: &Self) -> Option<Self> {
+        let min = Point(array::from_fn(|i| greater(self.min.0[i], other.min.0[i])));
+        let max = Point(array::from_fn(|i| lesser(self.max.0[i], other.max.0[i])));
+        (0..N)
+            .all(|i| min.0[i] <= max.0[i])
+            .then_some(Cuboid { min, max })
+    }
+
+    /// The smallest box that holds both boxes
+    pub fn union(&self, other: &Self) -> Self {
+        Cuboid {
+            min: Point(array::from_fn(|i| lesser(self.min.0[i], other.min.0[i]))),
+            max: Point(array::from_fn(|i| greater(self.max.0[i], other.max.0[i]))),
+        }
+    }
+
+    /// The length along each axis, counting both ends, like the number of cubes in a grid
+    pub fn dims(&self) -> [T; N] {
+        array::from_fn(|i| self.max.0[i] - self.min.0[i] + 1.into())
+    }
+
+    /// The number of grid points inside, which is the area in 2D
+    pub fn volume(&self) -> T {
+        self.dims()
+            .into_iter()
+            .fold(1.into(), |product, d| product * d)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_point_2d() {
+        let p: Point2d<i32> = Point2d::new(3, -4);
+        assert_eq!(p.manhattan(), 7);
+        assert_eq!(p.chebyshev(), 4);
+        assert_eq!(Point2d::new(3.0, -4.0).euclid(), 5.0);
+        assert_eq!(p.step(Direction::North), Point2d::new(3, -5));
+        assert_eq!(
+            Direction::North.delta::<i32>().turn_right(),
+            Direction::East.delta()
+        );
+        assert_eq!(p.turn_left().turn_right(), p);
+    }
+
+    #[test]
+    fn test_euclid() {
+        assert_eq!(Point2d::new(3.0, 4.0).euclid(), 5.0);
+        assert_eq!(Point2d::new(-5.0, 12.0).euclid(), 13.0);
+        assert_eq!(Point2d::new(0.0, 0.0).euclid(), 0.0);
+    }
+
+    #[test]
+    fn test_bound() {
+        // Only the points count, the origin doesn't unless it's one of them
+        let rect = Rect::bound([(2, 3), (5, 1), (4, 4)].into_iter().map(Point2d::from));
+        assert_eq!(rect.northwest, Point2d::new(2, 1));
+        assert_eq!(rect.southeast, Point2d::new(5, 4));
+        assert_eq!(rect.area(), 16);
+        let rect = Rect::bound([(-3, -1), (-2, -4)].into_iter().map(Point2d::from));
+        assert_eq!(rect.northwest, Point2d::new(-3, -4));
+        assert_eq!(rect.southeast, Point2d::new(-2, -1));
+        let single = Rect::bound(std::iter::once(Point2d::new(7i64, 9)));
+        assert_eq!(single.area(), 1);
+        let empty = Rect::bound(std::iter::empty::<Point2d<i32>>());
+        assert_eq!(empty.northwest, Point2d::new(0, 0));
+        assert_eq!(empty.southeast, Point2d::new(0, 0));
+    }
+
+    #[test]
+    fn test_directions() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.reverse().reverse(), dir);
+            assert_eq!(Direction8::from(dir).delta::<i32>(), dir.delta());
+        }
+        assert_eq!(Direction::West.turn_right(), Direction::North);
+        assert_eq!(Direction::from_char('v'), Some(Direction::South));
+        assert_eq!(
+            Direction8::ALL.map(|d| d.delta::<i32>().chebyshev()),
+            [1; 8]
+        );
+    }
+
+    #[test]
+    fn test_point_3d() {
+        let p = Point3d::new(1i64, 2, 3);
+        assert_eq!(p.cross(Point3d::new(4, 5, 6)), Point3d::new(-3, 6, -3));
+        assert_eq!((p - Point3d::new(2, 0, 0)).manhattan(), 6);
+        assert_eq!(p.faces().len(), 6);
+        let rotations = p.rotations();
+        assert_eq!(rotations.iter().collect::<HashSet<_>>().len(), 24);
+        assert!(rotations.contains(&p));
+        assert!(rotations.iter().all(|r| r.dot(*r) == p.dot(p)));
+        assert_eq!(p.rotate_x().rotate_x().rotate_x().rotate_x(), p);
+    }
+
+    #[test]
+    fn test_point_n() {
+        let p = Point([1i32, -2, 3, 4]);
+        assert_eq!(p.manhattan(), 10);
+        assert_eq!(p.chebyshev(), 4);
+        assert_eq!(p.norm_squared(), 30);
+        assert_eq!(p.around().len(), 80);
+        assert_eq!(Point([0.0, 3.0, 4.0]).euclid(), 5.0);
+        assert_eq!(Point::from(Point3d::new(1, 2, 3)), Point([1, 2, 3]));
+    }
+
+    #[test]
+    fn test_cuboid() {
+        let a = Cuboid::new(Point([0i64, 0, 0]), Point([2, 2, 2]));
+        let b = Cuboid::new(Point([3, 3, 3]), Point([1, 1, 1]));
+        assert_eq!(a.volume(), 27);
+        assert_eq!(
+            a.intersect(&b),
+            Some(Cuboid::new(Point([1, 1, 1]), Point([2, 2, 2])))
+        );
+        assert_eq!(a.union(&b).volume(), 64);
+        assert!(a.contains(Point([2, 0, 1])));
+        let far = Cuboid::new(Point([5, 5, 5]), Point([6, 6, 6]));
+        assert_eq!(a.intersect(&far), None);
+        let bound = Cuboid::bound([Point([1, 5]), Point([-1, 2])].into_iter()).unwrap();
+        assert_eq!(bound.dims(), [3, 4]);
+    }
+}
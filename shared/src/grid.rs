@@ -0,0 +1,375 @@
+use anyhow::{anyhow, Result};
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+/// The 4 neighbours, clockwise from up, with y growing downwards
+pub const NEIGHBOURS_4: [(i32, i32); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// The 8 neighbours, clockwise from up
+pub const NEIGHBOURS_8: [(i32, i32); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// A dense, rectangular grid stored row by row. Positions are `(x, y)` with `(0, 0)` in the
+/// top left corner, like the puzzle inputs, and are `i32` so that stepping outside the grid
+/// is just a lookup that returns `None`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Grid<T> {
+    width: i32,
+    height: i32,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid with `width` columns, from cells given row by row
+    pub fn from_vec(width: i32, cells: Vec<T>) -> Result<Grid<T>> {
+        if width <= 0 || !cells.len().is_multiple_of(width as usize) {
+            return Err(anyhow!(
+                "{} cells don't make rows of width {width}",
+                cells.len()
+            ));
+        }
+        let height = (cells.len() / width as usize) as i32;
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Parse one row per non-empty line, with `f` turning characters into cells. Input without
+    /// any rows gives an empty grid.
+    pub fn parse(input: &str, f: impl Fn(char) -> Option<T>) -> Result<Grid<T>> {
+        let mut width = None;
+        let mut cells = Vec::with_capacity(input.len());
+        for (y, line) in input.lines().filter(|line| !line.is_empty()).enumerate() {
+            let before = cells.len();
+            for (x, ch) in line.chars().enumerate() {
+                cells.push(f(ch).ok_or_else(|| anyhow!("Unexpected {ch:?} at {x},{y}"))?);
+            }
+            let row_width = cells.len() - before;
+            let expected = *width.get_or_insert(row_width);
+            if expected != row_width {
+                return Err(anyhow!(
+                    "Row {y} has width {row_width}, expected {expected}"
+                ));
+            }
+        }
+        match width {
+            Some(width) => Grid::from_vec(width as i32, cells),
+            None => Ok(Grid {
+                width: 0,
+                height: 0,
+                cells,
+            }),
+        }
+    }
+
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        (0..self.width).contains(&x) && (0..self.height).contains(&y)
+    }
+
+    fn offset(&self, x: i32, y: i32) -> Option<usize> {
+        self.contains(x, y).then(|| (x + y * self.width) as usize)
+    }
+
+    pub fn get(&self, x: i32, y: i32) -> Option<&T> {
+        self.offset(x, y).map(|ix| &self.cells[ix])
+    }
+
+    pub fn get_mut(&mut self, x: i32, y: i32) -> Option<&mut T> {
+        self.offset(x, y).map(|ix| &mut self.cells[ix])
+    }
+
+    /// Replace the cell at `x, y`, returning the old value, or `None` if it's outside the grid
+    pub fn set(&mut self, x: i32, y: i32, value: T) -> Option<T> {
+        self.get_mut(x, y)
+            .map(|cell| std::mem::replace(cell, value))
+    }
+
+    /// For grids that repeat infinitely in every direction, `None` only if the grid is empty
+    pub fn get_wrapping(&self, x: i32, y: i32) -> Option<&T> {
+        if self.cells.is_empty() {
+            return None;
+        }
+        self.get(x.rem_euclid(self.width), y.rem_euclid(self.height))
+    }
+
+    /// All positions, row by row
+    pub fn positions(&self) -> impl Iterator<Item = (i32, i32)> {
+        let (width, height) = (self.width, self.height);
+        (0..height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// All cells with their positions, row by row
+    pub fn iter(&self) -> impl Iterator<Item = ((i32, i32), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The neighbours of `x, y` that are inside the grid, up, right, down and left
+    pub fn neighbours_4(&self, x: i32, y: i32) -> impl Iterator<Item = (i32, i32)> + '_ {
+        self.neighbours(x, y, &NEIGHBOURS_4)
+    }
+
+    /// Like `neighbours_4`, with the diagonals too
+    pub fn neighbours_8(&self, x: i32, y: i32) -> impl Iterator<Item = (i32, i32)> + '_ {
+        self.neighbours(x, y, &NEIGHBOURS_8)
+    }
+
+    fn neighbours<'a>(
+        &'a self,
+        x: i32,
+        y: i32,
+        deltas: &'a [(i32, i32)],
+    ) -> impl Iterator<Item = (i32, i32)> + 'a {
+        deltas
+            .iter()
+            .map(move |(dx, dy)| (x + dx, y + dy))
+            .filter(|&(x, y)| self.contains(x, y))
+    }
+
+    pub fn row(&self, y: i32) -> Option<&[T]> {
+        self.offset(0, y)
+            .map(|start| &self.cells[start..start + self.width as usize])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1) as usize)
+    }
+
+    pub fn column(&self, x: i32) -> Option<impl Iterator<Item = &T>> {
+        self.contains(x, 0).then(|| {
+            self.cells
+                .iter()
+                .skip(x as usize)
+                .step_by(self.width as usize)
+        })
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).filter_map(|x| self.column(x))
+    }
+
+    /// The position of the first cell that is `value`, row by row
+    pub fn find(&self, value: &T) -> Option<(i32, i32)>
+    where
+        T: PartialEq,
+    {
+        self.find_all(value).next()
+    }
+
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = (i32, i32)> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// A grid of the given size, where the cell at `x, y` comes from `f(x, y)`
+    fn build(width: i32, height: i32, f: impl Fn(i32, i32) -> T) -> Grid<T> {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| f(x, y))
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `fill`. Panics if either is negative.
+    pub fn new(width: i32, height: i32, fill: T) -> Grid<T> {
+        assert!(
+            width >= 0 && height >= 0,
+            "A grid can't be {width} wide and {height} tall"
+        );
+        Grid {
+            width,
+            height,
+            cells: vec![fill; (width * height) as usize],
+        }
+    }
+
+    /// Rows become columns, mirroring the grid along the diagonal from the top left corner
+    pub fn transposed(&self) -> Grid<T> {
+        Grid::build(self.height, self.width, |x, y| self[(y, x)].clone())
+    }
+
+    pub fn rotated_clockwise(&self) -> Grid<T> {
+        Grid::build(self.height, self.width, |x, y| {
+            self[(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    pub fn rotated_counterclockwise(&self) -> Grid<T> {
+        Grid::build(self.height, self.width, |x, y| {
+            self[(self.width - 1 - y, x)].clone()
+        })
+    }
+
+    /// Mirror left to right
+    pub fn flipped_horizontally(&self) -> Grid<T> {
+        Grid::build(self.width, self.height, |x, y| {
+            self[(self.width - 1 - x, y)].clone()
+        })
+    }
+
+    /// Mirror top to bottom
+    pub fn flipped_vertically(&self) -> Grid<T> {
+        Grid::build(self.width, self.height, |x, y| {
+            self[(x, self.height - 1 - y)].clone()
+        })
+    }
+}
+
+impl<T> Index<(i32, i32)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (i32, i32)) -> &T {
+        self.get(x, y)
+            .unwrap_or_else(|| panic!("{x},{y} is outside the {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<(i32, i32)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (i32, i32)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("{x},{y} is outside the {width}x{height} grid"))
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Grid::parse(s, Some)
+    }
+}
+
+/// One line per row, with the cells written next to each other
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "abc\ndef\n";
+
+    #[test]
+    fn test_parse_and_index() {
+        let grid: Grid<char> = EXAMPLE.parse().unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get(0, -1), None);
+        assert_eq!(grid.get_wrapping(-1, 2), Some(&'c'));
+        assert_eq!(grid.find(&'e'), Some((1, 1)));
+        assert_eq!(grid.to_string(), "abc\ndef");
+        assert!("ab\nc".parse::<Grid<char>>().is_err());
+        assert!(Grid::parse("12x", |ch| ch.to_digit(10)).is_err());
+    }
+
+    #[test]
+    fn test_empty() {
+        let grid = Grid::new(0, 3, '.');
+        assert_eq!(grid.get(0, 0), None);
+        assert_eq!(grid.get_wrapping(5, -2), None);
+        assert_eq!(Grid::new(4, 0, '.').get_wrapping(0, 0), None);
+        assert_eq!(grid.positions().count(), 0);
+        let parsed: Grid<char> = "\n\n".parse().unwrap();
+        assert_eq!((parsed.width(), parsed.height()), (0, 0));
+        assert_eq!(parsed.to_string(), "");
+        assert_eq!(
+            Grid::parse("", |ch| ch.to_digit(10)).unwrap().get(0, 0),
+            None
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "A grid can't be -2 wide and 3 tall")]
+    fn test_negative_size() {
+        Grid::new(-2, 3, 0);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(grid.neighbours_4(1, 1).count(), 4);
+        assert_eq!(grid.neighbours_8(1, 1).count(), 8);
+        assert_eq!(
+            grid.neighbours_4(0, 0).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbours_8(2, 2).count(), 3);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let mut grid: Grid<char> = EXAMPLE.parse().unwrap();
+        assert_eq!(grid.row(1), Some(&['d', 'e', 'f'][..]));
+        assert_eq!(grid.row(2), None);
+        let column: String = grid.column(1).unwrap().collect();
+        assert_eq!(column, "be");
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(grid.set(0, 0, 'x'), Some('a'));
+        assert_eq!(grid.rows().next(), Some(&['x', 'b', 'c'][..]));
+    }
+
+    #[test]
+    fn test_transformations() {
+        let grid: Grid<char> = EXAMPLE.parse().unwrap();
+        assert_eq!(grid.transposed().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotated_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotated_counterclockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.flipped_horizontally().to_string(), "cba\nfed");
+        assert_eq!(grid.flipped_vertically().to_string(), "def\nabc");
+        let mut turned = grid.clone();
+        for _ in 0..4 {
+            turned = turned.rotated_clockwise();
+        }
+        assert_eq!(turned, grid);
+        assert_eq!(grid.map(|ch| ch.is_ascii_uppercase()).find(&true), None);
+    }
+}
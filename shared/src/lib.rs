@@ -1,31 +1,30 @@
-use anyhow::Result;
-use std::time::Instant;
+pub mod answer;
+pub mod examples;
+pub mod geometry;
+pub mod grid;
+pub mod math;
+pub mod ocr;
+pub mod search;
+pub mod solution;
 
-pub fn not_implemented(_s: &str) -> Result<String> {
-    Ok("Not implemented yet".to_string())
-}
+pub use answer::AnswerValue;
+pub use grid::Grid;
+pub use solution::{Day, Input, List, Owned, Parsed, Prepared, Solution};
 
-#[derive(Default)]
-pub enum Answer {
-    SolvedBoth {
-        part_1: fn(&str) -> Result<String>,
-        part_2: fn(&str) -> Result<String>,
-    },
-    SolvedOne {
-        part_1: fn(&str) -> Result<String>,
-    },
-    #[default]
-    NotImplementedYet,
-}
+use std::time::{Duration, Instant};
 
-pub fn elapsed_string(now: Instant) -> String {
-    if now.elapsed().as_millis() > 2 {
-        format!("{}ms", now.elapsed().as_millis())
+pub fn duration_string(duration: Duration) -> String {
+    if duration.as_millis() > 2 {
+        format!("{}ms", duration.as_millis())
     } else {
-        format!("{}μs", now.elapsed().as_micros())
+        format!("{}μs", duration.as_micros())
     }
 }
 
+pub fn elapsed_string(now: Instant) -> String {
+    duration_string(now.elapsed())
+}
+
 pub fn grid_parser<'a, T, F, N>(input: &'a str, f: &'a F) -> impl Iterator<Item = ((N, N), T)> + 'a
 where
     F: Fn(char) -> Option<T>,
@@ -0,0 +1,228 @@
+use fxhash::FxHashMap;
+use std::ops::{Div, Rem};
+
+/// The primitive integers, so `gcd` and `lcm` work with whatever type a puzzle uses
+pub trait Integer: Copy + Eq + Div<Output = Self> + Rem<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    /// The absolute value, which is the identity for unsigned types, or `None` for `Self::MIN`
+    /// of a signed type, since its absolute value doesn't fit
+    fn checked_magnitude(self) -> Option<Self>;
+}
+
+macro_rules! integer {
+    (signed: $($t:ty),*) => {
+        $(impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$t>::checked_mul(self, other)
+            }
+            fn checked_magnitude(self) -> Option<Self> {
+                self.checked_abs()
+            }
+        })*
+    };
+    (unsigned: $($t:ty),*) => {
+        $(impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$t>::checked_mul(self, other)
+            }
+            fn checked_magnitude(self) -> Option<Self> {
+                Some(self)
+            }
+        })*
+    };
+}
+
+integer!(signed: i8, i16, i32, i64, i128, isize);
+integer!(unsigned: u8, u16, u32, u64, u128, usize);
+
+/// The greatest common divisor, never negative. `gcd(0, 0)` is 0. Panics if the gcd doesn't fit
+/// in `T`, which only happens for `gcd(T::MIN, 0)` and `gcd(T::MIN, T::MIN)` of signed types.
+pub fn gcd<T: Integer>(mut a: T, mut b: T) -> T {
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a.checked_magnitude().expect("gcd overflows")
+}
+
+/// The least common multiple, or `None` if it doesn't fit in `T`
+pub fn lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    (a / gcd(a, b))
+        .checked_mul(b)
+        .and_then(T::checked_magnitude)
+}
+
+/// The gcd of all the numbers, 0 if there are none
+pub fn gcd_all<T: Integer>(numbers: impl IntoIterator<Item = T>) -> T {
+    numbers.into_iter().fold(T::ZERO, gcd)
+}
+
+/// The lcm of all the numbers, 1 if there are none, or `None` if it doesn't fit in `T`
+pub fn lcm_all<T: Integer>(numbers: impl IntoIterator<Item = T>) -> Option<T> {
+    numbers.into_iter().try_fold(T::ONE, lcm)
+}
+
+/// Extended Euclid: `(g, x, y)` where `g = gcd(a, b)` and `a * x + b * y = g`
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a as i128, b as i128);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (old_r, old_x, old_y) = (-old_r, -old_x, -old_y);
+    }
+    (old_r as i64, old_x as i64, old_y as i64)
+}
+
+/// `a * b % m`, in `0..m`, without overflowing
+pub fn mod_mul(a: i64, b: i64, m: i64) -> i64 {
+    (a as i128 * b as i128).rem_euclid(m as i128) as i64
+}
+
+/// `base` to the power of `exp`, modulo `m`, by repeated squaring
+pub fn mod_pow(base: i64, mut exp: u64, m: i64) -> i64 {
+    let mut base = base.rem_euclid(m);
+    let mut result = 1 % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mod_mul(result, base, m);
+        }
+        base = mod_mul(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+/// The `x` in `0..m` with `a * x % m == 1`, which only exists when `a` and `m` are coprime
+pub fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// Chinese remainder theorem: the `x` that is congruent to every `(remainder, modulus)`, along
+/// with the lcm of the moduli that every other solution differs from `x` by. The moduli don't
+/// need to be coprime, but then the congruences can contradict each other, which gives `None`,
+/// as does an lcm that doesn't fit in an `i64`, or a modulus that isn't positive.
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>) -> Option<(i64, i64)> {
+    congruences.into_iter().try_fold((0, 1), |(x, m), (r, n)| {
+        if n <= 0 {
+            return None;
+        }
+        let r = r.rem_euclid(n);
+        let (g, p, _) = extended_gcd(m, n);
+        let diff = r - x;
+        if diff % g != 0 {
+            return None;
+        }
+        let step = n / g;
+        let lcm = m.checked_mul(step)?;
+        // x + m * k solves both when m * k = diff (mod n), so k = (diff / g) * p (mod n / g)
+        let k = mod_mul(diff / g, p, step);
+        Some((
+            (x as i128 + m as i128 * k as i128).rem_euclid(lcm as i128) as i64,
+            lcm,
+        ))
+    })
+}
+
+/// Baby-step giant-step: the smallest `x >= 0` with `base^x % m == target % m`, for a `base`
+/// that is coprime with `m`. Takes time and memory in the order of the square root of `m`.
+pub fn discrete_log(base: i64, target: i64, m: i64) -> Option<i64> {
+    let target = target.rem_euclid(m);
+    let steps = (m as f64).sqrt().ceil() as i64;
+    let mut baby = FxHashMap::default();
+    let mut power = 1 % m;
+    for j in 0..steps {
+        baby.entry(power).or_insert(j);
+        power = mod_mul(power, base, m);
+    }
+    // Each giant step takes `steps` baby steps back by multiplying with base^-steps
+    let giant = mod_pow(mod_inverse(base, m)?, steps as u64, m);
+    let mut gamma = target;
+    for i in 0..steps {
+        if let Some(j) = baby.get(&gamma) {
+            return Some(i * steps + j);
+        }
+        gamma = mod_mul(gamma, giant, m);
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_and_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(0u8, 0), 0);
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(-4, 6), Some(12));
+        assert_eq!(lcm(u64::MAX, 2), None);
+        assert_eq!(gcd_all([12usize, 18, 27]), 3);
+        assert_eq!(lcm_all([2i64, 3, 4, 5]), Some(60));
+        assert_eq!(lcm_all(Vec::<u32>::new()), Some(1));
+        assert_eq!(lcm_all([1u8 << 4, 3, 5, 7]), None);
+        assert_eq!(gcd(i64::MIN, 6), 2);
+        assert_eq!(lcm(i64::MIN, 1), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "gcd overflows")]
+    fn test_gcd_overflow() {
+        gcd(i32::MIN, 0);
+    }
+
+    #[test]
+    fn test_modular() {
+        let (g, x, y) = extended_gcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+        assert_eq!(extended_gcd(-4, 6).0, 2);
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(4, 8), None);
+        assert_eq!(mod_pow(2, 10, 1000), 24);
+        assert_eq!(mod_pow(5, 0, 1), 0);
+        let big = i64::MAX - 24; // prime
+        assert_eq!(mod_pow(3, (big - 1) as u64, big), 1);
+        assert_eq!(mod_mul(big - 1, big - 1, big), 1);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // 2020 day 13: t % 17 == 0, (t + 2) % 13 == 0, (t + 3) % 19 == 0
+        assert_eq!(crt([(0, 17), (-2, 13), (-3, 19)]), Some((3417, 4199)));
+        assert_eq!(crt([(3, 4), (5, 6)]), Some((11, 12)));
+        assert_eq!(crt([(3, 4), (4, 6)]), None);
+        assert_eq!(crt([]), Some((0, 1)));
+        assert_eq!(crt([(1, i64::MAX), (0, 2)]), None);
+        assert_eq!(crt([(2, 3), (1, 0)]), None);
+        assert_eq!(crt([(2, 3), (1, -5)]), None);
+    }
+
+    #[test]
+    fn test_discrete_log() {
+        // The loop sizes from 2020 day 25
+        assert_eq!(discrete_log(7, 5764801, 20201227), Some(8));
+        assert_eq!(discrete_log(7, 17807724, 20201227), Some(11));
+        assert_eq!(discrete_log(3, 1, 7), Some(0));
+        assert_eq!(discrete_log(2, 3, 7), None);
+        assert_eq!(discrete_log(2, 5, 8), None);
+    }
+}
@@ -0,0 +1,280 @@
+use anyhow::{anyhow, Result};
+use std::collections::HashSet;
+
+type Glyph = (char, &'static [&'static str]);
+
+/// The font most puzzles draw with, 4 pixels wide (`Y` is 5) and 6 tall, with a blank
+/// column between letters. Leading blank columns are left out, which only matters for `I`.
+const SMALL: &[Glyph] = &[
+    ('A', &[".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', &["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', &[".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', &["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', &["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', &[".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', &["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', &["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+    ('J', &["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', &["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', &["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', &[".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', &["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', &["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', &[".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', &["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', &["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', &["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// The font from 2018 day 10, 6 pixels wide and 10 tall
+const LARGE: &[Glyph] = &[
+    (
+        'A',
+        &[
+            "..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'B',
+        &[
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#",
+            "#....#", "#####.",
+        ],
+    ),
+    (
+        'C',
+        &[
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#....#", ".####.",
+        ],
+    ),
+    (
+        'E',
+        &[
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+    (
+        'F',
+        &[
+            "######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "#.....",
+        ],
+    ),
+    (
+        'G',
+        &[
+            ".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#",
+            "#...##", ".###.#",
+        ],
+    ),
+    (
+        'H',
+        &[
+            "#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'J',
+        &[
+            "...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.",
+            "#...#.", ".###..",
+        ],
+    ),
+    (
+        'K',
+        &[
+            "#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..",
+            "#...#.", "#....#",
+        ],
+    ),
+    (
+        'L',
+        &[
+            "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+    (
+        'N',
+        &[
+            "#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##",
+            "#...##", "#....#",
+        ],
+    ),
+    (
+        'P',
+        &[
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....",
+            "#.....", "#.....",
+        ],
+    ),
+    (
+        'R',
+        &[
+            "#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'X',
+        &[
+            "#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.",
+            "#....#", "#....#",
+        ],
+    ),
+    (
+        'Z',
+        &[
+            "######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....",
+            "#.....", "######",
+        ],
+    ),
+];
+
+/// Draw the points with `#` on `.`, in the smallest rectangle that holds all of them
+pub fn render_points(points: impl IntoIterator<Item = (i64, i64)>) -> String {
+    let points: HashSet<_> = points.into_iter().collect();
+    let (Some(xmin), Some(xmax)) = (
+        points.iter().map(|p| p.0).min(),
+        points.iter().map(|p| p.0).max(),
+    ) else {
+        return String::new();
+    };
+    let ymin = points.iter().map(|p| p.1).min().unwrap_or_default();
+    let ymax = points.iter().map(|p| p.1).max().unwrap_or_default();
+    (ymin..=ymax)
+        .map(|y| {
+            (xmin..=xmax)
+                .map(|x| if points.contains(&(x, y)) { '#' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn matches(pixels: &[Vec<bool>], x: usize, glyph: &[&str]) -> bool {
+    glyph.iter().zip(pixels).all(|(glyph_row, row)| {
+        glyph_row
+            .chars()
+            .enumerate()
+            .all(|(dx, ch)| row.get(x + dx).copied().unwrap_or(false) == (ch == '#'))
+    })
+}
+
+/// Read the letters in an image where `#` is lit and anything else is dark, like the ones
+/// puzzles draw on pixel displays. Blank rows around the letters don't matter, and the font
+/// is picked by the height of what's left. Fails, showing the image, if any letter is unknown.
+pub fn read_letters(image: &str) -> Result<String> {
+    let rows: Vec<Vec<bool>> = image
+        .lines()
+        .map(|line| line.chars().map(|ch| ch == '#').collect())
+        .skip_while(|row: &Vec<bool>| !row.contains(&true))
+        .collect();
+    let height = rows
+        .iter()
+        .rposition(|row| row.contains(&true))
+        .map_or(0, |last| last + 1);
+    let pixels = &rows[..height];
+    let font = match height {
+        6 => SMALL,
+        10 => LARGE,
+        _ => return Err(anyhow!("No font is {height} pixels tall:\n{image}")),
+    };
+
+    let width = pixels.iter().map(Vec::len).max().unwrap_or_default();
+    let blank = |x: usize| {
+        pixels
+            .iter()
+            .all(|row| !row.get(x).copied().unwrap_or(false))
+    };
+    let mut letters = String::new();
+    let mut x = 0;
+    while x < width {
+        if blank(x) {
+            x += 1;
+            continue;
+        }
+        // Widest first, so a narrow letter can't match the start of a wider one
+        let (letter, glyph) = font
+            .iter()
+            .filter(|(_, glyph)| matches(pixels, x, glyph))
+            .max_by_key(|(_, glyph)| glyph[0].len())
+            .ok_or_else(|| anyhow!("Unknown letter at column {x}:\n{image}"))?;
+        letters.push(*letter);
+        x += glyph[0].len();
+    }
+    Ok(letters)
+}
+
+/// Read the letters drawn by a set of lit points
+pub fn read_points(points: impl IntoIterator<Item = (i64, i64)>) -> Result<String> {
+    read_letters(&render_points(points))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(font: &[Glyph], text: &str, gap: usize) -> String {
+        let height = font[0].1.len();
+        (0..height)
+            .map(|y| {
+                text.chars()
+                    .map(|letter| {
+                        let (_, glyph) = font.iter().find(|(ch, _)| *ch == letter).unwrap();
+                        glyph[y].replace('.', " ") + &" ".repeat(gap)
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_small_font() {
+        let all: String = SMALL.iter().map(|(ch, _)| ch).collect();
+        assert_eq!(read_letters(&write(SMALL, &all, 1)).unwrap(), all);
+        // Y fills the gap, so the next letter touches it
+        assert_eq!(read_letters(&write(SMALL, "YZYI", 0)).unwrap(), "YZYI");
+        let framed = format!("\n\n{}\n   \n", write(SMALL, "HI", 1));
+        assert_eq!(read_letters(&framed).unwrap(), "HI");
+    }
+
+    #[test]
+    fn test_large_font() {
+        let all: String = LARGE.iter().map(|(ch, _)| ch).collect();
+        assert_eq!(read_letters(&write(LARGE, &all, 2)).unwrap(), all);
+    }
+
+    #[test]
+    fn test_points() {
+        let points = [
+            (10, 5),
+            (11, 5),
+            (10, 6),
+            (10, 7),
+            (11, 7),
+            (10, 8),
+            (10, 9),
+        ]
+        .into_iter()
+        .chain([(10, 10), (11, 10), (12, 10), (13, 10)])
+        .chain([(12, 5), (13, 5), (12, 7)]);
+        assert_eq!(
+            render_points(points.clone()),
+            "####\n#...\n###.\n#...\n#...\n####"
+        );
+        assert_eq!(read_points(points).unwrap(), "E");
+    }
+
+    #[test]
+    fn test_unknown() {
+        let err = read_letters("#.#\n.#.\n#.#\n.#.\n#.#\n.#.").unwrap_err();
+        assert!(err.to_string().starts_with("Unknown letter at column 0"));
+        assert!(read_letters("#").is_err());
+    }
+}
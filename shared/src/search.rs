@@ -0,0 +1,465 @@
+use fxhash::{FxHashMap, FxHashSet};
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Costs of edges and paths, `Default` is the cost of standing still
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<T> Cost for T where T: Copy + Ord + Add<Output = T> + Default {}
+
+/// A path from the start to a goal, with both ends included
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Path<N, C> {
+    pub cost: C,
+    pub nodes: Vec<N>,
+}
+
+/// Every node a search reached, with the cost of the cheapest way there and where it came from.
+/// Nodes are numbered in the order they were found, so the start is 0.
+#[derive(Debug, Clone)]
+pub struct Reached<N, C> {
+    index: FxHashMap<N, usize>,
+    nodes: Vec<(N, C, usize)>,
+}
+
+impl<N: Eq + Hash + Clone, C: Cost> Reached<N, C> {
+    fn new(start: N) -> Self {
+        let mut index = FxHashMap::default();
+        index.insert(start.clone(), 0);
+        Reached {
+            index,
+            nodes: vec![(start, C::default(), 0)],
+        }
+    }
+
+    /// Record `node` if it's new, or reached cheaper than before, and return its number
+    fn improve(&mut self, node: N, cost: C, parent: usize) -> Option<usize> {
+        match self.index.entry(node) {
+            Entry::Occupied(entry) => {
+                let ix = *entry.get();
+                if cost < self.nodes[ix].1 {
+                    self.nodes[ix].1 = cost;
+                    self.nodes[ix].2 = parent;
+                    Some(ix)
+                } else {
+                    None
+                }
+            }
+            Entry::Vacant(entry) => {
+                let ix = self.nodes.len();
+                self.nodes.push((entry.key().clone(), cost, parent));
+                entry.insert(ix);
+                Some(ix)
+            }
+        }
+    }
+
+    fn path_from(&self, mut ix: usize) -> Path<N, C> {
+        let cost = self.nodes[ix].1;
+        let mut nodes = vec![self.nodes[ix].0.clone()];
+        while ix != 0 {
+            ix = self.nodes[ix].2;
+            nodes.push(self.nodes[ix].0.clone());
+        }
+        nodes.reverse();
+        Path { cost, nodes }
+    }
+
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.index.get(node).map(|&ix| self.nodes[ix].1)
+    }
+
+    /// The cheapest path from the start to `node`, if the search got there
+    pub fn path_to(&self, node: &N) -> Option<Path<N, C>> {
+        self.index.get(node).map(|&ix| self.path_from(ix))
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// All the nodes, with their costs, in the order they were found
+    pub fn iter(&self) -> impl Iterator<Item = (&N, C)> {
+        self.nodes.iter().map(|(node, cost, _)| (node, *cost))
+    }
+}
+
+fn breadth_first<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+) -> (Reached<N, usize>, Option<usize>)
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut reached = Reached::new(start);
+    let mut work = VecDeque::from([0]);
+    while let Some(ix) = work.pop_front() {
+        let (node, cost, _) = &reached.nodes[ix];
+        if goal(node) {
+            return (reached, Some(ix));
+        }
+        let cost = cost + 1;
+        for next in successors(&node.clone()) {
+            // With equal edges, the first time we see a node is the cheapest
+            if !reached.index.contains_key(&next) {
+                work.extend(reached.improve(next, cost, ix));
+            }
+        }
+    }
+    (reached, None)
+}
+
+/// The shortest path to a node where `goal` is true, counting each step as 1
+pub fn bfs<N, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let (reached, found) = breadth_first(start, successors, goal);
+    found.map(|ix| reached.path_from(ix))
+}
+
+/// The number of steps to every node that can be reached from `start`
+pub fn bfs_all<N, I>(start: N, successors: impl FnMut(&N) -> I) -> Reached<N, usize>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    breadth_first(start, successors, |_| false).0
+}
+
+/// Best first search, which is Dijkstra when the heuristic is always 0, and A* otherwise
+fn best_first<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut goal: impl FnMut(&N) -> bool,
+) -> (Reached<N, C>, Option<usize>)
+where
+    N: Eq + Hash + Clone,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut reached = Reached::new(start.clone());
+    let mut work = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+    while let Some(Reverse((_, cost, ix))) = work.pop() {
+        let node = reached.nodes[ix].0.clone();
+        if cost > reached.nodes[ix].1 {
+            // Found a cheaper way here after this was queued
+            continue;
+        }
+        if goal(&node) {
+            return (reached, Some(ix));
+        }
+        for (next, step) in successors(&node) {
+            let estimate = heuristic(&next);
+            if let Some(next_ix) = reached.improve(next, cost + step, ix) {
+                work.push(Reverse((cost + step + estimate, cost + step, next_ix)));
+            }
+        }
+    }
+    (reached, None)
+}
+
+/// The cheapest path to a node where `goal` is true. `successors` gives the neighbours of a
+/// node along with the cost of going there, which must not be negative.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (reached, found) = best_first(start, successors, |_| C::default(), goal);
+    found.map(|ix| reached.path_from(ix))
+}
+
+/// The cost of the cheapest path to every node that can be reached from `start`
+pub fn dijkstra_all<N, C, I>(start: N, successors: impl FnMut(&N) -> I) -> Reached<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    best_first(start, successors, |_| C::default(), |_| false).0
+}
+
+/// Like `dijkstra`, but looks at the nodes that `heuristic` thinks are closer to the goal
+/// first. The path is only the cheapest if the heuristic never guesses too high, like the
+/// Manhattan distance on a grid.
+pub fn astar<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (reached, found) = best_first(start, successors, heuristic, goal);
+    found.map(|ix| reached.path_from(ix))
+}
+
+/// Every cheapest path to the goal, as a graph where each node knows all the nodes it can be
+/// reached from at the lowest cost
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<N, C> {
+    pub cost: C,
+    nodes: Vec<(N, C, Vec<usize>)>,
+    goals: Vec<usize>,
+}
+
+impl<N: Eq + Hash + Clone, C: Cost> ShortestPaths<N, C> {
+    /// The goal nodes that can be reached at the lowest cost
+    pub fn goals(&self) -> impl Iterator<Item = &N> {
+        self.goals.iter().map(|&ix| &self.nodes[ix].0)
+    }
+
+    fn on_paths(&self) -> Vec<usize> {
+        let mut seen = vec![false; self.nodes.len()];
+        let mut work = self.goals.clone();
+        let mut out = Vec::new();
+        while let Some(ix) = work.pop() {
+            if !std::mem::replace(&mut seen[ix], true) {
+                out.push(ix);
+                work.extend(&self.nodes[ix].2);
+            }
+        }
+        out
+    }
+
+    /// All the nodes that are on at least one of the cheapest paths
+    pub fn nodes(&self) -> FxHashSet<&N> {
+        self.on_paths()
+            .into_iter()
+            .map(|ix| &self.nodes[ix].0)
+            .collect()
+    }
+
+    /// How many different cheapest paths there are
+    pub fn count(&self) -> u64 {
+        let mut counts: Vec<Option<u64>> = vec![None; self.nodes.len()];
+        let mut work = self.goals.clone();
+        // Count the paths to the parents of a node before the node itself
+        while let Some(&ix) = work.last() {
+            let parents = &self.nodes[ix].2;
+            let pending: Vec<_> = parents.iter().filter(|&&p| counts[p].is_none()).collect();
+            if counts[ix].is_some() {
+                work.pop();
+            } else if pending.is_empty() {
+                counts[ix] = Some(if parents.is_empty() {
+                    1
+                } else {
+                    parents.iter().filter_map(|&p| counts[p]).sum()
+                });
+                work.pop();
+            } else {
+                work.extend(pending);
+            }
+        }
+        self.goals.iter().filter_map(|&ix| counts[ix]).sum()
+    }
+
+    /// One of the cheapest paths
+    pub fn path(&self) -> Path<N, C> {
+        let mut ix = self.goals[0];
+        let mut nodes = vec![self.nodes[ix].0.clone()];
+        while let Some(&parent) = self.nodes[ix].2.first() {
+            ix = parent;
+            nodes.push(self.nodes[ix].0.clone());
+        }
+        nodes.reverse();
+        Path {
+            cost: self.cost,
+            nodes,
+        }
+    }
+}
+
+/// Like `dijkstra`, but keeps track of every way to reach each node at the lowest cost, so
+/// that all the cheapest paths to the goal can be found. Every step must cost more than 0: with
+/// a cycle that costs nothing there would be endlessly many cheapest paths.
+pub fn all_shortest_paths<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Option<ShortestPaths<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut index = FxHashMap::default();
+    index.insert(start.clone(), 0);
+    let mut nodes = vec![(start, C::default(), Vec::new())];
+    let mut work = BinaryHeap::from([Reverse((C::default(), 0))]);
+    let mut goals = Vec::new();
+    let mut best = None;
+
+    while let Some(Reverse((cost, ix))) = work.pop() {
+        if cost > nodes[ix].1 {
+            continue;
+        }
+        if best.is_some_and(|best| cost > best) {
+            break;
+        }
+        let node = nodes[ix].0.clone();
+        if goal(&node) {
+            best = Some(cost);
+            goals.push(ix);
+            continue;
+        }
+        for (next, step) in successors(&node) {
+            assert!(
+                step > C::default(),
+                "all_shortest_paths needs steps that cost more than 0"
+            );
+            let next_cost = cost + step;
+            match index.entry(next) {
+                Entry::Vacant(entry) => {
+                    let next_ix = nodes.len();
+                    nodes.push((entry.key().clone(), next_cost, vec![ix]));
+                    entry.insert(next_ix);
+                    work.push(Reverse((next_cost, next_ix)));
+                }
+                Entry::Occupied(entry) => {
+                    let next_ix = *entry.get();
+                    let (_, known, parents) = &mut nodes[next_ix];
+                    if next_cost < *known {
+                        *known = next_cost;
+                        *parents = vec![ix];
+                        work.push(Reverse((next_cost, next_ix)));
+                    } else if next_cost == *known && !parents.contains(&ix) {
+                        parents.push(ix);
+                    }
+                }
+            }
+        }
+    }
+
+    Some(ShortestPaths {
+        cost: best?,
+        nodes,
+        goals,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAZE: &str = "\
+#######
+#...#.#
+#S#...#
+#...#E#
+#######";
+
+    type Pos = (i32, i32);
+
+    fn parse() -> (FxHashSet<Pos>, Pos, Pos) {
+        let cells: Vec<_> = MAZE
+            .lines()
+            .enumerate()
+            .flat_map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(move |(x, ch)| ((x as i32, y as i32), ch))
+            })
+            .collect();
+        let find = |target| cells.iter().find(|(_, ch)| *ch == target).unwrap().0;
+        let open = cells
+            .iter()
+            .filter(|(_, ch)| *ch != '#')
+            .map(|(pos, _)| *pos)
+            .collect();
+        (open, find('S'), find('E'))
+    }
+
+    fn neighbours(open: &FxHashSet<Pos>, (x, y): Pos) -> Vec<Pos> {
+        [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+            .into_iter()
+            .filter(|pos| open.contains(pos))
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let (open, start, end) = parse();
+        let path = bfs(start, |&pos| neighbours(&open, pos), |&pos| pos == end).unwrap();
+        assert_eq!(path.cost, 7);
+        assert_eq!(path.nodes.len(), 8);
+        assert_eq!((path.nodes[0], path.nodes[7]), (start, end));
+        let all = bfs_all(start, |&pos| neighbours(&open, pos));
+        assert_eq!(all.len(), open.len());
+        assert_eq!(all.cost(&(5, 1)), Some(7));
+        assert!(bfs(start, |&pos| neighbours(&open, pos), |_| false).is_none());
+    }
+
+    #[test]
+    fn test_weighted() {
+        let (open, start, end) = parse();
+        // Going down costs 10
+        let successors = |&(x, y): &Pos| {
+            neighbours(&open, (x, y))
+                .into_iter()
+                .map(move |(nx, ny)| ((nx, ny), if ny > y { 10 } else { 1 }))
+        };
+        let path = dijkstra(start, successors, |&pos| pos == end).unwrap();
+        assert_eq!(path.cost, 25);
+        let manhattan = |&(x, y): &Pos| (end.0 - x).abs() + (end.1 - y).abs();
+        let guided = astar(start, successors, manhattan, |&pos| pos == end).unwrap();
+        assert_eq!(guided.cost, 25);
+        assert_eq!(dijkstra_all(start, successors).cost(&end), Some(25));
+    }
+
+    #[test]
+    fn test_all_shortest_paths() {
+        let (open, start, end) = parse();
+        let paths = all_shortest_paths(
+            start,
+            |&pos| neighbours(&open, pos).into_iter().map(|next| (next, 1)),
+            |&pos| pos == end,
+        )
+        .unwrap();
+        assert_eq!(paths.cost, 7);
+        // Around either side of the pillar at (2, 2), then the same way to the end
+        assert_eq!(paths.count(), 2);
+        assert_eq!(paths.nodes().len(), 11);
+        assert_eq!(paths.path().nodes.len(), 8);
+        assert_eq!(paths.goals().collect::<Vec<_>>(), vec![&end]);
+    }
+
+    #[test]
+    #[should_panic(expected = "steps that cost more than 0")]
+    fn test_all_shortest_paths_rejects_free_steps() {
+        // Going back and forth between 0 and 1 for free would make endlessly many paths to 2
+        all_shortest_paths(
+            0,
+            |&n: &u8| match n {
+                0 => vec![(1, 0)],
+                1 => vec![(0, 0), (2, 1)],
+                _ => vec![],
+            },
+            |&n| n == 2,
+        );
+    }
+}
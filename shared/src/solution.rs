@@ -0,0 +1,356 @@
+use crate::AnswerValue;
+use anyhow::{anyhow, Result};
+use std::borrow::Borrow;
+use std::marker::PhantomData;
+
+pub type PartFn = fn(&str) -> Result<AnswerValue>;
+
+/// A solution for one day of advent of code. Solutions are registered in a slice for each
+/// year, in any order, and days without a solution are simply left out.
+pub trait Solution: Sync {
+    fn year(&self) -> u16;
+
+    fn day(&self) -> u8;
+
+    /// The title of the puzzle, if the solution knows it
+    fn title(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// How many parts are solved, 1 or 2
+    fn parts(&self) -> u8;
+
+    /// Whether the parts share a parse step, which is then worth timing on its own
+    fn parses_once(&self) -> bool {
+        false
+    }
+
+    /// Do the work that both parts share, like parsing the input
+    fn prepare<'a>(&self, input: &'a str) -> Result<Box<dyn Prepared + 'a>>;
+
+    /// Run a single part, from scratch
+    fn run(&self, part: u8, input: &str) -> Result<AnswerValue> {
+        self.prepare(input)?.part(part)
+    }
+}
+
+/// A day that is ready to answer each part
+pub trait Prepared {
+    fn part(&self, part: u8) -> Result<AnswerValue>;
+}
+
+/// A solution where each part takes the input as it is, and parses it on its own
+#[derive(Debug, Copy, Clone)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub title: Option<&'static str>,
+    pub part_1: PartFn,
+    pub part_2: Option<PartFn>,
+}
+
+impl Day {
+    pub const fn both(year: u16, day: u8, part_1: PartFn, part_2: PartFn) -> Day {
+        Day {
+            year,
+            day,
+            title: None,
+            part_1,
+            part_2: Some(part_2),
+        }
+    }
+
+    /// A day where only part 1 is solved so far
+    pub const fn one(year: u16, day: u8, part_1: PartFn) -> Day {
+        Day {
+            year,
+            day,
+            title: None,
+            part_1,
+            part_2: None,
+        }
+    }
+
+    pub const fn titled(self, title: &'static str) -> Day {
+        Day {
+            title: Some(title),
+            ..self
+        }
+    }
+
+    fn part_fn(&self, part: u8) -> Result<PartFn> {
+        match (part, self.part_2) {
+            (1, _) => Ok(self.part_1),
+            (2, Some(part_2)) => Ok(part_2),
+            _ => Err(anyhow!(
+                "No solution for {} day {} part {part}",
+                self.year,
+                self.day
+            )),
+        }
+    }
+}
+
+struct Unparsed<'a> {
+    day: Day,
+    input: &'a str,
+}
+
+impl Prepared for Unparsed<'_> {
+    fn part(&self, part: u8) -> Result<AnswerValue> {
+        (self.day.part_fn(part)?)(self.input)
+    }
+}
+
+impl Solution for Day {
+    fn year(&self) -> u16 {
+        self.year
+    }
+
+    fn day(&self) -> u8 {
+        self.day
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        self.title
+    }
+
+    fn parts(&self) -> u8 {
+        if self.part_2.is_some() {
+            2
+        } else {
+            1
+        }
+    }
+
+    fn prepare<'a>(&self, input: &'a str) -> Result<Box<dyn Prepared + 'a>> {
+        Ok(Box::new(Unparsed { day: *self, input }))
+    }
+
+    fn run(&self, part: u8, input: &str) -> Result<AnswerValue> {
+        (self.part_fn(part)?)(input)
+    }
+}
+
+/// What a day parses its input into. This is a family of types, one for each lifetime of the
+/// input, so that the parsed value can borrow from the input instead of copying it. The parts
+/// see the parsed value as `View`, which is usually the same type, or a slice of a parsed `Vec`.
+pub trait Input: 'static {
+    type Of<'a>: Borrow<Self::View<'a>> + 'a;
+    type View<'a>: ?Sized;
+}
+
+/// The `Input` of days that parse into a type that doesn't borrow from the input
+pub struct Owned<T>(PhantomData<T>);
+
+impl<T: 'static> Input for Owned<T> {
+    type Of<'a> = T;
+    type View<'a> = T;
+}
+
+/// The `Input` of days that parse into a `Vec` of values that don't borrow from the input,
+/// where the parts take a slice
+pub struct List<T>(PhantomData<T>);
+
+impl<T: 'static> Input for List<T> {
+    type Of<'a> = Vec<T>;
+    type View<'a> = [T];
+}
+
+pub type ParseFn<I> = for<'a> fn(&'a str) -> Result<<I as Input>::Of<'a>>;
+
+pub type ParsedPartFn<I> = for<'a, 'b> fn(&'b <I as Input>::View<'a>) -> Result<AnswerValue>;
+
+/// A solution that parses the input once, and hands the parsed value to both parts
+pub struct Parsed<I: Input> {
+    pub year: u16,
+    pub day: u8,
+    pub title: Option<&'static str>,
+    pub parse: ParseFn<I>,
+    pub part_1: ParsedPartFn<I>,
+    pub part_2: Option<ParsedPartFn<I>>,
+}
+
+impl<I: Input> Parsed<I> {
+    pub const fn both(
+        year: u16,
+        day: u8,
+        parse: ParseFn<I>,
+        part_1: ParsedPartFn<I>,
+        part_2: ParsedPartFn<I>,
+    ) -> Parsed<I> {
+        Parsed {
+            year,
+            day,
+            title: None,
+            parse,
+            part_1,
+            part_2: Some(part_2),
+        }
+    }
+
+    pub const fn one(year: u16, day: u8, parse: ParseFn<I>, part_1: ParsedPartFn<I>) -> Parsed<I> {
+        Parsed {
+            year,
+            day,
+            title: None,
+            parse,
+            part_1,
+            part_2: None,
+        }
+    }
+
+    pub const fn titled(self, title: &'static str) -> Parsed<I> {
+        Parsed {
+            title: Some(title),
+            ..self
+        }
+    }
+}
+
+struct ParsedInput<'a, I: Input> {
+    year: u16,
+    day: u8,
+    parsed: I::Of<'a>,
+    part_1: ParsedPartFn<I>,
+    part_2: Option<ParsedPartFn<I>>,
+}
+
+impl<I: Input> Prepared for ParsedInput<'_, I> {
+    fn part(&self, part: u8) -> Result<AnswerValue> {
+        match (part, self.part_2) {
+            (1, _) => (self.part_1)(self.parsed.borrow()),
+            (2, Some(part_2)) => part_2(self.parsed.borrow()),
+            _ => Err(anyhow!(
+                "No solution for {} day {} part {part}",
+                self.year,
+                self.day
+            )),
+        }
+    }
+}
+
+impl<I: Input> Solution for Parsed<I> {
+    fn year(&self) -> u16 {
+        self.year
+    }
+
+    fn day(&self) -> u8 {
+        self.day
+    }
+
+    fn title(&self) -> Option<&'static str> {
+        self.title
+    }
+
+    fn parts(&self) -> u8 {
+        if self.part_2.is_some() {
+            2
+        } else {
+            1
+        }
+    }
+
+    fn parses_once(&self) -> bool {
+        true
+    }
+
+    fn prepare<'a>(&self, input: &'a str) -> Result<Box<dyn Prepared + 'a>> {
+        Ok(Box::new(ParsedInput::<'a, I> {
+            year: self.year,
+            day: self.day,
+            parsed: (self.parse)(input)?,
+            part_1: self.part_1,
+            part_2: self.part_2,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn length(input: &str) -> Result<AnswerValue> {
+        Ok(input.len().into())
+    }
+
+    fn lines(input: &str) -> Result<AnswerValue> {
+        Ok(input.lines().count().into())
+    }
+
+    struct Words<'a>(Vec<&'a str>);
+
+    impl Input for Words<'static> {
+        type Of<'a> = Words<'a>;
+        type View<'a> = Words<'a>;
+    }
+
+    fn words(input: &str) -> Result<Words<'_>> {
+        Ok(Words(input.split_whitespace().collect()))
+    }
+
+    fn longest(words: &Words) -> Result<AnswerValue> {
+        Ok(words
+            .0
+            .iter()
+            .map(|word| word.len())
+            .max()
+            .unwrap_or(0)
+            .into())
+    }
+
+    fn count(words: &Words) -> Result<AnswerValue> {
+        Ok(words.0.len().into())
+    }
+
+    fn numbers(input: &str) -> Result<Vec<u32>> {
+        Ok(input
+            .split_whitespace()
+            .map(str::parse)
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn sum(numbers: &[u32]) -> Result<AnswerValue> {
+        Ok(numbers.iter().sum::<u32>().into())
+    }
+
+    const SOLUTIONS: &[&dyn Solution] = &[
+        &Day::both(2016, 1, length, lines).titled("No Time for a Taxicab"),
+        &Day::one(2016, 3, length),
+        &Parsed::<Words>::both(2016, 4, words, longest, count),
+        &Parsed::<List<u32>>::one(2016, 5, numbers, sum),
+    ];
+
+    #[test]
+    fn test_registered_days() {
+        let days: Vec<_> = SOLUTIONS.iter().map(|s| s.day()).collect();
+        assert_eq!(days, vec![1, 3, 4, 5]);
+        assert_eq!(SOLUTIONS[0].title(), Some("No Time for a Taxicab"));
+        assert_eq!(SOLUTIONS[1].parts(), 1);
+    }
+
+    #[test]
+    fn test_run_parts() {
+        let prepared = SOLUTIONS[0].prepare("ab\ncd").unwrap();
+        assert_eq!(prepared.part(1).unwrap(), AnswerValue::Integer(5));
+        assert_eq!(prepared.part(2).unwrap(), AnswerValue::Integer(2));
+        assert!(SOLUTIONS[1].run(2, "ab").is_err());
+    }
+
+    #[test]
+    fn test_parse_once() {
+        assert!(!SOLUTIONS[0].parses_once());
+        assert!(SOLUTIONS[2].parses_once());
+        let prepared = SOLUTIONS[2].prepare("a bcd ef").unwrap();
+        assert_eq!(prepared.part(1).unwrap(), AnswerValue::Integer(3));
+        assert_eq!(prepared.part(2).unwrap(), AnswerValue::Integer(3));
+        assert_eq!(
+            SOLUTIONS[2].run(1, "abcde").unwrap(),
+            AnswerValue::Integer(5)
+        );
+        assert_eq!(
+            SOLUTIONS[3].run(1, "1 2 3").unwrap(),
+            AnswerValue::Integer(6)
+        );
+    }
+}
@@ -1,14 +1,15 @@
 use anyhow::Context;
+use shared::AnswerValue;
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
+pub fn part_1(s: &str) -> anyhow::Result<AnswerValue> {
     Ok(s.chars()
         .fold(0, |floor, ch| {
             floor + i32::from(ch == '(') - i32::from(ch == ')')
         })
-        .to_string())
+        .into())
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
+pub fn part_2(s: &str) -> anyhow::Result<AnswerValue> {
     s.chars()
         .scan(1i32, |floor, ch| {
             *floor += i32::from(ch == '(') - i32::from(ch == ')');
@@ -16,6 +17,6 @@ pub fn part_2(s: &str) -> anyhow::Result<String> {
         })
         .enumerate()
         .find(|(_, floor)| *floor < 0)
-        .map(|(ix, _)| ix.to_string())
+        .map(|(ix, _)| ix.into())
         .context("Unable to find basement")
 }
@@ -4,6 +4,7 @@ use nom::combinator::map_res;
 use nom::multi::separated_list1;
 use nom::sequence::terminated;
 use nom::IResult;
+use shared::AnswerValue;
 
 fn parse(s: &str) -> IResult<&str, [u32; 3]> {
     let (s, h) = map_res(terminated(digit1, char('x')), |s: &str| s.parse())(s)?;
@@ -20,7 +21,7 @@ fn presents(s: &str) -> anyhow::Result<Vec<[u32; 3]>> {
         .map(|(_, p)| p)
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
+pub fn part_1(s: &str) -> anyhow::Result<AnswerValue> {
     Ok(presents(s)?
         .into_iter()
         .map(|p| {
@@ -28,10 +29,10 @@ pub fn part_1(s: &str) -> anyhow::Result<String> {
             h * w + 2 * l * w + 2 * w * h + 2 * l * h
         })
         .sum::<u32>()
-        .to_string())
+        .into())
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
+pub fn part_2(s: &str) -> anyhow::Result<AnswerValue> {
     Ok(presents(s)?
         .into_iter()
         .map(|p| {
@@ -39,7 +40,7 @@ pub fn part_2(s: &str) -> anyhow::Result<String> {
             h * w * l + 2 * h + 2 * w
         })
         .sum::<u32>()
-        .to_string())
+        .into())
 }
 
 #[cfg(test)]
@@ -47,10 +48,13 @@ mod tests {
     use super::*;
     #[test]
     fn test_p1() {
-        assert_eq!(part_1("2x3x4\n1x1x10\n").unwrap().as_str(), "101");
+        assert_eq!(
+            part_1("2x3x4\n1x1x10\n").unwrap(),
+            AnswerValue::Integer(101)
+        );
     }
     #[test]
     fn test_p2() {
-        assert_eq!(part_2("2x3x4\n1x1x10\n").unwrap().as_str(), "48");
+        assert_eq!(part_2("2x3x4\n1x1x10\n").unwrap(), AnswerValue::Integer(48));
     }
 }
@@ -1,4 +1,5 @@
 use fxhash::FxHashSet;
+use shared::AnswerValue;
 use std::iter::once;
 
 fn traverse(s: impl Iterator<Item = char>) -> FxHashSet<(i32, i32)> {
@@ -11,17 +12,17 @@ fn traverse(s: impl Iterator<Item = char>) -> FxHashSet<(i32, i32)> {
     .collect()
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
-    Ok(traverse(s.chars()).len().to_string())
+pub fn part_1(s: &str) -> anyhow::Result<AnswerValue> {
+    Ok(traverse(s.chars()).len().into())
 }
 
 fn rem_2(n: usize) -> impl Fn((usize, char)) -> Option<char> {
     move |(ix, ch)| if ix % 2 == n { Some(ch) } else { None }
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
+pub fn part_2(s: &str) -> anyhow::Result<AnswerValue> {
     let santa = traverse(s.chars().enumerate().filter_map(rem_2(0)));
     let robot = traverse(s.chars().enumerate().filter_map(rem_2(1)));
     let union: FxHashSet<_> = santa.union(&robot).collect();
-    Ok(union.len().to_string())
+    Ok(union.len().into())
 }
@@ -1,4 +1,5 @@
 use rayon::prelude::*;
+use shared::AnswerValue;
 
 const CHUNK: usize = 16_000;
 
@@ -23,12 +24,12 @@ fn md5pariter(k: &str, mask: u8) -> usize {
     unreachable!()
 }
 
-pub fn part_1(k: &str) -> anyhow::Result<String> {
-    Ok(md5pariter(k.trim(), 0xf0).to_string())
+pub fn part_1(k: &str) -> anyhow::Result<AnswerValue> {
+    Ok(md5pariter(k.trim(), 0xf0).into())
 }
 
-pub fn part_2(k: &str) -> anyhow::Result<String> {
-    Ok(md5pariter(k.trim(), 0xff).to_string())
+pub fn part_2(k: &str) -> anyhow::Result<AnswerValue> {
+    Ok(md5pariter(k.trim(), 0xff).into())
 }
 
 #[cfg(test)]
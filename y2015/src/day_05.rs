@@ -1,6 +1,8 @@
+use shared::AnswerValue;
+
 const VOWELS: [char; 5] = ['a', 'e', 'i', 'o', 'u'];
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
+pub fn part_1(s: &str) -> anyhow::Result<AnswerValue> {
     let n = s
         .lines()
         .filter(|word| {
@@ -21,7 +23,7 @@ pub fn part_1(s: &str) -> anyhow::Result<String> {
             (vowels >= 3) && twice
         })
         .count();
-    Ok(n.to_string())
+    Ok(n.into())
 }
 
 fn pair_twice(s: &str) -> bool {
@@ -43,12 +45,12 @@ fn repeats(s: &str) -> bool {
     false
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
+pub fn part_2(s: &str) -> anyhow::Result<AnswerValue> {
     let n = s
         .lines()
         .filter(|word| pair_twice(word) && repeats(word))
         .count();
-    Ok(n.to_string())
+    Ok(n.into())
 }
 
 #[cfg(test)]
@@ -7,6 +7,7 @@ use nom::multi::separated_list1;
 use nom::sequence::preceded;
 use nom::IResult;
 use rayon::prelude::*;
+use shared::AnswerValue;
 
 #[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Copy, Clone)]
 enum Command {
@@ -16,7 +17,7 @@ enum Command {
 }
 
 #[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Copy, Clone)]
-struct CommandSegment {
+pub struct CommandSegment {
     command: Command,
     xmin: u16,
     xmax: u16,
@@ -54,7 +55,7 @@ fn parse_command_segment(s: &str) -> IResult<&str, CommandSegment> {
     ))
 }
 
-fn parse(s: &str) -> anyhow::Result<Vec<CommandSegment>> {
+pub fn parse(s: &str) -> anyhow::Result<Vec<CommandSegment>> {
     separated_list1(char('\n'), parse_command_segment)(s)
         .map_err(|err| anyhow!("{err}"))
         .map(|(_, s)| s)
@@ -118,14 +119,12 @@ fn p2_score(seg: &[u16]) -> usize {
     s
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
-    let commands = parse(s)?;
-    Ok(grade_segments(&p1_state_change, p1_score, &commands).to_string())
+pub fn part_1(commands: &[CommandSegment]) -> anyhow::Result<AnswerValue> {
+    Ok(grade_segments(&p1_state_change, p1_score, commands).into())
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
-    let commands = parse(s)?;
-    Ok(grade_segments(&p2_state_change, p2_score, &commands).to_string())
+pub fn part_2(commands: &[CommandSegment]) -> anyhow::Result<AnswerValue> {
+    Ok(grade_segments(&p2_state_change, p2_score, commands).into())
 }
 
 #[cfg(test)]
@@ -135,8 +134,8 @@ mod tests {
     #[test]
     fn test_ex() {
         assert_eq!(
-            part_1("turn on 499,499 through 500,500").unwrap().as_str(),
-            "4"
+            part_1(&parse("turn on 499,499 through 500,500").unwrap()).unwrap(),
+            AnswerValue::Integer(4)
         );
     }
 }
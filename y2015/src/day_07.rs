@@ -7,6 +7,7 @@ use nom::combinator::{map, map_res};
 use nom::multi::separated_list1;
 use nom::sequence::{preceded, separated_pair, terminated, tuple};
 use nom::IResult;
+use shared::AnswerValue;
 
 #[derive(Copy, Clone, Debug)]
 enum Atom<'a> {
@@ -149,17 +150,17 @@ fn read(s: &str) -> anyhow::Result<FxHashMap<&str, Expr<'_>>> {
     Ok(m)
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
+pub fn part_1(s: &str) -> anyhow::Result<AnswerValue> {
     let by_wire = read(s)?;
     let ans = evaluate("a", &by_wire, &mut FxHashMap::default())?;
-    Ok(ans.to_string())
+    Ok(ans.into())
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
+pub fn part_2(s: &str) -> anyhow::Result<AnswerValue> {
     let by_wire = read(s)?;
     let ans = evaluate("a", &by_wire, &mut FxHashMap::default())?;
     let ans = evaluate("a", &by_wire, &mut FxHashMap::from_iter([("b", ans)]))?;
-    Ok(ans.to_string())
+    Ok(ans.into())
 }
 
 #[cfg(test)]
@@ -6,6 +6,7 @@ use nom::combinator::{map, recognize};
 use nom::multi::many0;
 use nom::sequence::{pair, preceded};
 use nom::IResult;
+use shared::{AnswerValue, Input};
 
 enum Lexeme<'a> {
     Escaped(&'a str),
@@ -63,37 +64,42 @@ impl<'a> Lexeme<'a> {
     }
 }
 
-fn lengthdiff<'a>(lexemes: impl Iterator<Item = Lexeme<'a>>) -> usize {
-    lexemes.map(|l| l.escaped_len() - l.len()).sum::<usize>() + 2 // open and close "
+fn lengthdiff(lexemes: &[Lexeme]) -> usize {
+    lexemes
+        .iter()
+        .map(|l| l.escaped_len() - l.len())
+        .sum::<usize>()
+        + 2 // open and close "
 }
 
-fn p2_lengthdiff<'a>(lexemes: impl Iterator<Item = Lexeme<'a>>) -> usize {
+fn p2_lengthdiff(lexemes: &[Lexeme]) -> usize {
     4 + // "" -> "\"\""
-    lexemes.map(|l| l.escape_it_len() - l.escaped_len()).sum::<usize>()
+    lexemes.iter().map(|l| l.escape_it_len() - l.escaped_len()).sum::<usize>()
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
-    let r: anyhow::Result<Vec<_>> = s
-        .lines()
-        .map(|s| {
-            let lex = parse(s)?;
-            Ok(lengthdiff(lex.into_iter()))
-        })
-        .collect();
-    let r: usize = r?.iter().sum();
-    Ok(r.to_string())
+/// The lexemes of each line of the input
+pub struct Lines<'a>(Vec<Vec<Lexeme<'a>>>);
+
+impl Input for Lines<'static> {
+    type Of<'a> = Lines<'a>;
+    type View<'a> = Lines<'a>;
+}
+
+pub fn parse_lines(s: &str) -> anyhow::Result<Lines<'_>> {
+    s.lines()
+        .map(parse)
+        .collect::<anyhow::Result<_>>()
+        .map(Lines)
+}
+
+pub fn part_1(lines: &Lines) -> anyhow::Result<AnswerValue> {
+    let r: usize = lines.0.iter().map(|lex| lengthdiff(lex)).sum();
+    Ok(r.into())
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
-    let r: anyhow::Result<Vec<_>> = s
-        .lines()
-        .map(|s| {
-            let lex = parse(s)?;
-            Ok(p2_lengthdiff(lex.into_iter()))
-        })
-        .collect();
-    let r: usize = r?.iter().sum();
-    Ok(r.to_string())
+pub fn part_2(lines: &Lines) -> anyhow::Result<AnswerValue> {
+    let r: usize = lines.0.iter().map(|lex| p2_lengthdiff(lex)).sum();
+    Ok(r.into())
 }
 
 #[cfg(test)]
@@ -103,24 +109,24 @@ mod test {
     #[test]
     fn test_parse() {
         let s = parse("").unwrap();
-        assert_eq!(lengthdiff(s.into_iter()), 2);
+        assert_eq!(lengthdiff(&s), 2);
         let s = parse("abc").unwrap();
-        assert_eq!(lengthdiff(s.into_iter()), 2);
+        assert_eq!(lengthdiff(&s), 2);
         let s = parse("aaa\\\"aaa").unwrap();
-        assert_eq!(lengthdiff(s.into_iter()), 3);
+        assert_eq!(lengthdiff(&s), 3);
         let s = parse("\\x27").unwrap();
-        assert_eq!(lengthdiff(s.into_iter()), 5);
+        assert_eq!(lengthdiff(&s), 5);
     }
 
     #[test]
     fn test_unparse() {
         let s = parse("").unwrap();
-        assert_eq!(p2_lengthdiff(s.into_iter()), 4);
+        assert_eq!(p2_lengthdiff(&s), 4);
         let s = parse("abc").unwrap();
-        assert_eq!(p2_lengthdiff(s.into_iter()), 4);
+        assert_eq!(p2_lengthdiff(&s), 4);
         let s = parse("aaa\\\"aaa").unwrap();
-        assert_eq!(p2_lengthdiff(s.into_iter()), 6);
+        assert_eq!(p2_lengthdiff(&s), 6);
         let s = parse("\\x27").unwrap();
-        assert_eq!(p2_lengthdiff(s.into_iter()), 5);
+        assert_eq!(p2_lengthdiff(&s), 5);
     }
 }
@@ -1,8 +1,9 @@
 use anyhow::Context;
 use fxhash::{FxHashMap, FxHashSet};
 use regex::Regex;
+use shared::AnswerValue;
 
-fn parse(s: &str) -> anyhow::Result<Vec<Vec<Option<usize>>>> {
+pub fn parse(s: &str) -> anyhow::Result<Vec<Vec<Option<usize>>>> {
     let re = Regex::new(r"^([^ ]+) to ([^ ]+) = ([0-9]+)")?;
     let mut interned = FxHashMap::default();
     for line in s.lines() {
@@ -64,24 +65,22 @@ fn distances(graph: &[Vec<Option<usize>>]) -> FxHashSet<usize> {
     found
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
-    let graph = parse(s)?;
-    let distances = distances(&graph);
+pub fn part_1(graph: &[Vec<Option<usize>>]) -> anyhow::Result<AnswerValue> {
+    let distances = distances(graph);
     let min = distances
         .into_iter()
         .min()
         .context("Unable to find a tour")?;
-    Ok(min.to_string())
+    Ok(min.into())
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
-    let graph = parse(s)?;
-    let distances = distances(&graph);
+pub fn part_2(graph: &[Vec<Option<usize>>]) -> anyhow::Result<AnswerValue> {
+    let distances = distances(graph);
     let max = distances
         .into_iter()
         .max()
         .context("Unable to find a tour")?;
-    Ok(max.to_string())
+    Ok(max.into())
 }
 
 #[cfg(test)]
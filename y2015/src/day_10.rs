@@ -1,3 +1,5 @@
+use shared::AnswerValue;
+
 fn step(n: &[u8], step_into: &mut Vec<u8>) {
     let mut c = 1;
     for i in 0..n.len() {
@@ -27,12 +29,12 @@ fn steps(s: &str, n: usize) -> usize {
     v.len()
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
-    Ok(steps(s, 40).to_string())
+pub fn part_1(s: &str) -> anyhow::Result<AnswerValue> {
+    Ok(steps(s, 40).into())
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
-    Ok(steps(s, 50).to_string())
+pub fn part_2(s: &str) -> anyhow::Result<AnswerValue> {
+    Ok(steps(s, 50).into())
 }
 
 #[cfg(test)]
@@ -4,6 +4,7 @@
 
 use itertools::izip;
 use rayon::prelude::*;
+use shared::AnswerValue;
 
 fn to_numeric(pw: &str) -> u128 {
     pw.trim()
@@ -69,13 +70,17 @@ fn next_pw(pw: &str) -> [u8; 8] {
     }
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
-    Ok(String::from_utf8_lossy(&next_pw(s.trim())).to_string())
+pub fn part_1(s: &str) -> anyhow::Result<AnswerValue> {
+    Ok(String::from_utf8_lossy(&next_pw(s.trim()))
+        .into_owned()
+        .into())
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
-    let next = part_1(s)?;
-    Ok(String::from_utf8_lossy(&next_pw(next.as_str())).to_string())
+pub fn part_2(s: &str) -> anyhow::Result<AnswerValue> {
+    let next = part_1(s)?.to_string();
+    Ok(String::from_utf8_lossy(&next_pw(next.as_str()))
+        .into_owned()
+        .into())
 }
 
 #[cfg(test)]
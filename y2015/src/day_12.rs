@@ -1,8 +1,9 @@
 // I know how to make a json-parser, I just refuse to do it.
 
 use serde_json::{from_str, Value};
+use shared::AnswerValue;
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
+pub fn part_1(s: &str) -> anyhow::Result<AnswerValue> {
     fn visit(v: &Value) -> i64 {
         match v {
             Value::Number(n) => n.as_i64().unwrap_or(0),
@@ -11,10 +12,10 @@ pub fn part_1(s: &str) -> anyhow::Result<String> {
             _ => 0,
         }
     }
-    Ok(visit(&from_str(s)?).to_string())
+    Ok(visit(&from_str(s)?).into())
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
+pub fn part_2(s: &str) -> anyhow::Result<AnswerValue> {
     fn visit(v: &Value) -> i64 {
         match v {
             Value::Number(n) => n.as_i64().unwrap_or(0),
@@ -29,7 +30,7 @@ pub fn part_2(s: &str) -> anyhow::Result<String> {
             _ => 0,
         }
     }
-    Ok(visit(&from_str(s)?).to_string())
+    Ok(visit(&from_str(s)?).into())
 }
 
 #[cfg(test)]
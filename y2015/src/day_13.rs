@@ -1,8 +1,9 @@
 use anyhow::{anyhow, Context};
 use fxhash::{FxHashMap, FxHashSet};
 use regex::Regex;
+use shared::AnswerValue;
 
-fn parse(s: &str) -> anyhow::Result<Vec<Vec<i32>>> {
+pub fn parse(s: &str) -> anyhow::Result<Vec<Vec<i32>>> {
     let re = Regex::new(
         r"([A-Za-z]+) would (gain|lose) ([0-9]+) happiness units by sitting next to ([A-Za-z]+)",
     )
@@ -75,21 +76,20 @@ fn all_scores(costs: &[Vec<i32>]) -> FxHashSet<i32> {
     scores
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
-    let costs = parse(s)?;
-    let score = all_scores(&costs).into_iter().max().context("No guests")?;
-    Ok(score.to_string())
+pub fn part_1(costs: &[Vec<i32>]) -> anyhow::Result<AnswerValue> {
+    let score = all_scores(costs).into_iter().max().context("No guests")?;
+    Ok(score.into())
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
-    let mut costs = parse(s)?;
+pub fn part_2(costs: &[Vec<i32>]) -> anyhow::Result<AnswerValue> {
+    let mut costs = costs.to_owned();
     let guest_count = costs.len() + 1;
     for guest in costs.iter_mut() {
         guest.push(0);
     }
     costs.push(vec![0; guest_count]);
     let score = all_scores(&costs).into_iter().max().context("No guests")?;
-    Ok(score.to_string())
+    Ok(score.into())
 }
 
 #[cfg(test)]
@@ -1,8 +1,9 @@
 use anyhow::Context;
 use regex::Regex;
+use shared::AnswerValue;
 
 #[derive(Debug, Default)]
-struct Reindeer {
+pub struct Reindeer {
     velocity: i64,
     work_seconds: i64,
     rest_seconds: i64,
@@ -15,7 +16,7 @@ fn distance(reindeer: &Reindeer, time: i64) -> i64 {
     complete_cycles * reindeer.velocity * reindeer.work_seconds + remainder * reindeer.velocity
 }
 
-fn parse_reindeers(s: &str) -> anyhow::Result<Vec<Reindeer>> {
+pub fn parse_reindeers(s: &str) -> anyhow::Result<Vec<Reindeer>> {
     let re = Regex::new(r"\d+").context("Invalid regex")?;
     let mut reindeers = Vec::new();
     for line in s.lines() {
@@ -34,14 +35,12 @@ fn parse_reindeers(s: &str) -> anyhow::Result<Vec<Reindeer>> {
     Ok(reindeers)
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
-    let reindeers = parse_reindeers(s)?;
+pub fn part_1(reindeers: &[Reindeer]) -> anyhow::Result<AnswerValue> {
     let answer = reindeers.iter().map(|r| distance(r, 2503)).max();
-    answer.context("No reindeers parsed").map(|n| n.to_string())
+    answer.context("No reindeers parsed").map(AnswerValue::from)
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
-    let reindeers = parse_reindeers(s)?;
+pub fn part_2(reindeers: &[Reindeer]) -> anyhow::Result<AnswerValue> {
     let mut scores = vec![0; reindeers.len()];
 
     for time in 1..=2503 {
@@ -57,7 +56,7 @@ pub fn part_2(s: &str) -> anyhow::Result<String> {
         .into_iter()
         .max()
         .context("No reindeers parsed")
-        .map(|n| n.to_string())
+        .map(AnswerValue::from)
 }
 
 #[cfg(test)]
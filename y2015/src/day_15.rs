@@ -1,9 +1,10 @@
 use anyhow::Context;
 use regex::Regex;
+use shared::AnswerValue;
 
-type Ingredient = [i64; 5];
+pub type Ingredient = [i64; 5];
 
-fn parse(s: &str) -> anyhow::Result<Vec<Ingredient>> {
+pub fn parse(s: &str) -> anyhow::Result<Vec<Ingredient>> {
     let digit = Regex::new(r"-?\d+").context("Invalid regex")?;
     s.lines()
         .map(|n| {
@@ -68,14 +69,12 @@ fn best_possible(ingredients: &[&[i64]], target_sum: usize) -> i64 {
     iterate(target_sum, ingredients.len(), &evaluate)
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
-    let ingredients = parse(s)?;
+pub fn part_1(ingredients: &[Ingredient]) -> anyhow::Result<AnswerValue> {
     let sliced: Vec<_> = ingredients.iter().map(|v| v.as_slice()).collect();
-    Ok(best_possible(&sliced, 100).to_string())
+    Ok(best_possible(&sliced, 100).into())
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
-    let ingredients = parse(s)?;
+pub fn part_2(ingredients: &[Ingredient]) -> anyhow::Result<AnswerValue> {
     let sliced: Vec<_> = ingredients.iter().map(|v| v.as_slice()).collect();
     let slice_ref = &sliced;
     let original_eval = evaluator(slice_ref);
@@ -92,7 +91,7 @@ pub fn part_2(s: &str) -> anyhow::Result<String> {
         }
     };
     let best = iterate(100, ingredients.len(), &eval);
-    Ok(best.to_string())
+    Ok(best.into())
 }
 
 #[cfg(test)]
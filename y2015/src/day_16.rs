@@ -1,7 +1,8 @@
 use anyhow::{anyhow, Context};
+use shared::AnswerValue;
 
 #[derive(Default, Copy, Clone)]
-struct Sue {
+pub struct Sue {
     children: Option<u8>,
     cats: Option<u8>,
     samoyeds: Option<u8>,
@@ -27,7 +28,7 @@ fn compatible_sue(sue: &Sue) -> bool {
         && sue.perfumes.unwrap_or(1) == 1
 }
 
-fn parse(s: &str) -> anyhow::Result<Vec<Sue>> {
+pub fn parse(s: &str) -> anyhow::Result<Vec<Sue>> {
     let mut sues = Vec::with_capacity(500);
     for line in s.lines() {
         let mut sue = Sue::default();
@@ -54,10 +55,10 @@ fn parse(s: &str) -> anyhow::Result<Vec<Sue>> {
     Ok(sues)
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
-    for (index, sue) in parse(s)?.into_iter().enumerate() {
-        if compatible_sue(&sue) {
-            return Ok((index + 1).to_string());
+pub fn part_1(sues: &[Sue]) -> anyhow::Result<AnswerValue> {
+    for (index, sue) in sues.iter().enumerate() {
+        if compatible_sue(sue) {
+            return Ok((index + 1).into());
         }
     }
     Err(anyhow!("Failed to find aunt sue"))
@@ -79,10 +80,10 @@ fn real_aunt_sue(sue: &Sue) -> bool {
         && sue.perfumes.unwrap_or(1) == 1
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
-    for (index, sue) in parse(s)?.into_iter().enumerate() {
-        if real_aunt_sue(&sue) {
-            return Ok((index + 1).to_string());
+pub fn part_2(sues: &[Sue]) -> anyhow::Result<AnswerValue> {
+    for (index, sue) in sues.iter().enumerate() {
+        if real_aunt_sue(sue) {
+            return Ok((index + 1).into());
         }
     }
     Err(anyhow!("Failed to find aunt sue"))
@@ -1,12 +1,13 @@
 use rayon::prelude::*;
+use shared::AnswerValue;
 
 #[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
-struct Container {
+pub struct Container {
     id: usize,
     capacity: i32,
 }
 
-fn parse(s: &str) -> anyhow::Result<Vec<Container>> {
+pub fn parse(s: &str) -> anyhow::Result<Vec<Container>> {
     s.lines()
         .enumerate()
         .map(|(id, container)| {
@@ -36,9 +37,8 @@ fn count_solutions(containers: &[Container], target: i32) -> usize {
         .count()
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
-    let containers = parse(s)?;
-    Ok(count_solutions(&containers, 150).to_string())
+pub fn part_1(containers: &[Container]) -> anyhow::Result<AnswerValue> {
+    Ok(count_solutions(containers, 150).into())
 }
 
 fn count_minimal_solutions(containers: &[Container], target: i32) -> usize {
@@ -66,9 +66,8 @@ fn count_minimal_solutions(containers: &[Container], target: i32) -> usize {
     by_bits.into_iter().find(|s| *s != 0).unwrap()
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
-    let containers = parse(s)?;
-    Ok(count_minimal_solutions(&containers, 150).to_string())
+pub fn part_2(containers: &[Container]) -> anyhow::Result<AnswerValue> {
+    Ok(count_minimal_solutions(containers, 150).into())
 }
 
 #[cfg(test)]
@@ -1,5 +1,7 @@
+use shared::AnswerValue;
+
 #[derive(Copy, Clone, PartialEq, Eq)]
-enum Light {
+pub enum Light {
     On,
     Off,
 }
@@ -21,7 +23,7 @@ fn corners(width: usize, grid: &[Light]) -> [[i32; 2]; 4] {
     [[xmin, ymin], [xmax, ymin], [xmin, ymax], [xmax, ymax]]
 }
 
-fn parse(s: &str) -> (usize, Vec<Light>) {
+pub fn parse(s: &str) -> anyhow::Result<(usize, Vec<Light>)> {
     let height = s.lines().count();
     let grid: Vec<_> = s
         .lines()
@@ -30,7 +32,7 @@ fn parse(s: &str) -> (usize, Vec<Light>) {
                 .map(|ch| if ch == '#' { Light::On } else { Light::Off })
         })
         .collect();
-    (grid.len() / height, grid)
+    Ok((grid.len() / height, grid))
 }
 
 fn step(width: usize, grid: &[Light], corners_on: bool) -> impl Iterator<Item = Light> + use<'_> {
@@ -81,22 +83,20 @@ fn steps(width: usize, grid: &[Light], time: usize, corners_on: bool) -> Vec<Lig
     out
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
-    let (width, grid) = parse(s);
-    let c = steps(width, &grid, 100, false)
+pub fn part_1((width, grid): &(usize, Vec<Light>)) -> anyhow::Result<AnswerValue> {
+    let c = steps(*width, grid, 100, false)
         .into_iter()
         .filter(|l| matches!(l, Light::On))
         .count();
-    Ok(c.to_string())
+    Ok(c.into())
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
-    let (width, grid) = parse(s);
-    let c = steps(width, &grid, 100, true)
+pub fn part_2((width, grid): &(usize, Vec<Light>)) -> anyhow::Result<AnswerValue> {
+    let c = steps(*width, grid, 100, true)
         .into_iter()
         .filter(|l| matches!(l, Light::On))
         .count();
-    Ok(c.to_string())
+    Ok(c.into())
 }
 
 #[cfg(test)]
@@ -112,7 +112,7 @@ mod tests {
 
     #[test]
     fn test_ex() {
-        let (width, grid) = parse(EX);
+        let (width, grid) = parse(EX).unwrap();
         assert_eq!(width, 6);
         assert_eq!(grid.len() / width, 6);
         let stepped = steps(width, &grid, 4, false);
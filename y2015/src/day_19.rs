@@ -1,8 +1,19 @@
 use anyhow::{anyhow, Context};
 use fxhash::FxHashSet;
+use shared::{AnswerValue, Input};
 use std::cmp::Reverse;
 
-fn parse(s: &str) -> anyhow::Result<(Vec<(&str, &str)>, &str)> {
+pub struct Machine<'a> {
+    replacements: Vec<(&'a str, &'a str)>,
+    molecule: &'a str,
+}
+
+impl Input for Machine<'static> {
+    type Of<'a> = Machine<'a>;
+    type View<'a> = Machine<'a>;
+}
+
+pub fn parse(s: &str) -> anyhow::Result<Machine<'_>> {
     let (replacements, molecule) = s.split_once("\n\n").context("Invalid input")?;
 
     let replacements: anyhow::Result<Vec<_>> = replacements
@@ -13,14 +24,17 @@ fn parse(s: &str) -> anyhow::Result<(Vec<(&str, &str)>, &str)> {
         })
         .collect();
 
-    Ok((replacements?, molecule.trim()))
+    Ok(Machine {
+        replacements: replacements?,
+        molecule: molecule.trim(),
+    })
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
+pub fn part_1(machine: &Machine) -> anyhow::Result<AnswerValue> {
     let mut seen = FxHashSet::default();
-    let (replacements, molecule) = parse(s)?;
+    let molecule = machine.molecule;
 
-    for (from, to) in replacements {
+    for &(from, to) in &machine.replacements {
         for (start_at, _) in molecule.match_indices(from) {
             let mut after = String::with_capacity(molecule.len() + to.len() - from.len());
             after.push_str(&molecule[..start_at]);
@@ -30,7 +44,7 @@ pub fn part_1(s: &str) -> anyhow::Result<String> {
         }
     }
 
-    Ok(seen.len().to_string())
+    Ok(seen.len().into())
 }
 
 fn greedy_backtracking_search(
@@ -58,11 +72,11 @@ fn greedy_backtracking_search(
     }
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
-    let (mut replacements, molecule) = parse(s)?;
+pub fn part_2(machine: &Machine) -> anyhow::Result<AnswerValue> {
+    let (mut replacements, molecule) = (machine.replacements.clone(), machine.molecule);
     replacements.sort_by_key(|(from, to)| Reverse(to.len() - from.len()));
     if let Some(answer) = greedy_backtracking_search(molecule, &replacements, 0) {
-        Ok(answer.to_string())
+        Ok(answer.into())
     } else {
         Err(anyhow!("Solution not found"))
     }
@@ -1,3 +1,5 @@
+use shared::AnswerValue;
+
 fn find_house_with_at_least(gifts: usize, factor: usize) -> usize {
     // Start with 2 ^ 16 houses and double until we find the right one
     let mut size = 0xffff;
@@ -28,12 +30,12 @@ fn find_house_with_at_least(gifts: usize, factor: usize) -> usize {
     }
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
+pub fn part_1(s: &str) -> anyhow::Result<AnswerValue> {
     let gifts_required: usize = s.trim().parse()?;
-    Ok(find_house_with_at_least(gifts_required, 10).to_string())
+    Ok(find_house_with_at_least(gifts_required, 10).into())
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
+pub fn part_2(s: &str) -> anyhow::Result<AnswerValue> {
     let gifts_required: usize = s.trim().parse()?;
-    Ok(find_house_with_at_least(gifts_required, 11).to_string())
+    Ok(find_house_with_at_least(gifts_required, 11).into())
 }
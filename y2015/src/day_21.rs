@@ -1,5 +1,6 @@
 use anyhow::anyhow;
 use regex::Regex;
+use shared::AnswerValue;
 
 type Item = [i32; 3];
 
@@ -24,13 +25,13 @@ const RINGS: [Item; 6] = [
 ];
 
 #[derive(Copy, Clone, Debug, Default)]
-struct Character {
+pub struct Character {
     hitpoints: i32,
     armor: i32,
     damage: i32,
 }
 
-fn parse_boss(s: &str) -> anyhow::Result<Character> {
+pub fn parse_boss(s: &str) -> anyhow::Result<Character> {
     let numbers = Regex::new(r"\d+")?;
     let mut stats = [0; 3];
     for (g, m) in numbers.find_iter(s).enumerate() {
@@ -99,27 +100,25 @@ impl Character {
     }
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
-    let boss = parse_boss(s)?;
+pub fn part_1(boss: &Character) -> anyhow::Result<AnswerValue> {
     if let Some(cost) = all_loadouts()
-        .filter(|items| Character::new(100, *items).wins(&boss))
+        .filter(|items| Character::new(100, *items).wins(boss))
         .map(|items| items[0])
         .min()
     {
-        Ok(cost.to_string())
+        Ok(cost.into())
     } else {
         Err(anyhow!("Can't win"))
     }
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
-    let boss = parse_boss(s)?;
+pub fn part_2(boss: &Character) -> anyhow::Result<AnswerValue> {
     if let Some(cost) = all_loadouts()
-        .filter(|items| !Character::new(100, *items).wins(&boss))
+        .filter(|items| !Character::new(100, *items).wins(boss))
         .map(|items| items[0])
         .max()
     {
-        Ok(cost.to_string())
+        Ok(cost.into())
     } else {
         Err(anyhow!("Can't lose"))
     }
@@ -1,6 +1,7 @@
 use anyhow::{anyhow, Context};
 use fxhash::FxHashSet;
 use regex::Regex;
+use shared::AnswerValue;
 use std::cmp::Reverse;
 use std::collections::BinaryHeap;
 
@@ -218,17 +219,17 @@ fn find_cheapest_victory(initial: State, hard_mode: bool) -> Option<i32> {
     None
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
+pub fn part_1(s: &str) -> anyhow::Result<AnswerValue> {
     if let Some(cost) = find_cheapest_victory(initial_state(s)?, false) {
-        Ok(cost.to_string())
+        Ok(cost.into())
     } else {
         Err(anyhow!("No victory found"))
     }
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
+pub fn part_2(s: &str) -> anyhow::Result<AnswerValue> {
     if let Some(cost) = find_cheapest_victory(initial_state(s)?, true) {
-        Ok(cost.to_string())
+        Ok(cost.into())
     } else {
         Err(anyhow!("No victory found"))
     }
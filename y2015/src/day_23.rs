@@ -6,6 +6,7 @@ use nom::combinator::{map, map_res};
 use nom::multi::separated_list1;
 use nom::sequence::{preceded, separated_pair};
 use nom::IResult;
+use shared::AnswerValue;
 
 #[derive(Copy, Clone, Debug)]
 enum Register {
@@ -123,12 +124,12 @@ fn eval(s: &str, initial_a: usize) -> anyhow::Result<(usize, usize)> {
     Ok(run(&prog, initial_a))
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
+pub fn part_1(s: &str) -> anyhow::Result<AnswerValue> {
     let (_, b) = eval(s, 0)?;
-    Ok(b.to_string())
+    Ok(b.into())
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
+pub fn part_2(s: &str) -> anyhow::Result<AnswerValue> {
     let (_, b) = eval(s, 1)?;
-    Ok(b.to_string())
+    Ok(b.into())
 }
@@ -1,4 +1,5 @@
 use itertools::Itertools;
+use shared::AnswerValue;
 
 fn parse(s: &str) -> anyhow::Result<Vec<u32>> {
     s.split_whitespace().map(|n| Ok(n.parse()?)).collect()
@@ -23,14 +24,14 @@ fn find_smallest_of_n_groups(s: &str, n: u32) -> anyhow::Result<u64> {
     Ok(find_smallest_group(&numbers, target))
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
+pub fn part_1(s: &str) -> anyhow::Result<AnswerValue> {
     let answer = find_smallest_of_n_groups(s, 3)?;
-    Ok(answer.to_string())
+    Ok(answer.into())
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
+pub fn part_2(s: &str) -> anyhow::Result<AnswerValue> {
     let answer = find_smallest_of_n_groups(s, 4)?;
-    Ok(answer.to_string())
+    Ok(answer.into())
 }
 
 #[cfg(test)]
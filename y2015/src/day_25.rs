@@ -20,31 +20,28 @@
 
 use anyhow::Context;
 use regex::Regex;
+use shared::math::{mod_mul, mod_pow};
+use shared::AnswerValue;
 
 fn calculate_index(row: u64, col: u64) -> u64 {
     let n = col + row - 1;
     (n * (n + 1)) / 2 - row + 1
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
+pub fn part_1(s: &str) -> anyhow::Result<AnswerValue> {
     let re = Regex::new(r"row (\d+), column (\d+)")?;
     let caps = re.captures(s).context("No match")?;
     let row = caps.get(1).unwrap().as_str().parse()?;
     let col = caps.get(2).unwrap().as_str().parse()?;
     let ix = calculate_index(row, col);
 
-    let mut n: u64 = 20151125;
-    // The calculation the task gives us is this:
-    for _ in 1..ix {
-        n = (n * 252533) % 33554393;
-    }
-    // There _should_ be a faster way to do this using modular exponentiation...
-    // but leaving that as TODO
-    Ok(n.to_string())
+    // The calculation the task gives us is n = n * 252533 % 33554393, ix - 1 times
+    let n = mod_mul(20151125, mod_pow(252533, ix - 1, 33554393), 33554393);
+    Ok(n.into())
 }
 
-pub fn part_2(_: &str) -> anyhow::Result<String> {
-    Ok("Collect the stars and click the button (-:".to_string())
+pub fn part_2(_: &str) -> anyhow::Result<AnswerValue> {
+    Ok("Collect the stars and click the button (-:".into())
 }
 
 #[cfg(test)]
@@ -59,4 +56,12 @@ mod tests {
         assert_eq!(calculate_index(2, 5), 20);
         assert_eq!(calculate_index(5, 2), 17);
     }
+
+    #[test]
+    fn test_part_1() {
+        let code = |s| part_1(s).unwrap();
+        assert_eq!(code("row 1, column 1."), AnswerValue::Integer(20151125));
+        assert_eq!(code("row 1, column 6."), AnswerValue::Integer(33511524));
+        assert_eq!(code("row 6, column 1."), AnswerValue::Integer(33071741));
+    }
 }
@@ -1,5 +1,4 @@
-use shared::Answer;
-use shared::Answer::SolvedBoth;
+use shared::{Day, List, Owned, Parsed, Solution};
 
 mod day_01;
 mod day_02;
@@ -27,105 +26,88 @@ mod day_23;
 mod day_24;
 mod day_25;
 
-pub const SOLUTIONS: [Answer; 25] = [
-    SolvedBoth {
-        part_1: day_01::part_1,
-        part_2: day_01::part_2,
-    },
-    SolvedBoth {
-        part_1: day_02::part_1,
-        part_2: day_02::part_2,
-    },
-    SolvedBoth {
-        part_1: day_03::part_1,
-        part_2: day_03::part_2,
-    },
-    SolvedBoth {
-        part_1: day_04::part_1,
-        part_2: day_04::part_2,
-    },
-    SolvedBoth {
-        part_1: day_05::part_1,
-        part_2: day_05::part_2,
-    },
-    SolvedBoth {
-        part_1: day_06::part_1,
-        part_2: day_06::part_2,
-    },
-    SolvedBoth {
-        part_1: day_07::part_1,
-        part_2: day_07::part_2,
-    },
-    SolvedBoth {
-        part_1: day_08::part_1,
-        part_2: day_08::part_2,
-    },
-    SolvedBoth {
-        part_1: day_09::part_1,
-        part_2: day_09::part_2,
-    },
-    SolvedBoth {
-        part_1: day_10::part_1,
-        part_2: day_10::part_2,
-    },
-    SolvedBoth {
-        part_1: day_11::part_1,
-        part_2: day_11::part_2,
-    },
-    SolvedBoth {
-        part_1: day_12::part_1,
-        part_2: day_12::part_2,
-    },
-    SolvedBoth {
-        part_1: day_13::part_1,
-        part_2: day_13::part_2,
-    },
-    SolvedBoth {
-        part_1: day_14::part_1,
-        part_2: day_14::part_2,
-    },
-    SolvedBoth {
-        part_1: day_15::part_1,
-        part_2: day_15::part_2,
-    },
-    SolvedBoth {
-        part_1: day_16::part_1,
-        part_2: day_16::part_2,
-    },
-    SolvedBoth {
-        part_1: day_17::part_1,
-        part_2: day_17::part_2,
-    },
-    SolvedBoth {
-        part_1: day_18::part_1,
-        part_2: day_18::part_2,
-    },
-    SolvedBoth {
-        part_1: day_19::part_1,
-        part_2: day_19::part_2,
-    },
-    SolvedBoth {
-        part_1: day_20::part_1,
-        part_2: day_20::part_2,
-    },
-    SolvedBoth {
-        part_1: day_21::part_1,
-        part_2: day_21::part_2,
-    },
-    SolvedBoth {
-        part_1: day_22::part_1,
-        part_2: day_22::part_2,
-    },
-    SolvedBoth {
-        part_1: day_23::part_1,
-        part_2: day_23::part_2,
-    },
-    SolvedBoth {
-        part_1: day_24::part_1,
-        part_2: day_24::part_2,
-    },
-    SolvedBoth {
-        part_1: day_25::part_1,
-        part_2: day_25::part_2,
-    },
+const YEAR: u16 = 2015;
+
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &Day::both(YEAR, 1, day_01::part_1, day_01::part_2).titled("Not Quite Lisp"),
+    &Day::both(YEAR, 2, day_02::part_1, day_02::part_2).titled("I Was Told There Would Be No Math"),
+    &Day::both(YEAR, 3, day_03::part_1, day_03::part_2)
+        .titled("Perfectly Spherical Houses in a Vacuum"),
+    &Day::both(YEAR, 4, day_04::part_1, day_04::part_2).titled("The Ideal Stocking Stuffer"),
+    &Day::both(YEAR, 5, day_05::part_1, day_05::part_2)
+        .titled("Doesn't He Have Intern-Elves For This?"),
+    &Parsed::<List<day_06::CommandSegment>>::both(
+        YEAR,
+        6,
+        day_06::parse,
+        day_06::part_1,
+        day_06::part_2,
+    )
+    .titled("Probably a Fire Hazard"),
+    &Day::both(YEAR, 7, day_07::part_1, day_07::part_2).titled("Some Assembly Required"),
+    &Parsed::<day_08::Lines>::both(YEAR, 8, day_08::parse_lines, day_08::part_1, day_08::part_2)
+        .titled("Matchsticks"),
+    &Parsed::<List<Vec<Option<usize>>>>::both(
+        YEAR,
+        9,
+        day_09::parse,
+        day_09::part_1,
+        day_09::part_2,
+    )
+    .titled("All in a Single Night"),
+    &Day::both(YEAR, 10, day_10::part_1, day_10::part_2).titled("Elves Look, Elves Say"),
+    &Day::both(YEAR, 11, day_11::part_1, day_11::part_2).titled("Corporate Policy"),
+    &Day::both(YEAR, 12, day_12::part_1, day_12::part_2).titled("JSAbacusFramework.io"),
+    &Parsed::<List<Vec<i32>>>::both(YEAR, 13, day_13::parse, day_13::part_1, day_13::part_2)
+        .titled("Knights of the Dinner Table"),
+    &Parsed::<List<day_14::Reindeer>>::both(
+        YEAR,
+        14,
+        day_14::parse_reindeers,
+        day_14::part_1,
+        day_14::part_2,
+    )
+    .titled("Reindeer Olympics"),
+    &Parsed::<List<day_15::Ingredient>>::both(
+        YEAR,
+        15,
+        day_15::parse,
+        day_15::part_1,
+        day_15::part_2,
+    )
+    .titled("Science for Hungry People"),
+    &Parsed::<List<day_16::Sue>>::both(YEAR, 16, day_16::parse, day_16::part_1, day_16::part_2)
+        .titled("Aunt Sue"),
+    &Parsed::<List<day_17::Container>>::both(
+        YEAR,
+        17,
+        day_17::parse,
+        day_17::part_1,
+        day_17::part_2,
+    )
+    .titled("No Such Thing as Too Much"),
+    &Parsed::<Owned<(usize, Vec<day_18::Light>)>>::both(
+        YEAR,
+        18,
+        day_18::parse,
+        day_18::part_1,
+        day_18::part_2,
+    )
+    .titled("Like a GIF For Your Yard"),
+    &Parsed::<day_19::Machine>::both(YEAR, 19, day_19::parse, day_19::part_1, day_19::part_2)
+        .titled("Medicine for Rudolph"),
+    &Day::both(YEAR, 20, day_20::part_1, day_20::part_2)
+        .titled("Infinite Elves and Infinite Houses"),
+    &Parsed::<Owned<day_21::Character>>::both(
+        YEAR,
+        21,
+        day_21::parse_boss,
+        day_21::part_1,
+        day_21::part_2,
+    )
+    .titled("RPG Simulator 20XX"),
+    &Day::both(YEAR, 22, day_22::part_1, day_22::part_2).titled("Wizard Simulator 20XX"),
+    &Day::both(YEAR, 23, day_23::part_1, day_23::part_2).titled("Opening the Turing Lock"),
+    &Day::both(YEAR, 24, day_24::part_1, day_24::part_2).titled("It Hangs in the Balance"),
+    &Day::both(YEAR, 25, day_25::part_1, day_25::part_2).titled("Let It Snow"),
 ];
@@ -1,5 +1,6 @@
 use anyhow::anyhow;
 use fxhash::FxHashSet;
+use shared::AnswerValue;
 
 const DIRECTIONS: [(i32, i32); 4] = [
     (0, 1),  // north
@@ -44,16 +45,16 @@ pub fn walk(s: &str) -> impl Iterator<Item = (i32, i32)> {
         .flatten()
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
+pub fn part_1(s: &str) -> anyhow::Result<AnswerValue> {
     let (x, y) = walk(s).last().unwrap();
-    Ok((x.abs() + y.abs()).to_string())
+    Ok((x.abs() + y.abs()).into())
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
+pub fn part_2(s: &str) -> anyhow::Result<AnswerValue> {
     let mut seen = FxHashSet::default();
     for (x, y) in walk(s) {
         if !seen.insert((x, y)) {
-            return Ok((x.abs() + y.abs()).to_string());
+            return Ok((x.abs() + y.abs()).into());
         }
     }
     Err(anyhow!("No position visited twice"))
@@ -65,11 +66,17 @@ mod tests {
 
     #[test]
     fn examples() {
-        assert_eq!(part_1("R2, L3").unwrap().as_str(), "5");
-        assert_eq!(part_1("R2, R2, R2").unwrap().as_str(), "2");
-        assert_eq!(part_1("R5, L5, R5, R3").unwrap().as_str(), "12");
-        assert_eq!(part_1("R2, R2, R2, R2, R2").unwrap().as_str(), "2");
-        assert_eq!(part_1("L1, L1, L1, L1, L1").unwrap().as_str(), "1");
-        assert_eq!(part_2("R8, R4, R4, R8").unwrap().as_str(), "4");
+        assert_eq!(part_1("R2, L3").unwrap(), AnswerValue::Integer(5));
+        assert_eq!(part_1("R2, R2, R2").unwrap(), AnswerValue::Integer(2));
+        assert_eq!(part_1("R5, L5, R5, R3").unwrap(), AnswerValue::Integer(12));
+        assert_eq!(
+            part_1("R2, R2, R2, R2, R2").unwrap(),
+            AnswerValue::Integer(2)
+        );
+        assert_eq!(
+            part_1("L1, L1, L1, L1, L1").unwrap(),
+            AnswerValue::Integer(1)
+        );
+        assert_eq!(part_2("R8, R4, R4, R8").unwrap(), AnswerValue::Integer(4));
     }
 }
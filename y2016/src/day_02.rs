@@ -1,5 +1,6 @@
 use anyhow::Context;
 use fxhash::FxHashMap;
+use shared::AnswerValue;
 
 const NUMPAD: &str = "123
 456
@@ -53,12 +54,12 @@ pub fn code(s: &str, pad: &str) -> anyhow::Result<String> {
     Ok(code)
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
-    code(s, NUMPAD)
+pub fn part_1(s: &str) -> anyhow::Result<AnswerValue> {
+    code(s, NUMPAD).map(AnswerValue::from)
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
-    code(s, KEYPAD)
+pub fn part_2(s: &str) -> anyhow::Result<AnswerValue> {
+    code(s, KEYPAD).map(AnswerValue::from)
 }
 
 #[cfg(test)]
@@ -68,8 +69,8 @@ mod tests {
     #[test]
     fn examples() {
         assert_eq!(
-            part_1("ULL\nRRDDD\nLURDL\nUUUUD\n").unwrap().as_str(),
-            "1985"
+            part_1("ULL\nRRDDD\nLURDL\nUUUUD\n").unwrap(),
+            AnswerValue::from("1985")
         );
     }
 }
@@ -1,4 +1,6 @@
-fn parse(s: &str) -> anyhow::Result<Vec<i32>> {
+use shared::AnswerValue;
+
+pub fn parse(s: &str) -> anyhow::Result<Vec<i32>> {
     s.split_whitespace()
         .filter(|n| !n.is_empty())
         .map(|n| Ok(n.parse()?))
@@ -32,10 +34,10 @@ fn reshape(n: &[i32]) -> Vec<i32> {
     out
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
-    Ok(count_possible_triangles(&parse(s)?).to_string())
+pub fn part_1(sides: &[i32]) -> anyhow::Result<AnswerValue> {
+    Ok(count_possible_triangles(sides).into())
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
-    Ok(count_possible_triangles(&reshape(&parse(s)?)).to_string())
+pub fn part_2(sides: &[i32]) -> anyhow::Result<AnswerValue> {
+    Ok(count_possible_triangles(&reshape(sides)).into())
 }
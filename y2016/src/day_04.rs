@@ -7,6 +7,7 @@ use nom::combinator::{map_res, recognize};
 use nom::multi::{many1, separated_list1};
 use nom::sequence::delimited;
 use nom::IResult;
+use shared::AnswerValue;
 use std::cmp::Reverse;
 
 fn parse(s: &str) -> IResult<&str, (u32, bool, String)> {
@@ -39,21 +40,21 @@ fn parse(s: &str) -> IResult<&str, (u32, bool, String)> {
     Ok((s, (sector_id, expect.as_str() == checksum, decrypted)))
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
+pub fn part_1(s: &str) -> anyhow::Result<AnswerValue> {
     let (_, r) = separated_list1(tag("\n"), parse)(s).map_err(|err| anyhow!("{err}"))?;
     let n: u32 = r
         .into_iter()
         .map(|(sector_id, checks_out, _)| if checks_out { sector_id } else { 0 })
         .sum();
-    Ok(n.to_string())
+    Ok(n.into())
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
+pub fn part_2(s: &str) -> anyhow::Result<AnswerValue> {
     let (_, r) = separated_list1(tag("\n"), parse)(s).map_err(|err| anyhow!("{err}"))?;
     let n: u32 = r
         .into_iter()
         .filter(|(_, checks_out, room)| *checks_out && room.contains("northpole"))
         .map(|(sector_id, checks_out, _)| if checks_out { sector_id } else { 0 })
         .sum();
-    Ok(n.to_string())
+    Ok(n.into())
 }
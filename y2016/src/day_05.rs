@@ -1,5 +1,6 @@
 use md5::Digest;
 use rayon::prelude::*;
+use shared::AnswerValue;
 
 const CHUNK: usize = 32_000;
 
@@ -28,7 +29,7 @@ fn check_chunk(k: &str, start: usize) -> Vec<(u8, u8)> {
     r.into_iter().map(|(_, hexdig)| hexdig).collect()
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
+pub fn part_1(s: &str) -> anyhow::Result<AnswerValue> {
     let mut ix = 0;
     let mut hex = Vec::with_capacity(10);
     while hex.len() < 8 {
@@ -39,10 +40,10 @@ pub fn part_1(s: &str) -> anyhow::Result<String> {
         );
         ix += CHUNK;
     }
-    Ok(String::from_utf8_lossy(&hex[..8]).to_string())
+    Ok(String::from_utf8_lossy(&hex[..8]).into_owned().into())
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
+pub fn part_2(s: &str) -> anyhow::Result<AnswerValue> {
     let mut pw = [0u8; 8];
     let mut written = 0u32;
     let mut ix = 0;
@@ -58,7 +59,7 @@ pub fn part_2(s: &str) -> anyhow::Result<String> {
         }
         ix += CHUNK;
     }
-    Ok(String::from_utf8_lossy(&pw).to_string())
+    Ok(String::from_utf8_lossy(&pw).into_owned().into())
 }
 
 #[cfg(test)]
@@ -67,7 +68,7 @@ mod tests {
 
     #[test]
     fn test_example() {
-        assert_eq!(part_1("abc").unwrap().as_str(), "18f47a30");
-        assert_eq!(part_2("abc").unwrap().as_str(), "05ace8e3");
+        assert_eq!(part_1("abc").unwrap(), AnswerValue::from("18f47a30"));
+        assert_eq!(part_2("abc").unwrap(), AnswerValue::from("05ace8e3"));
     }
 }
@@ -1,3 +1,5 @@
+use shared::AnswerValue;
+
 fn visit_chars_in_pos(s: &str, pos: usize) -> impl Iterator<Item = u8> + use<'_> {
     s.lines()
         .filter_map(move |l| l.as_bytes().get(pos).copied())
@@ -27,7 +29,7 @@ where
     message
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
+pub fn part_1(s: &str) -> anyhow::Result<AnswerValue> {
     let message = reconstruct_message(s, |freq| {
         let ix = freq
             .into_iter()
@@ -36,10 +38,10 @@ pub fn part_1(s: &str) -> anyhow::Result<String> {
             .map(|(ix, _)| ix);
         ix.unwrap() as u8
     });
-    Ok(message)
+    Ok(message.into())
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
+pub fn part_2(s: &str) -> anyhow::Result<AnswerValue> {
     let message = reconstruct_message(s, |freq| {
         let ix = freq
             .into_iter()
@@ -49,7 +51,7 @@ pub fn part_2(s: &str) -> anyhow::Result<String> {
             .map(|(ix, _)| ix);
         ix.unwrap() as u8
     });
-    Ok(message)
+    Ok(message.into())
 }
 
 #[cfg(test)]
@@ -76,7 +78,7 @@ enarar
 
     #[test]
     fn check_example() {
-        assert_eq!(part_1(EX).unwrap().as_str(), "easter");
-        assert_eq!(part_2(EX).unwrap().as_str(), "advent");
+        assert_eq!(part_1(EX).unwrap(), AnswerValue::from("easter"));
+        assert_eq!(part_2(EX).unwrap(), AnswerValue::from("advent"));
     }
 }
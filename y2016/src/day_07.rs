@@ -7,6 +7,7 @@ use nom::combinator::recognize;
 use nom::multi::many1;
 use nom::sequence::delimited;
 use nom::IResult;
+use shared::AnswerValue;
 
 enum Segment<'a> {
     Enclosed(&'a str),
@@ -51,7 +52,7 @@ fn has_abba(s: &str) -> bool {
     false
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
+pub fn part_1(s: &str) -> anyhow::Result<AnswerValue> {
     let mut found = 0;
     for line in s.lines() {
         let (open, enclosed) = partition(line)?;
@@ -59,7 +60,7 @@ pub fn part_1(s: &str) -> anyhow::Result<String> {
             found += 1;
         }
     }
-    Ok(found.to_string())
+    Ok(found.into())
 }
 
 fn list_abas(s: &str) -> impl Iterator<Item = (char, char)> + use<'_> {
@@ -72,7 +73,7 @@ fn list_abas(s: &str) -> impl Iterator<Item = (char, char)> + use<'_> {
     })
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
+pub fn part_2(s: &str) -> anyhow::Result<AnswerValue> {
     let mut found = 0;
     for line in s.lines() {
         let (open, enclosed) = partition(line)?;
@@ -85,7 +86,7 @@ pub fn part_2(s: &str) -> anyhow::Result<String> {
             found += 1;
         }
     }
-    Ok(found.to_string())
+    Ok(found.into())
 }
 
 #[cfg(test)]
@@ -6,6 +6,7 @@ use nom::combinator::map_res;
 use nom::multi::separated_list1;
 use nom::sequence::{preceded, separated_pair};
 use nom::IResult;
+use shared::AnswerValue;
 
 struct Display {
     rows: Vec<Vec<bool>>,
@@ -102,16 +103,17 @@ fn draw_display(s: &str) -> anyhow::Result<Display> {
     Ok(display)
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
+pub fn part_1(s: &str) -> anyhow::Result<AnswerValue> {
     let n: u32 = draw_display(s)?
         .rows
         .into_iter()
         .flat_map(|row| row.into_iter().map(u32::from))
         .sum();
-    Ok(n.to_string())
+    Ok(n.into())
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
+/// The lit display, before reading the letters
+pub fn render(s: &str) -> anyhow::Result<String> {
     let mut lines: Vec<String> = Vec::with_capacity(6);
     for row in draw_display(s)?.rows {
         lines.push(
@@ -123,6 +125,10 @@ pub fn part_2(s: &str) -> anyhow::Result<String> {
     Ok(lines.join("\n"))
 }
 
+pub fn part_2(s: &str) -> anyhow::Result<AnswerValue> {
+    shared::ocr::read_letters(&render(s)?).map(AnswerValue::from)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
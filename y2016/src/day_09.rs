@@ -1,4 +1,5 @@
 use regex::Regex;
+use shared::AnswerValue;
 use std::sync::LazyLock;
 
 static RLE_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\((\d+)x(\d+)\)").unwrap());
@@ -30,7 +31,7 @@ pub fn recursive_rle_len(mut s: &str) -> usize {
     len
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
+pub fn part_1(s: &str) -> anyhow::Result<AnswerValue> {
     let s: String = s.chars().filter(|ch| !ch.is_whitespace()).collect();
     let mut as_str = s.as_str();
     let mut len = 0;
@@ -43,13 +44,13 @@ pub fn part_1(s: &str) -> anyhow::Result<String> {
             len += 1;
         }
     }
-    Ok(len.to_string())
+    Ok(len.into())
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
+pub fn part_2(s: &str) -> anyhow::Result<AnswerValue> {
     let s: String = s.chars().filter(|ch| !ch.is_whitespace()).collect();
     let len = recursive_rle_len(s.as_str());
-    Ok(len.to_string())
+    Ok(len.into())
 }
 
 #[cfg(test)]
@@ -58,20 +59,18 @@ mod tests {
 
     #[test]
     fn p1_examples() {
-        assert_eq!(part_1("ADVENT").unwrap().as_str(), "6");
-        assert_eq!(part_1("A(1x5)BC").unwrap().as_str(), "7");
-        assert_eq!(part_1("X(8x2)(3x3)ABCY").unwrap().as_str(), "18");
+        assert_eq!(part_1("ADVENT").unwrap(), AnswerValue::Integer(6));
+        assert_eq!(part_1("A(1x5)BC").unwrap(), AnswerValue::Integer(7));
+        assert_eq!(part_1("X(8x2)(3x3)ABCY").unwrap(), AnswerValue::Integer(18));
     }
 
     #[test]
     fn p2_examples() {
-        assert_eq!(part_2("(3x3)XYZ").unwrap().as_str(), "9");
+        assert_eq!(part_2("(3x3)XYZ").unwrap(), AnswerValue::Integer(9));
 
         assert_eq!(
-            part_2("(25x3)(3x3)ABC(2x3)XY(5x2)PQRSTX(18x9)(3x2)TWO(5x7)SEVEN")
-                .unwrap()
-                .as_str(),
-            "445"
+            part_2("(25x3)(3x3)ABC(2x3)XY(5x2)PQRSTX(18x9)(3x2)TWO(5x7)SEVEN").unwrap(),
+            AnswerValue::Integer(445)
         );
     }
 }
@@ -7,16 +7,17 @@ use nom::combinator::{map, map_res};
 use nom::multi::separated_list1;
 use nom::sequence::preceded;
 use nom::IResult;
+use shared::AnswerValue;
 use std::collections::VecDeque;
 
 #[derive(Eq, PartialEq, Hash, Debug, Copy, Clone)]
-enum Destination {
+pub enum Destination {
     Output(u32),
     Bot(u32),
 }
 
 #[derive(Eq, PartialEq, Hash, Debug, Copy, Clone)]
-enum Line {
+pub enum Line {
     Give(u32, Destination),
     CompareAndGive(u32, Destination, Destination),
 }
@@ -45,7 +46,7 @@ fn parse_bot(s: &str) -> IResult<&str, Line> {
     Ok((s, Line::CompareAndGive(b, low, hi)))
 }
 
-fn parse(s: &str) -> anyhow::Result<Vec<Line>> {
+pub fn parse(s: &str) -> anyhow::Result<Vec<Line>> {
     separated_list1(tag("\n"), alt((parse_value, parse_bot)))(s)
         .map_err(|err| anyhow!("{err}"))
         .map(|(_, l)| l)
@@ -109,22 +110,20 @@ fn eval_tree(prog: &[Line]) -> FxHashMap<Destination, Slot> {
     bindings
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
-    let prog = parse(s)?;
-    let tree = eval_tree(&prog);
+pub fn part_1(prog: &[Line]) -> anyhow::Result<AnswerValue> {
+    let tree = eval_tree(prog);
 
     if let Some((Destination::Bot(bot), _)) = tree
         .into_iter()
         .find(|(bot, slot)| matches!(bot, Destination::Bot(_)) && matches!(slot, Slot::Two(17, 61)))
     {
-        return Ok(bot.to_string());
+        return Ok(bot.into());
     }
     Err(anyhow!("Unable to solve"))
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
-    let prog = parse(s)?;
-    let tree = eval_tree(&prog);
+pub fn part_2(prog: &[Line]) -> anyhow::Result<AnswerValue> {
+    let tree = eval_tree(prog);
     let mut product = 1;
     for i in 0..3u32 {
         let slot = tree
@@ -136,7 +135,7 @@ pub fn part_2(s: &str) -> anyhow::Result<String> {
             return Err(anyhow!("Got {slot:?} for {i}"));
         }
     }
-    Ok(product.to_string())
+    Ok(product.into())
 }
 
 #[cfg(test)]
@@ -25,6 +25,7 @@
 
 use fxhash::{FxHashMap, FxHashSet};
 use regex::Regex;
+use shared::AnswerValue;
 use std::collections::VecDeque;
 use std::sync::LazyLock;
 
@@ -82,7 +83,7 @@ struct Pair {
 }
 
 #[derive(Eq, PartialEq, Debug, Copy, Clone, Hash)]
-struct State<const N: usize> {
+pub struct State<const N: usize> {
     pairs: [Pair; N],
     elevator: u8,
 }
@@ -243,14 +244,17 @@ fn bfs<const N: usize>(initial_state: State<N>) -> usize {
     panic!("Unable to solve");
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
-    let state: State<5> = initial_state(parse(s));
-    let steps = bfs(state);
-    Ok(format!("{steps}"))
+/// The starting state of the puzzle input, which has 5 elements
+pub fn parse_state(s: &str) -> anyhow::Result<State<5>> {
+    Ok(initial_state(parse(s)))
+}
+
+pub fn part_1(state: &State<5>) -> anyhow::Result<AnswerValue> {
+    let steps = bfs(*state);
+    Ok(steps.into())
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
-    let state: State<5> = initial_state(parse(s));
+pub fn part_2(state: &State<5>) -> anyhow::Result<AnswerValue> {
     // The default pair is a generator + chip at the lowest floor
     let mut pairs = [Pair::default(); 7];
     for (i, pair) in state.pairs.into_iter().enumerate() {
@@ -259,7 +263,7 @@ pub fn part_2(s: &str) -> anyhow::Result<String> {
     let state: State<7> = State { pairs, elevator: 0 };
 
     let steps = bfs(state);
-    Ok(format!("{steps}"))
+    Ok(steps.into())
 }
 
 #[cfg(test)]
@@ -1,5 +1,6 @@
 use crate::day_12::assembunny::{Op, Registers};
 use anyhow::anyhow;
+use shared::AnswerValue;
 
 mod assembunny {
     use nom::branch::alt;
@@ -192,16 +193,16 @@ fn exec(prog: &[Op], set_c: i32) -> Registers {
     reg
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
+pub fn part_1(s: &str) -> anyhow::Result<AnswerValue> {
     let (_, prog) = assembunny::parse(s).map_err(|err| anyhow!("{err}"))?;
     let reg = exec(&prog, 0);
-    Ok(format!("{}", reg.a))
+    Ok(reg.a.into())
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
+pub fn part_2(s: &str) -> anyhow::Result<AnswerValue> {
     let (_, prog) = assembunny::parse(s).map_err(|err| anyhow!("{err}"))?;
     let reg = exec(&prog, 1);
-    Ok(format!("{}", reg.a))
+    Ok(reg.a.into())
 }
 
 #[cfg(test)]
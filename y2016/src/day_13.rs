@@ -1,5 +1,7 @@
-use fxhash::FxHashSet;
-use std::collections::VecDeque;
+use shared::search::{bfs, bfs_all};
+use shared::AnswerValue;
+
+const START: (usize, usize) = (1, 1);
 
 fn is_open_space(x: usize, y: usize, favorite_number: usize) -> bool {
     let r = x * x + 3 * x + 2 * x * y + y + y * y;
@@ -24,37 +26,43 @@ fn cardinal_neighbours(x: usize, y: usize) -> impl Iterator<Item = (usize, usize
     possible.into_iter().flatten()
 }
 
-fn bfs(favorite_number: usize, target: (usize, usize)) -> (usize, usize) {
-    let mut work = VecDeque::new();
-    work.push_back((0, (1, 1)));
-    let mut seen = FxHashSet::default();
-    seen.insert((1, 1));
-    let mut p2 = 0;
-    while let Some((steps, pos)) = work.pop_front() {
-        if steps <= 50 {
-            p2 += 1;
-        }
-        if pos == target {
-            return (steps, p2);
-        }
-        for (x, y) in cardinal_neighbours(pos.0, pos.1) {
-            if is_open_space(x, y, favorite_number) && seen.insert((x, y)) {
-                work.push_back((steps + 1, (x, y)));
-            }
-        }
-    }
+fn open_neighbours(
+    &(x, y): &(usize, usize),
+    favorite_number: usize,
+) -> impl Iterator<Item = (usize, usize)> {
+    cardinal_neighbours(x, y).filter(move |&(x, y)| is_open_space(x, y, favorite_number))
+}
+
+fn steps_to(favorite_number: usize, target: (usize, usize)) -> Option<usize> {
+    bfs(
+        START,
+        |pos| open_neighbours(pos, favorite_number),
+        |&pos| pos == target,
+    )
+    .map(|path| path.cost)
+}
 
-    panic!("Unable to solve")
+fn reachable_within(favorite_number: usize, steps: usize) -> usize {
+    // The maze goes on forever, but nothing further away than this can be reached in time
+    let limit = START.0 + START.1 + steps;
+    bfs_all(START, |pos| {
+        open_neighbours(pos, favorite_number).filter(|&(x, y)| x + y <= limit)
+    })
+    .iter()
+    .filter(|&(_, cost)| cost <= steps)
+    .count()
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
+pub fn part_1(s: &str) -> anyhow::Result<AnswerValue> {
     let n: usize = s.trim().parse()?;
-    Ok(format!("{}", bfs(n, (31, 39)).0))
+    steps_to(n, (31, 39))
+        .map(AnswerValue::from)
+        .ok_or_else(|| anyhow::anyhow!("Unable to reach 31,39"))
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
+pub fn part_2(s: &str) -> anyhow::Result<AnswerValue> {
     let n: usize = s.trim().parse()?;
-    Ok(format!("{}", bfs(n, (31, 39)).1))
+    Ok(reachable_within(n, 50).into())
 }
 
 #[cfg(test)]
@@ -63,7 +71,8 @@ mod tests {
 
     #[test]
     fn test_ex() {
-        assert_eq!(bfs(10, (7, 4)).0, 11);
-        assert_eq!(bfs(10, (7, 4)).1, 19);
+        assert_eq!(steps_to(10, (7, 4)), Some(11));
+        assert_eq!(reachable_within(10, 1), 3);
+        assert_eq!(reachable_within(10, 2), 5);
     }
 }
@@ -2,6 +2,7 @@ use itertools::Itertools;
 use md5::Digest;
 use nom::AsBytes;
 use rayon::prelude::*;
+use shared::AnswerValue;
 
 #[inline]
 fn hexchar(b: u8) -> u8 {
@@ -97,14 +98,14 @@ fn solve(salt: &str, stretch: usize) -> usize {
     unreachable!()
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
+pub fn part_1(s: &str) -> anyhow::Result<AnswerValue> {
     let ans = solve(s.trim(), 0);
-    Ok(format!("{ans}"))
+    Ok(ans.into())
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
+pub fn part_2(s: &str) -> anyhow::Result<AnswerValue> {
     let ans = solve(s.trim(), 2016);
-    Ok(format!("{ans}"))
+    Ok(ans.into())
 }
 
 #[cfg(test)]
@@ -1,8 +1,11 @@
+use anyhow::{anyhow, Context};
 use regex::Regex;
+use shared::math::crt;
+use shared::AnswerValue;
 use std::sync::LazyLock;
 
 #[derive(Copy, Clone)]
-struct Disc {
+pub struct Disc {
     positions: u8,
     current_position: u8,
 }
@@ -10,82 +13,55 @@ struct Disc {
 static DISC_PAT: &str = r"^Disc #\d+ has (\d+) positions; at time=0, it is at position (\d+).$";
 static DISC_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(DISC_PAT).unwrap());
 
-fn parse(s: &str) -> Vec<Disc> {
+pub fn parse(s: &str) -> anyhow::Result<Vec<Disc>> {
     s.lines()
         .filter(|line| !line.is_empty())
         .map(|line| {
-            let caps = DISC_RE.captures(line).unwrap();
-            let positions = caps.get(1).unwrap().as_str().parse().unwrap();
-            let current_position = caps.get(2).unwrap().as_str().parse().unwrap();
-            Disc {
+            let caps = DISC_RE
+                .captures(line)
+                .with_context(|| anyhow!("Invalid disc: {line}"))?;
+            let positions = caps.get(1).unwrap().as_str().parse()?;
+            let current_position = caps.get(2).unwrap().as_str().parse()?;
+            Ok(Disc {
                 positions,
                 current_position,
-            }
+            })
         })
         .collect()
 }
 
 // Problem statement is to find the time such that all discs are in
 // position 0 when the capsule passes them at time t + 1 + disc index
-// I believe this is equivalent to a set of modular equations:
+// This is a set of modular equations, which the chinese remainder theorem solves:
 // Disc #1 has 5 positions; at time=0, it is at position 4.
 // Disc #2 has 2 positions; at time=0, it is at position 1.
 // (4 + t + 1) % 5 == 0
 // (1 + t + 2) % 2 == 0
-struct LinearCongruenceSystem {
-    coefficients: Vec<usize>,
-    moduli: Vec<usize>,
-}
-
-impl From<Vec<Disc>> for LinearCongruenceSystem {
-    fn from(value: Vec<Disc>) -> Self {
-        let mut coefficients = Vec::with_capacity(value.len());
-        let mut moduli = Vec::with_capacity(value.len());
-
-        for (i, disc) in value.into_iter().enumerate() {
-            coefficients.push(i + 1 + usize::from(disc.current_position));
-            moduli.push(usize::from(disc.positions));
-        }
-
-        LinearCongruenceSystem {
-            coefficients,
-            moduli,
-        }
-    }
-}
-
-fn solve(system: &LinearCongruenceSystem) -> usize {
-    let mut t = 0;
-    let mut t_jump = 1;
-    let mut eq = 0;
-
-    while eq < system.moduli.len() {
-        if (system.coefficients[eq] + t).is_multiple_of(system.moduli[eq]) {
-            // It worked!
-            t_jump *= system.moduli[eq];
-            eq += 1;
-        } else {
-            t += t_jump;
-        }
-    }
-
-    t
+fn solve(discs: &[Disc]) -> anyhow::Result<i64> {
+    let congruences = discs.iter().enumerate().map(|(i, disc)| {
+        (
+            -(i as i64 + 1 + i64::from(disc.current_position)),
+            i64::from(disc.positions),
+        )
+    });
+    crt(congruences)
+        .map(|(t, _)| t)
+        .context("The discs never line up")
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
-    let inp = parse(s).into();
-    let ans = solve(&inp);
-    Ok(format!("{ans}"))
+pub fn part_1(discs: &[Disc]) -> anyhow::Result<AnswerValue> {
+    let ans = solve(discs)?;
+    Ok(ans.into())
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
-    let mut discs = parse(s);
+pub fn part_2(discs: &[Disc]) -> anyhow::Result<AnswerValue> {
+    let mut discs = discs.to_vec();
     discs.push(Disc {
         positions: 11,
         current_position: 0,
     });
-    let ans = solve(&discs.into());
-    Ok(format!("{ans}"))
+    let ans = solve(&discs)?;
+    Ok(ans.into())
 }
 
 #[cfg(test)]
@@ -94,10 +70,12 @@ mod tests {
 
     #[test]
     fn test_solve() {
-        let system = LinearCongruenceSystem {
-            coefficients: vec![5, 3],
-            moduli: vec![5, 2],
-        };
-        assert_eq!(solve(&system), 5);
+        let discs = parse(
+            "Disc #1 has 5 positions; at time=0, it is at position 4.
+Disc #2 has 2 positions; at time=0, it is at position 1.
+",
+        )
+        .unwrap();
+        assert_eq!(solve(&discs).unwrap(), 5);
     }
 }
@@ -1,4 +1,4 @@
-use shared::Answer;
+use shared::{Day, List, Owned, Parsed, Solution};
 
 mod day_01;
 mod day_02;
@@ -16,75 +16,32 @@ mod day_13;
 mod day_14;
 mod day_15;
 
-pub const SOLUTIONS: [Answer; 25] = [
-    Answer::SolvedBoth {
-        part_1: day_01::part_1,
-        part_2: day_01::part_2,
-    },
-    Answer::SolvedBoth {
-        part_1: day_02::part_1,
-        part_2: day_02::part_2,
-    },
-    Answer::SolvedBoth {
-        part_1: day_03::part_1,
-        part_2: day_03::part_2,
-    },
-    Answer::SolvedBoth {
-        part_1: day_04::part_1,
-        part_2: day_04::part_2,
-    },
-    Answer::SolvedBoth {
-        part_1: day_05::part_1,
-        part_2: day_05::part_2,
-    },
-    Answer::SolvedBoth {
-        part_1: day_06::part_1,
-        part_2: day_06::part_2,
-    },
-    Answer::SolvedBoth {
-        part_1: day_07::part_1,
-        part_2: day_07::part_2,
-    },
-    Answer::SolvedBoth {
-        part_1: day_08::part_1,
-        part_2: day_08::part_2,
-    },
-    Answer::SolvedBoth {
-        part_1: day_09::part_1,
-        part_2: day_09::part_2,
-    },
-    Answer::SolvedBoth {
-        part_1: day_10::part_1,
-        part_2: day_10::part_2,
-    },
-    Answer::SolvedBoth {
-        part_1: day_11::part_1,
-        part_2: day_11::part_2,
-    },
-    Answer::SolvedBoth {
-        part_1: day_12::part_1,
-        part_2: day_12::part_2,
-    },
-    Answer::SolvedBoth {
-        part_1: day_13::part_1,
-        part_2: day_13::part_2,
-    },
-    Answer::SolvedBoth {
-        part_1: day_14::part_1,
-        part_2: day_14::part_2,
-    },
-    Answer::SolvedBoth {
-        part_1: day_15::part_1,
-        part_2: day_15::part_2,
-    },
-    Answer::NotImplementedYet,
-    Answer::NotImplementedYet,
-    Answer::NotImplementedYet,
-    Answer::NotImplementedYet,
-    Answer::NotImplementedYet,
-    Answer::NotImplementedYet,
-    Answer::NotImplementedYet,
-    Answer::NotImplementedYet,
-    Answer::NotImplementedYet,
-    Answer::NotImplementedYet,
+const YEAR: u16 = 2016;
+
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &Day::both(YEAR, 1, day_01::part_1, day_01::part_2).titled("No Time for a Taxicab"),
+    &Day::both(YEAR, 2, day_02::part_1, day_02::part_2).titled("Bathroom Security"),
+    &Parsed::<List<i32>>::both(YEAR, 3, day_03::parse, day_03::part_1, day_03::part_2)
+        .titled("Squares With Three Sides"),
+    &Day::both(YEAR, 4, day_04::part_1, day_04::part_2).titled("Security Through Obscurity"),
+    &Day::both(YEAR, 5, day_05::part_1, day_05::part_2).titled("How About a Nice Game of Chess?"),
+    &Day::both(YEAR, 6, day_06::part_1, day_06::part_2).titled("Signals and Noise"),
+    &Day::both(YEAR, 7, day_07::part_1, day_07::part_2).titled("Internet Protocol Version 7"),
+    &Day::both(YEAR, 8, day_08::part_1, day_08::part_2).titled("Two-Factor Authentication"),
+    &Day::both(YEAR, 9, day_09::part_1, day_09::part_2).titled("Explosives in Cyberspace"),
+    &Parsed::<List<day_10::Line>>::both(YEAR, 10, day_10::parse, day_10::part_1, day_10::part_2)
+        .titled("Balance Bots"),
+    &Parsed::<Owned<day_11::State<5>>>::both(
+        YEAR,
+        11,
+        day_11::parse_state,
+        day_11::part_1,
+        day_11::part_2,
+    )
+    .titled("Radioisotope Thermoelectric Generators"),
+    &Day::both(YEAR, 12, day_12::part_1, day_12::part_2).titled("Leonardo's Monorail"),
+    &Day::both(YEAR, 13, day_13::part_1, day_13::part_2).titled("A Maze of Twisty Little Cubicles"),
+    &Day::both(YEAR, 14, day_14::part_1, day_14::part_2).titled("One-Time Pad"),
+    &Parsed::<List<day_15::Disc>>::both(YEAR, 15, day_15::parse, day_15::part_1, day_15::part_2)
+        .titled("Timing is Everything"),
 ];
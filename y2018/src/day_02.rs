@@ -1,6 +1,7 @@
 use anyhow::Result;
 use fxhash::FxHashMap as HashMap;
 use itertools::Itertools;
+use shared::AnswerValue;
 use std::hash::Hash;
 
 fn letter_count(line: &str) -> HashMap<char, usize> {
@@ -39,8 +40,8 @@ fn checksum(words: &str) -> usize {
     has_two * has_three
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    Ok(checksum(input).to_string())
+pub fn part_1(input: &str) -> Result<AnswerValue> {
+    Ok(checksum(input).into())
 }
 
 fn closest_match(words: &str) -> Option<(usize, &str, &str)> {
@@ -61,7 +62,7 @@ fn closest_match(words: &str) -> Option<(usize, &str, &str)> {
         .min_by_key(|(count, _, _)| *count)
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let (differing_letters, left, right) =
         closest_match(input).ok_or_else(|| anyhow::anyhow!("No close matches found in input"))?;
     if differing_letters != 1 {
@@ -76,7 +77,7 @@ pub fn part_2(input: &str) -> Result<String> {
         .filter(|(l, r)| l == r)
         .map(|(l, _)| l)
         .collect();
-    Ok(common_letters)
+    Ok(common_letters.into())
 }
 
 #[cfg(test)]
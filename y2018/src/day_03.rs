@@ -6,9 +6,10 @@ use nom::combinator::{map, map_res};
 use nom::multi::separated_list1;
 use nom::sequence::{preceded, tuple};
 use nom::IResult;
+use shared::AnswerValue;
 
 #[derive(Debug, PartialEq, Copy, Clone, Eq, Hash)]
-struct Claim {
+pub struct Claim {
     id: u32,
     x: u32,
     y: u32,
@@ -82,29 +83,27 @@ fn parse_claim(input: &str) -> IResult<&str, Claim> {
     })(input)
 }
 
-fn parse_claims(input: &str) -> Result<Vec<Claim>> {
+pub fn parse_claims(input: &str) -> Result<Vec<Claim>> {
     separated_list1(char('\n'), parse_claim)(input)
         .map_err(|e| anyhow!("{e}"))
         .map(|(_, claims)| claims)
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let claims = parse_claims(input)?;
-    let points = points_in_intersections(&claims);
-    Ok(points.len().to_string())
+pub fn part_1(claims: &[Claim]) -> Result<AnswerValue> {
+    let points = points_in_intersections(claims);
+    Ok(points.len().into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let claims = parse_claims(input)?;
-    let points = points_in_intersections(&claims);
+pub fn part_2(claims: &[Claim]) -> Result<AnswerValue> {
+    let points = points_in_intersections(claims);
     claims
-        .into_iter()
-        .find(|claim| {
-            let rect = Rect::from(*claim);
+        .iter()
+        .find(|&&claim| {
+            let rect = Rect::from(claim);
             let overlaps = rect.points().any(|point| points.contains(&point));
             !overlaps
         })
-        .map(|claim| claim.id.to_string())
+        .map(|claim| claim.id.into())
         .ok_or_else(|| anyhow!("No claim found"))
 }
 
@@ -9,6 +9,7 @@ use nom::combinator::{map, map_res};
 use nom::multi::separated_list1;
 use nom::sequence::{delimited, tuple};
 use nom::IResult;
+use shared::AnswerValue;
 
 #[derive(Debug, PartialEq, Copy, Clone, Eq, Hash)]
 enum Event {
@@ -79,7 +80,7 @@ fn tallied_logs(input: &str) -> Result<HashMap<u32, [u32; 60]>> {
     Ok(tally_log(&log))
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     tallied_logs(input)?
         .into_iter()
         .max_by_key(|(_, v)| v.iter().sum::<u32>())
@@ -88,11 +89,11 @@ pub fn part_1(input: &str) -> Result<String> {
             let (minute, _) = v.iter().enumerate().max_by_key(|(_, v)| *v).unwrap();
             id * minute as u32
         })
-        .map(|v| v.to_string())
+        .map(AnswerValue::from)
         .ok_or_else(|| anyhow!("No sleeping guard found"))
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     tallied_logs(input)?
         .into_iter()
         .map(|(id, v)| {
@@ -107,7 +108,7 @@ pub fn part_2(input: &str) -> Result<String> {
         })
         .max()
         .map(|((_, slept), id)| slept as u32 * id)
-        .map(|v| v.to_string())
+        .map(AnswerValue::from)
         .ok_or_else(|| anyhow!("No sleeping guard found"))
 }
 
@@ -143,11 +144,11 @@ mod tests {
 
     #[test]
     fn happy_day_part_1() {
-        assert_eq!(part_1(EXAMPLE_LOG).unwrap(), "240");
+        assert_eq!(part_1(EXAMPLE_LOG).unwrap(), AnswerValue::Integer(240));
     }
 
     #[test]
     fn happy_day_part_2() {
-        assert_eq!(part_2(EXAMPLE_LOG).unwrap(), "4455");
+        assert_eq!(part_2(EXAMPLE_LOG).unwrap(), AnswerValue::Integer(4455));
     }
 }
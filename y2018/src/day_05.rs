@@ -2,6 +2,7 @@ use anyhow::Result;
 use fxhash::FxHashSet as HashSet;
 use itertools::Itertools;
 use rayon::prelude::*;
+use shared::AnswerValue;
 
 fn step_polymer(polymer: &[char]) -> Vec<char> {
     let mut stack: Vec<char> = vec![];
@@ -17,13 +18,13 @@ fn step_polymer(polymer: &[char]) -> Vec<char> {
     stack
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let polymer: Vec<_> = input.trim_end().chars().collect();
     let reacted = step_polymer(&polymer);
-    Ok(reacted.len().to_string())
+    Ok(reacted.len().into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let polymer: Vec<_> = input.trim_end().chars().collect();
     let units: HashSet<_> = polymer.iter().map(|c| c.to_ascii_lowercase()).collect();
     units
@@ -37,7 +38,7 @@ pub fn part_2(input: &str) -> Result<String> {
             step_polymer(&candidate).len()
         })
         .min()
-        .map(|n| n.to_string())
+        .map(AnswerValue::from)
         .ok_or_else(|| anyhow::anyhow!("No solution found!"))
 }
 
@@ -6,6 +6,7 @@ use nom::character::complete::anychar;
 use nom::multi::separated_list1;
 use nom::sequence::{preceded, separated_pair, terminated};
 use nom::IResult;
+use shared::AnswerValue;
 use std::cmp::Reverse;
 use std::collections::BinaryHeap;
 
@@ -104,17 +105,17 @@ fn schedule(graph: &DependencyGraph, workers: usize, base_cost: i32) -> (i32, Ve
     (time_passed, out)
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     DependencyGraph::parse(input).map(|graph| {
         let order = topsort_alphabetical(&graph);
-        order.into_iter().collect()
+        order.into_iter().collect::<String>().into()
     })
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     DependencyGraph::parse(input).map(|graph| {
         let (time, _) = schedule(&graph, 5, 60);
-        time.to_string()
+        time.into()
     })
 }
 
@@ -1,7 +1,8 @@
 use anyhow::{Context, Result};
+use shared::AnswerValue;
 use std::num::ParseIntError;
 
-fn parse(input: &str) -> Result<Vec<u32>> {
+pub fn parse(input: &str) -> Result<Vec<u32>> {
     let v = input
         .split_ascii_whitespace()
         .map(|n| n.parse::<u32>())
@@ -33,10 +34,9 @@ fn parse_nodes(input: &mut impl Iterator<Item = u32>) -> Option<Node> {
 fn sum_metadata(node: &Node) -> u32 {
     node.metadata.iter().sum::<u32>() + node.children.iter().map(sum_metadata).sum::<u32>()
 }
-pub fn part_1(input: &str) -> Result<String> {
-    let v = parse(input)?;
+pub fn part_1(v: &[u32]) -> Result<AnswerValue> {
     let node = parse_nodes(&mut v.iter().copied()).context("Parse error")?;
-    Ok(sum_metadata(&node).to_string())
+    Ok(sum_metadata(&node).into())
 }
 
 fn node_value(node: &Node) -> u32 {
@@ -52,10 +52,9 @@ fn node_value(node: &Node) -> u32 {
     }
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let v = parse(input)?;
+pub fn part_2(v: &[u32]) -> Result<AnswerValue> {
     let node = parse_nodes(&mut v.iter().copied()).context("Parse error")?;
-    Ok(node_value(&node).to_string())
+    Ok(node_value(&node).into())
 }
 
 #[cfg(test)]
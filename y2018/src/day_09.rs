@@ -4,14 +4,15 @@ use nom::character::complete::digit1;
 use nom::combinator::map_res;
 use nom::sequence::{terminated, tuple};
 use nom::IResult;
+use shared::AnswerValue;
 use std::collections::VecDeque;
 use std::str::FromStr;
 
 fn posint(s: &str) -> IResult<&str, usize> {
     map_res(digit1, FromStr::from_str)(s)
 }
-#[derive(Eq, PartialEq, Debug)]
-struct Game {
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub struct Game {
     players: usize,
     last_marble_points: usize,
 }
@@ -57,15 +58,19 @@ fn high_score(game: &Game) -> usize {
     }
     players.into_iter().max().unwrap_or(0)
 }
-pub fn part_1(input: &str) -> Result<String> {
+pub fn parse_game(input: &str) -> Result<Game> {
     let (_, game) = parse(input).map_err(|err| anyhow!("{err}"))?;
-    Ok(high_score(&game).to_string())
+    Ok(game)
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let (_, mut game) = parse(input).map_err(|err| anyhow!("{err}"))?;
+pub fn part_1(game: &Game) -> Result<AnswerValue> {
+    Ok(high_score(game).into())
+}
+
+pub fn part_2(game: &Game) -> Result<AnswerValue> {
+    let mut game = *game;
     game.last_marble_points *= 100;
-    Ok(high_score(&game).to_string())
+    Ok(high_score(&game).into())
 }
 
 #[cfg(test)]
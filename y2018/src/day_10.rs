@@ -7,6 +7,7 @@ use nom::combinator::{map, map_res};
 use nom::multi::separated_list1;
 use nom::sequence::{delimited, preceded, separated_pair};
 use nom::IResult;
+use shared::AnswerValue;
 use std::str::FromStr;
 
 type Vec2 = [i64; 2];
@@ -75,7 +76,7 @@ fn area(points: &[Point2]) -> i64 {
     (xmax - xmin) * (ymax - ymin)
 }
 
-fn render(points: &[Point2]) -> String {
+fn draw(points: &[Point2]) -> String {
     let [[xmin, xmax], [ymin, ymax]] = bounds(points);
     let p: HashSet<_> = points.iter().map(|p| p.pos).collect();
     let mut out = String::new();
@@ -108,12 +109,17 @@ where
     unreachable!()
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    solve(input, |_, points| Ok(render(points)))
+/// The message in the sky, before reading the letters
+pub fn render(input: &str) -> Result<String> {
+    solve(input, |_, points| Ok(draw(points)))
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    solve(input, |time, _| Ok(time.to_string()))
+pub fn part_1(input: &str) -> Result<AnswerValue> {
+    shared::ocr::read_letters(&render(input)?).map(AnswerValue::from)
+}
+
+pub fn part_2(input: &str) -> Result<AnswerValue> {
+    solve(input, |time, _| Ok(time.into()))
 }
 
 #[cfg(test)]
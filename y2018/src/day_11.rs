@@ -1,6 +1,7 @@
 use anyhow::{Context, Result};
 use itertools::Itertools;
 use rayon::prelude::*;
+use shared::AnswerValue;
 
 struct GridCache {
     cache: Vec<i32>,
@@ -41,14 +42,14 @@ fn power_level(serial_number: i32, x: i32, y: i32) -> i32 {
     let power_level = power_level * rack_id;
     (power_level / 100) % 10 - 5
 }
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let serial: i32 = input.trim().parse()?;
     let grid = GridCache::new(serial);
     let (_, (x, y, _)) = grid.best_convolution(3);
-    Ok(format!("{x},{y}"))
+    Ok(format!("{x},{y}").into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let serial: i32 = input.trim().parse()?;
     let grid = GridCache::new(serial);
     let convolved: Vec<_> = (1..=15)
@@ -59,7 +60,7 @@ pub fn part_2(input: &str) -> Result<String> {
     convolved
         .iter()
         .max_by_key(|(ag, _)| *ag)
-        .map(|(_, (x, y, size))| format!("{x},{y},{size}"))
+        .map(|(_, (x, y, size))| format!("{x},{y},{size}").into())
         .context("Wat")
 }
 
@@ -1,6 +1,7 @@
 use anyhow::{anyhow, Context, Result};
 use fxhash::FxHashMap as Map;
 use fxhash::FxHashSet as Set;
+use shared::AnswerValue;
 
 #[derive(Debug, Eq, PartialEq, Clone)]
 struct State {
@@ -68,12 +69,12 @@ fn sum_pots(state: &State) -> isize {
     state.pots.iter().filter(|(_, &v)| v).map(|(&i, _)| i).sum()
 }
 
-pub fn part_1(s: &str) -> Result<String> {
+pub fn part_1(s: &str) -> Result<AnswerValue> {
     let state = iterate(s, 20).with_context(|| anyhow!("Unable to parse and iterate"))?;
-    Ok(sum_pots(&state).to_string())
+    Ok(sum_pots(&state).into())
 }
 
-pub fn part_2(s: &str) -> Result<String> {
+pub fn part_2(s: &str) -> Result<AnswerValue> {
     let (mut state, rules) = parse(s).with_context(|| anyhow!("Unable to parse"))?;
     let mut diffs = Vec::new();
     for i in 0.. {
@@ -85,7 +86,7 @@ pub fn part_2(s: &str) -> Result<String> {
             if last_diffs.len() == 1 {
                 let diff = diffs[i];
                 let sum = sum_pots(&state) + (50_000_000_000 - i) as isize * diff;
-                return Ok(sum.to_string());
+                return Ok(sum.into());
             }
         }
         state = next;
@@ -1,5 +1,6 @@
 use anyhow::{Context, Result};
 use fxhash::FxHashSet as Set;
+use shared::AnswerValue;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Direction {
@@ -181,10 +182,10 @@ fn first_crash(s: &str) -> Option<(isize, isize)> {
     }
 }
 
-pub fn part_1(s: &str) -> Result<String> {
+pub fn part_1(s: &str) -> Result<AnswerValue> {
     first_crash(s)
         .context("Unable to parse")
-        .map(|(x, y)| format!("{},{}", x, y))
+        .map(|(x, y)| format!("{},{}", x, y).into())
 }
 
 fn last_cart(s: &str) -> Option<(isize, isize)> {
@@ -204,10 +205,10 @@ fn last_cart(s: &str) -> Option<(isize, isize)> {
     }
 }
 
-pub fn part_2(s: &str) -> Result<String> {
+pub fn part_2(s: &str) -> Result<AnswerValue> {
     last_cart(s)
         .context("Unable to parse")
-        .map(|(x, y)| format!("{},{}", x, y))
+        .map(|(x, y)| format!("{},{}", x, y).into())
 }
 
 #[cfg(test)]
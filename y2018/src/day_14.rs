@@ -1,4 +1,5 @@
 use anyhow::Result;
+use shared::AnswerValue;
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 struct RecipeIterator {
@@ -40,14 +41,14 @@ impl Iterator for RecipeIterator {
     }
 }
 
-pub fn part_1(s: &str) -> Result<String> {
+pub fn part_1(s: &str) -> Result<AnswerValue> {
     let n = s.trim().parse::<usize>()?;
     let it = RecipeIterator::new();
     let recipes = it.skip(n).take(10);
-    Ok(recipes.map(|n| n.to_string()).collect::<Vec<_>>().join(""))
+    Ok(recipes.map(|n| n.to_string()).collect::<String>().into())
 }
 
-pub fn part_2(s: &str) -> Result<String> {
+pub fn part_2(s: &str) -> Result<AnswerValue> {
     let target = s
         .trim()
         .as_bytes()
@@ -66,7 +67,7 @@ pub fn part_2(s: &str) -> Result<String> {
         ix += 1;
         let _ = it.next();
     }
-    Ok(ix.to_string())
+    Ok(ix.into())
 }
 
 #[cfg(test)]
@@ -75,17 +76,17 @@ mod tests {
 
     #[test]
     fn test_part_1() {
-        assert_eq!(part_1("9").unwrap(), "5158916779".to_string());
-        assert_eq!(part_1("5").unwrap(), "0124515891".to_string());
-        assert_eq!(part_1("18").unwrap(), "9251071085".to_string());
-        assert_eq!(part_1("2018").unwrap(), "5941429882".to_string());
+        assert_eq!(part_1("9").unwrap(), AnswerValue::from("5158916779"));
+        assert_eq!(part_1("5").unwrap(), AnswerValue::from("0124515891"));
+        assert_eq!(part_1("18").unwrap(), AnswerValue::from("9251071085"));
+        assert_eq!(part_1("2018").unwrap(), AnswerValue::from("5941429882"));
     }
 
     #[test]
     fn test_part_2() {
-        assert_eq!(part_2("51589").unwrap(), "9".to_string());
-        assert_eq!(part_2("01245").unwrap(), "5".to_string());
-        assert_eq!(part_2("92510").unwrap(), "18".to_string());
-        assert_eq!(part_2("59414").unwrap(), "2018".to_string());
+        assert_eq!(part_2("51589").unwrap(), AnswerValue::Integer(9));
+        assert_eq!(part_2("01245").unwrap(), AnswerValue::Integer(5));
+        assert_eq!(part_2("92510").unwrap(), AnswerValue::Integer(18));
+        assert_eq!(part_2("59414").unwrap(), AnswerValue::Integer(2018));
     }
 }
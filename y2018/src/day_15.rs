@@ -1,6 +1,7 @@
 use anyhow::{anyhow, Result};
 use fxhash::FxHashSet;
 use itertools::Itertools;
+use shared::AnswerValue;
 use std::collections::VecDeque;
 
 type Pos = (i32, i32);
@@ -317,14 +318,14 @@ impl Game {
     }
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let mut game = Game::try_from(input)?;
     let rounds = game.play();
     let outcome = rounds * game.remaining_hitpoints();
-    Ok(format!("{outcome}"))
+    Ok(outcome.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let game = Game::try_from(input)?;
     let elves = game.elves();
     for ap in 4.. {
@@ -336,7 +337,7 @@ pub fn part_2(input: &str) -> Result<String> {
         }
         if current_game.elves() == elves {
             let outcome = rounds * current_game.remaining_hitpoints();
-            return Ok(format!("{outcome}"));
+            return Ok(outcome.into());
         }
     }
     unreachable!();
@@ -423,7 +424,7 @@ mod tests {
 #..G#E#
 #.....#
 #######";
-        assert_eq!(part_1(ex).unwrap(), "27730");
+        assert_eq!(part_1(ex).unwrap(), AnswerValue::Integer(27730));
     }
 
     #[test]
@@ -452,6 +453,6 @@ mod tests {
 #.G...G.#
 #.....G.#
 #########";
-        assert_eq!(part_1(ex).unwrap(), "18740");
+        assert_eq!(part_1(ex).unwrap(), AnswerValue::Integer(18740));
     }
 }
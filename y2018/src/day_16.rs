@@ -10,6 +10,7 @@ use nom::character::complete::char;
 use nom::multi::{many1, separated_list1};
 use nom::sequence::{delimited, preceded, separated_pair, tuple};
 use nom::IResult;
+use shared::AnswerValue;
 use Choice::*;
 
 fn compatible_instructions(
@@ -35,7 +36,7 @@ fn parse_register(s: &str) -> IResult<&str, Registers<4>> {
 }
 
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
-struct Example {
+pub struct Example {
     before: Registers<4>,
     program: Command,
     after: Registers<4>,
@@ -45,17 +46,23 @@ fn parse_examples(s: &str) -> IResult<&str, Vec<Example>> {
     separated_list1(tag("\n\n"), parse_example)(s)
 }
 
-fn parse(s: &str) -> anyhow::Result<(Vec<Example>, Vec<Command>)> {
+pub struct Manual {
+    examples: Vec<Example>,
+    program: Vec<Command>,
+}
+
+pub fn parse(s: &str) -> anyhow::Result<Manual> {
     fn p(s: &str) -> IResult<&str, (Vec<Example>, Vec<Command>)> {
         separated_pair(parse_examples, many1(char('\n')), parse_elflang_bin)(s)
     }
-    p(s).map_err(|e| anyhow!("{e}")).map(|(_, r)| r)
+    p(s).map_err(|e| anyhow!("{e}"))
+        .map(|(_, (examples, program))| Manual { examples, program })
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
-    let (examples, _) = parse(s)?;
-    let n = examples
-        .into_iter()
+pub fn part_1(manual: &Manual) -> anyhow::Result<AnswerValue> {
+    let n = manual
+        .examples
+        .iter()
         .filter(|e| {
             compatible_instructions(e.before, e.program, e.after)
                 .into_iter()
@@ -64,7 +71,7 @@ pub fn part_1(s: &str) -> anyhow::Result<String> {
                 >= 3
         })
         .count();
-    Ok(format!("{n}"))
+    Ok(n.into())
 }
 
 fn parse_example(s: &str) -> IResult<&str, Example> {
@@ -150,15 +157,14 @@ fn identify_opcodes<const N: usize>(examples: &[Example]) -> [Instruction; N] {
     out
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
-    let (examples, programs) = parse(s)?;
-    let mapping = identify_opcodes::<16>(&examples);
+pub fn part_2(manual: &Manual) -> anyhow::Result<AnswerValue> {
+    let mapping = identify_opcodes::<16>(&manual.examples);
     let mut registers = [0; 4];
-    for mut prog in programs {
+    for mut prog in manual.program.iter().copied() {
         prog.instruction = mapping[prog.instruction as usize];
         registers = elflang::exec(registers, &prog).context("Unable to execute program")?;
     }
-    Ok(format!("{}", registers[0]))
+    Ok(registers[0].into())
 }
 
 #[cfg(test)]
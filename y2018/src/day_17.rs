@@ -6,6 +6,7 @@ use nom::combinator::{map, map_res};
 use nom::multi::separated_list1;
 use nom::sequence::{preceded, separated_pair};
 use nom::IResult;
+use shared::AnswerValue;
 use PointSpec::*;
 use Tile::*;
 
@@ -195,16 +196,16 @@ impl State {
     }
 }
 
-pub fn part_1(s: &str) -> Result<String> {
+pub fn part_1(s: &str) -> Result<AnswerValue> {
     let mut state = State::try_new(s)?;
     state.tile(500, 0);
-    Ok(format!("{}", state.still + state.moving))
+    Ok((state.still + state.moving).into())
 }
 
-pub fn part_2(s: &str) -> Result<String> {
+pub fn part_2(s: &str) -> Result<AnswerValue> {
     let mut state = State::try_new(s)?;
     state.tile(500, 0);
-    Ok(format!("{}", state.still))
+    Ok(state.still.into())
 }
 #[cfg(test)]
 mod tests {
@@ -220,7 +221,7 @@ y=13, x=498..504
 ";
     #[test]
     fn test_p1() -> Result<()> {
-        assert_eq!(part_1(EX)?.as_str(), "57");
+        assert_eq!(part_1(EX)?, AnswerValue::Integer(57));
         Ok(())
     }
 
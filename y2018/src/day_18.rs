@@ -1,6 +1,7 @@
 use anyhow::{anyhow, Error, Result};
 use fxhash::FxHashMap;
 use itertools::Itertools;
+use shared::AnswerValue;
 use std::mem;
 use Tile::*;
 
@@ -117,12 +118,12 @@ fn resource_value(s: &str, turns: usize) -> Result<usize> {
     Ok(counts[1] * counts[2])
 }
 
-pub fn part_1(s: &str) -> Result<String> {
-    Ok(resource_value(s, 10)?).map(|n| n.to_string())
+pub fn part_1(s: &str) -> Result<AnswerValue> {
+    Ok(resource_value(s, 10)?.into())
 }
 
-pub fn part_2(s: &str) -> Result<String> {
-    Ok(resource_value(s, 1000000000)?).map(|n| n.to_string())
+pub fn part_2(s: &str) -> Result<AnswerValue> {
+    Ok(resource_value(s, 1000000000)?.into())
 }
 
 #[cfg(test)]
@@ -1,6 +1,7 @@
 use crate::elflang;
 use anyhow::{Context, Result};
 use fxhash::FxHashSet;
+use shared::AnswerValue;
 
 fn prime_factors(mut n: usize) -> Vec<usize> {
     let mut divisors = vec![];
@@ -48,14 +49,14 @@ fn solve(s: &str, reg0: usize) -> Result<usize> {
     Ok(sum_product_combinations(&factors))
 }
 
-pub fn part_1(s: &str) -> Result<String> {
+pub fn part_1(s: &str) -> Result<AnswerValue> {
     let reg0 = solve(s, 0)?;
-    Ok(reg0.to_string())
+    Ok(reg0.into())
 }
 
-pub fn part_2(s: &str) -> Result<String> {
+pub fn part_2(s: &str) -> Result<AnswerValue> {
     let reg0 = solve(s, 1)?;
-    Ok(reg0.to_string())
+    Ok(reg0.into())
 }
 
 #[cfg(test)]
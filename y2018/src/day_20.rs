@@ -6,6 +6,7 @@ use nom::combinator::map;
 use nom::multi::{many0, many1, separated_list1};
 use nom::sequence::{delimited, pair};
 use nom::IResult;
+use shared::AnswerValue;
 use std::collections::VecDeque;
 use Ast::*;
 use SimpleAst::*;
@@ -129,16 +130,16 @@ fn find_paths(s: &str) -> anyhow::Result<FxHashMap<Point, usize>> {
     Ok(shortest_paths(&map))
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
+pub fn part_1(s: &str) -> anyhow::Result<AnswerValue> {
     let paths = find_paths(s)?;
     let n = paths.values().copied().max().context("No paths found")?;
-    Ok(n.to_string())
+    Ok(n.into())
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
+pub fn part_2(s: &str) -> anyhow::Result<AnswerValue> {
     let paths = find_paths(s)?;
     let n = paths.values().filter(|&l| *l >= 1000).count();
-    Ok(n.to_string())
+    Ok(n.into())
 }
 
 #[cfg(test)]
@@ -148,7 +149,7 @@ mod tests {
     #[test]
     fn test_example() {
         let ex = "^ESSWWN(E|NNENN(EESS(WNSE|)SSS|WWWSSSSE(SW|NNNE)))$";
-        assert_eq!(part_1(ex).unwrap().as_str(), "23");
+        assert_eq!(part_1(ex).unwrap(), AnswerValue::Integer(23));
     }
 
     #[test]
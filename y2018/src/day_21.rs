@@ -4,6 +4,7 @@ use crate::elflang::Operand::Reg;
 use crate::elflang::{exec_with_ipreg, Command, DisElf, Instruction, Registers};
 use anyhow::{anyhow, Context, Result};
 use fxhash::FxHashSet;
+use shared::AnswerValue;
 
 fn find_ins_reg_to_watch(program: &[Command], regs: [&str; 6]) -> Option<(usize, usize)> {
     program
@@ -53,7 +54,7 @@ fn setup(s: &str) -> Result<(usize, Vec<Command>, (usize, usize))> {
     Ok((ip_reg, program, watch))
 }
 
-pub fn part_1(s: &str) -> Result<String> {
+pub fn part_1(s: &str) -> Result<AnswerValue> {
     let (ip_reg, program, watch) = setup(s)?;
     let mut ip: usize = 0;
     let mut registers: Registers<6> = [0usize; 6];
@@ -61,7 +62,7 @@ pub fn part_1(s: &str) -> Result<String> {
     let n = tick_once(&mut registers, watch, &mut ip, ip_reg, &program)
         .context("Unable to evaluate")?;
 
-    Ok(n.to_string())
+    Ok(n.into())
 }
 
 // Disassembly
@@ -120,7 +121,7 @@ fn rust_hash_fn(seed: usize, mul: usize, last_hash: usize) -> usize {
     current
 }
 
-pub fn part_2(s: &str) -> Result<String> {
+pub fn part_2(s: &str) -> Result<AnswerValue> {
     let (_, program, _) = setup(s)?;
     // We need to find the hash seed: it is in a Set(Lit(n)) where n is quite large and does
     // not target ip reg. In my program it is in index 7, so let's just assume that's true for
@@ -145,7 +146,7 @@ pub fn part_2(s: &str) -> Result<String> {
     loop {
         let n = rust_hash_fn(seed, mul, last);
         if !seen.insert(n) {
-            return Ok(last.to_string());
+            return Ok(last.into());
         }
         last = n;
     }
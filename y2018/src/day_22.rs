@@ -1,6 +1,7 @@
 use anyhow::{Context, Result};
 use fxhash::FxHashSet;
 use regex::Regex;
+use shared::AnswerValue;
 use std::cmp::Reverse;
 use std::collections::BinaryHeap;
 use Equipment::*;
@@ -157,7 +158,7 @@ fn kind(pos: Pos, map: &mut Map) -> Kind {
     }
 }
 
-pub fn part_1(s: &str) -> Result<String> {
+pub fn part_1(s: &str) -> Result<AnswerValue> {
     let mut map: Map = s.try_into()?;
     let mut risk_level = 0;
     for x in 0..=map.target.x() {
@@ -165,7 +166,7 @@ pub fn part_1(s: &str) -> Result<String> {
             risk_level += kind(Pos::new(x, y), &mut map) as u32;
         }
     }
-    Ok(risk_level.to_string())
+    Ok(risk_level.into())
 }
 
 fn shortest_path(map: &mut Map) -> u32 {
@@ -212,10 +213,10 @@ fn shortest_path(map: &mut Map) -> u32 {
     unreachable!()
 }
 
-pub fn part_2(s: &str) -> Result<String> {
+pub fn part_2(s: &str) -> Result<AnswerValue> {
     let mut map: Map = s.try_into()?;
     let time = shortest_path(&mut map);
-    Ok(time.to_string())
+    Ok(time.into())
 }
 
 #[cfg(test)]
@@ -244,7 +245,7 @@ mod tests {
 target: 10,10";
         let m: Map = s.try_into().unwrap();
         assert_eq!(m.depth, 510);
-        assert_eq!(part_1(s).unwrap().as_str(), "114");
+        assert_eq!(part_1(s).unwrap(), AnswerValue::Integer(114));
     }
 
     #[test]
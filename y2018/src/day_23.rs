@@ -1,67 +1,53 @@
 use anyhow::{anyhow, Context};
 use regex::Regex;
+use shared::geometry::{Cuboid, Point};
+use shared::AnswerValue;
 use std::cmp::Reverse;
 use std::collections::BinaryHeap;
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-struct Bot {
-    coords: [i32; 3],
+pub struct Bot {
+    coords: Point<i32, 3>,
     radius: i32,
 }
 
-fn manhattan(lhs: &[i32; 3], rhs: &[i32; 3]) -> i32 {
-    lhs.iter()
-        .zip(rhs.iter())
-        .map(|(lhs, rhs)| (*lhs - *rhs).abs())
-        .sum()
-}
-
 #[derive(Copy, Clone, Eq, PartialEq, Debug, Ord, PartialOrd)]
 struct Cube {
-    min_coords: [i32; 3],
-    max_coords: [i32; 3],
+    bounds: Cuboid<i32, 3>,
 }
 
 impl Cube {
     #[inline]
-    fn contains(&self, point: [i32; 3]) -> bool {
-        let [x, y, z] = point;
-        let xrange = self.min_coords[0]..=self.max_coords[0];
-        let yrange = self.min_coords[1]..=self.max_coords[1];
-        let zrange = self.min_coords[2]..=self.max_coords[2];
-        xrange.contains(&x) && yrange.contains(&y) && zrange.contains(&z)
+    fn contains(&self, point: Point<i32, 3>) -> bool {
+        self.bounds.contains(point)
     }
 
     #[inline]
     fn size(&self) -> i32 {
-        self.max_coords[0] - self.min_coords[0] + 1
+        self.bounds.dims()[0]
     }
 
     #[inline]
     fn expand(&self) -> Self {
         let sz = self.size() * 2 - 1;
-        let [xmin, ymin, zmin] = self.min_coords;
-        Self {
-            min_coords: self.min_coords,
-            max_coords: [xmin + sz, ymin + sz, zmin + sz],
-        }
+        let [xmin, ymin, zmin] = self.bounds.min.0;
+        Self::new([xmin, ymin, zmin], [xmin + sz, ymin + sz, zmin + sz])
     }
 
     #[inline]
     fn closest_to_origin(&self) -> i32 {
-        self.min_coords
+        self.bounds
+            .min
+            .0
             .iter()
-            .zip(self.max_coords.iter())
+            .zip(self.bounds.max.0.iter())
             .map(|(c1, c2)| c1.abs().min(c2.abs()))
             .sum()
     }
 
     fn new(min_coords: [i32; 3], max_coords: [i32; 3]) -> Self {
-        let [xsize, ysize, zsize] = [
-            max_coords[0] - min_coords[0] + 1,
-            max_coords[1] - min_coords[1] + 1,
-            max_coords[2] - min_coords[2] + 1,
-        ];
+        let bounds = Cuboid::new(Point(min_coords), Point(max_coords));
+        let [xsize, ysize, zsize] = bounds.dims();
         assert_eq!(xsize, ysize);
         assert_eq!(ysize, zsize);
         assert_eq!(
@@ -70,15 +56,12 @@ impl Cube {
             "xsize must be a power of 2: {xsize}"
         );
 
-        Self {
-            min_coords,
-            max_coords,
-        }
+        Self { bounds }
     }
 
     fn split(&self) -> [Self; 8] {
-        let [xmin, ymin, zmin] = self.min_coords;
-        let [xmax, ymax, zmax] = self.max_coords;
+        let [xmin, ymin, zmin] = self.bounds.min.0;
+        let [xmax, ymax, zmax] = self.bounds.max.0;
         let xlen = xmax - xmin;
         let ylen = ymax - ymin;
         let zlen = zmax - zmin;
@@ -99,12 +82,12 @@ impl Cube {
 }
 
 fn intersection_count(cube: &Cube, bots: &[Bot]) -> usize {
-    let [x1, y1, z1] = cube.min_coords;
-    let [x2, y2, z2] = cube.max_coords;
+    let [x1, y1, z1] = cube.bounds.min.0;
+    let [x2, y2, z2] = cube.bounds.max.0;
 
     bots.iter()
         .filter(|bot| {
-            let [bx, by, bz] = bot.coords;
+            let [bx, by, bz] = bot.coords.0;
             // Find the closest distance between the cube and the bot in all 3 dimensions
             let x = (x1 - bx).max(0) + (bx - x2).max(0);
             let y = (y1 - by).max(0) + (by - y2).max(0);
@@ -116,18 +99,18 @@ fn intersection_count(cube: &Cube, bots: &[Bot]) -> usize {
         .count()
 }
 
-fn parse_bots(s: &str) -> anyhow::Result<Vec<Bot>> {
+pub fn parse_bots(s: &str) -> anyhow::Result<Vec<Bot>> {
     let r = Regex::new(r"pos=<(-?\d+),(-?\d+),(-?\d+)>, r=(\d+)")?;
     let mut bots = vec![];
     for line in s.lines() {
         let m = r
             .captures(line)
             .with_context(|| anyhow!("Bad line: {line}"))?;
-        let coords = [
+        let coords = Point([
             m.get(1).unwrap().as_str().parse()?,
             m.get(2).unwrap().as_str().parse()?,
             m.get(3).unwrap().as_str().parse()?,
-        ];
+        ]);
         let radius = m.get(4).unwrap().as_str().parse()?;
         bots.push(Bot { coords, radius });
     }
@@ -138,24 +121,21 @@ fn parse_bots(s: &str) -> anyhow::Result<Vec<Bot>> {
 /// Find the lowest coordinate and make a 1x1x1 cube around it. Then double the side length
 /// of the cube until it contains all the bot positions
 fn make_initial_cube_around(bots: &[Bot]) -> Option<Cube> {
-    let xmin = bots.iter().map(|bot| bot.coords[0]).min()?;
-    let ymin = bots.iter().map(|bot| bot.coords[1]).min()?;
-    let zmin = bots.iter().map(|bot| bot.coords[2]).min()?;
-    let mut cube = Cube::new([xmin, ymin, zmin], [xmin, ymin, zmin]);
+    let lowest = Cuboid::bound(bots.iter().map(|bot| bot.coords))?.min.0;
+    let mut cube = Cube::new(lowest, lowest);
     while bots.iter().any(|bot| !cube.contains(bot.coords)) {
         cube = cube.expand();
     }
     Some(cube)
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
-    let bots = parse_bots(s)?;
+pub fn part_1(bots: &[Bot]) -> anyhow::Result<AnswerValue> {
     let best_bot = *bots.last().unwrap();
     let n = bots
-        .into_iter()
-        .filter(|b| manhattan(&best_bot.coords, &b.coords) <= best_bot.radius)
+        .iter()
+        .filter(|b| (best_bot.coords - b.coords).manhattan() <= best_bot.radius)
         .count();
-    Ok(n.to_string())
+    Ok(n.into())
 }
 
 fn best_cubelet(initial: Cube, bots: &[Bot]) -> Cube {
@@ -187,13 +167,12 @@ fn best_cubelet(initial: Cube, bots: &[Bot]) -> Cube {
     unreachable!()
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
-    let bots = parse_bots(s)?;
-    let cube = make_initial_cube_around(&bots)
+pub fn part_2(bots: &[Bot]) -> anyhow::Result<AnswerValue> {
+    let cube = make_initial_cube_around(bots)
         .with_context(|| anyhow!("Unable to make cube around {bots:?}"))?;
-    let found = best_cubelet(cube, &bots);
+    let found = best_cubelet(cube, bots);
     let n = found.closest_to_origin();
-    Ok(n.to_string())
+    Ok(n.into())
 }
 
 #[cfg(test)]
@@ -204,9 +183,9 @@ mod tests {
 
     impl Cube {
         fn points(&self) -> impl Iterator<Item = [i32; 3]> {
-            let xrange = self.min_coords[0]..=self.max_coords[0];
-            let yrange = self.min_coords[1]..=self.max_coords[1];
-            let zrange = self.min_coords[2]..=self.max_coords[2];
+            let [xmin, ymin, zmin] = self.bounds.min.0;
+            let [xmax, ymax, zmax] = self.bounds.max.0;
+            let (xrange, yrange, zrange) = (xmin..=xmax, ymin..=ymax, zmin..=zmax);
             xrange
                 .cartesian_product(yrange)
                 .cartesian_product(zrange)
@@ -248,7 +227,7 @@ pos=<10,10,10>, r=5
         let bots = parse_bots(ex).unwrap();
         let cube = make_initial_cube_around(&bots).unwrap();
         let best_cube = best_cubelet(cube, &bots);
-        assert_eq!(best_cube.min_coords, [12, 12, 12]);
+        assert_eq!(best_cube.bounds.min, Point([12, 12, 12]));
     }
 
     #[test]
@@ -294,23 +273,23 @@ pos=<10,10,10>, r=5
     fn collision_detection() {
         let cube = Cube::new([0, 0, 0], [7, 7, 7]);
         let bot = Bot {
-            coords: [1, 1, 1],
+            coords: Point([1, 1, 1]),
             radius: 1,
         };
         assert_eq!(intersection_count(&cube, &[bot]), 1);
         let bot = Bot {
-            coords: [9, 9, 9],
+            coords: Point([9, 9, 9]),
             radius: 1,
         };
         assert_eq!(intersection_count(&cube, &[bot]), 0);
         let bot = Bot {
-            coords: [9, 9, 9],
+            coords: Point([9, 9, 9]),
             // 7, 7, 7 is manhattan distance 6 away
             radius: 6,
         };
         assert_eq!(intersection_count(&cube, &[bot]), 1);
         let bot = Bot {
-            coords: [9, 9, 9],
+            coords: Point([9, 9, 9]),
             // 7, 7, 7 is manhattan distance 6 away
             radius: 5,
         };
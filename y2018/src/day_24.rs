@@ -8,9 +8,10 @@ use nom::combinator::{map, map_res, recognize, success};
 use nom::multi::{many1, separated_list1};
 use nom::sequence::{delimited, preceded, separated_pair};
 use nom::IResult;
+use shared::{AnswerValue, Input};
 
 #[derive(Debug, Clone, Eq, PartialEq)]
-struct Group<'a> {
+pub struct Group<'a> {
     units: i32,
     hit_points: i32,
     weakness: FxHashSet<String>,
@@ -95,12 +96,17 @@ fn parse_group(s: &str) -> IResult<&str, Group<'_>> {
 }
 
 #[derive(Debug, Clone)]
-struct Armies<'a> {
+pub struct Armies<'a> {
     immune_system: Vec<Group<'a>>,
     infection: Vec<Group<'a>>,
 }
 
-fn parse(s: &str) -> anyhow::Result<Armies<'_>> {
+impl Input for Armies<'static> {
+    type Of<'a> = Armies<'a>;
+    type View<'a> = Armies<'a>;
+}
+
+pub fn parse(s: &str) -> anyhow::Result<Armies<'_>> {
     let mut parser = separated_pair(
         preceded(
             tag("Immune System:\n"),
@@ -253,18 +259,16 @@ fn fight_loop(armies: &Armies, boost: i32) -> (i32, i32) {
     }
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
-    let armies = parse(s)?;
-    let (infection, immune_system) = fight_loop(&armies, 0);
-    Ok(infection.max(immune_system).to_string())
+pub fn part_1(armies: &Armies) -> anyhow::Result<AnswerValue> {
+    let (infection, immune_system) = fight_loop(armies, 0);
+    Ok(infection.max(immune_system).into())
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
-    let armies = parse(s)?;
+pub fn part_2(armies: &Armies) -> anyhow::Result<AnswerValue> {
     for boost in 1.. {
-        let (infection, immune_system) = fight_loop(&armies, boost);
+        let (infection, immune_system) = fight_loop(armies, boost);
         if immune_system > 0 && infection <= 0 {
-            return Ok(immune_system.to_string());
+            return Ok(immune_system.into());
         }
     }
     unreachable!()
@@ -285,7 +289,10 @@ Infection:
 
     #[test]
     fn test_p1() {
-        assert_eq!(part_1(EX).unwrap().as_str(), "5216");
+        assert_eq!(
+            part_1(&parse(EX).unwrap()).unwrap(),
+            AnswerValue::Integer(5216)
+        );
     }
 
     #[test]
@@ -7,10 +7,12 @@ use nom::combinator::{map_res, recognize};
 use nom::multi::separated_list1;
 use nom::sequence::preceded;
 use nom::IResult;
+use shared::geometry::Point;
+use shared::AnswerValue;
 
-type Point = [i32; 4];
+type Point4 = Point<i32, 4>;
 
-fn parse(s: &str) -> anyhow::Result<Vec<Point>> {
+fn parse(s: &str) -> anyhow::Result<Vec<Point4>> {
     fn int(s: &str) -> IResult<&str, i32> {
         map_res(
             alt((recognize(preceded(char('-'), digit1)), digit1)),
@@ -22,19 +24,14 @@ fn parse(s: &str) -> anyhow::Result<Vec<Point>> {
         tag("\n"),
         map_res(
             preceded(space0, separated_list1(tag(","), int)),
-            |v: Vec<i32>| v.try_into(),
+            |v: Vec<i32>| v.try_into().map(Point),
         ),
     )(s)
     .map_err(|err| anyhow!("{err}"))
     .map(|(_, v)| v)
 }
 
-#[inline]
-fn manhattan<const N: usize>(left: &[i32; N], right: &[i32; N]) -> i32 {
-    (0..N).map(|i| (left[i] - right[i]).abs()).sum()
-}
-
-fn constellations(points: &[Point]) -> usize {
+fn constellations(points: &[Point4]) -> usize {
     let mut unassigned = points.iter().collect_vec();
     let mut found = 0;
     // Choose any arbitrary point to be start of a new constellation
@@ -46,7 +43,7 @@ fn constellations(points: &[Point]) -> usize {
             // Grab all the points that are close to `check`
             let friends = unassigned
                 .iter()
-                .filter(|&&friend| manhattan(check, friend) <= 3)
+                .filter(|&&friend| (*check - *friend).manhattan() <= 3)
                 .copied()
                 .collect_vec();
             unassigned.retain(|point| !friends.contains(point));
@@ -56,9 +53,9 @@ fn constellations(points: &[Point]) -> usize {
     found
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
+pub fn part_1(s: &str) -> anyhow::Result<AnswerValue> {
     let v = parse(s)?;
-    Ok(constellations(&v).to_string())
+    Ok(constellations(&v).into())
 }
 
 #[cfg(test)]
@@ -1,6 +1,4 @@
-use shared::Answer;
-use shared::Answer::SolvedBoth;
-use std::string::ToString;
+use shared::{Day, List, Owned, Parsed, Solution};
 
 pub mod day_01;
 pub mod day_02;
@@ -29,105 +27,67 @@ mod day_24;
 mod day_25;
 mod elflang;
 
-pub const SOLUTIONS: [Answer; 25] = [
-    SolvedBoth {
-        part_1: day_01::part_1,
-        part_2: day_01::part_2,
-    },
-    SolvedBoth {
-        part_1: day_02::part_1,
-        part_2: day_02::part_2,
-    },
-    SolvedBoth {
-        part_1: day_03::part_1,
-        part_2: day_03::part_2,
-    },
-    SolvedBoth {
-        part_1: day_04::part_1,
-        part_2: day_04::part_2,
-    },
-    SolvedBoth {
-        part_1: day_05::part_1,
-        part_2: day_05::part_2,
-    },
-    SolvedBoth {
-        part_1: day_06::part_1,
-        part_2: day_06::part_2,
-    },
-    SolvedBoth {
-        part_1: day_07::part_1,
-        part_2: day_07::part_2,
-    },
-    SolvedBoth {
-        part_1: day_08::part_1,
-        part_2: day_08::part_2,
-    },
-    SolvedBoth {
-        part_1: day_09::part_1,
-        part_2: day_09::part_2,
-    },
-    SolvedBoth {
-        part_1: day_10::part_1,
-        part_2: day_10::part_2,
-    },
-    SolvedBoth {
-        part_1: day_11::part_1,
-        part_2: day_11::part_2,
-    },
-    SolvedBoth {
-        part_1: day_12::part_1,
-        part_2: day_12::part_2,
-    },
-    SolvedBoth {
-        part_1: day_13::part_1,
-        part_2: day_13::part_2,
-    },
-    SolvedBoth {
-        part_1: day_14::part_1,
-        part_2: day_14::part_2,
-    },
-    SolvedBoth {
-        part_1: day_15::part_1,
-        part_2: day_15::part_2,
-    },
-    SolvedBoth {
-        part_1: day_16::part_1,
-        part_2: day_16::part_2,
-    },
-    SolvedBoth {
-        part_1: day_17::part_1,
-        part_2: day_17::part_2,
-    },
-    SolvedBoth {
-        part_1: day_18::part_1,
-        part_2: day_18::part_2,
-    },
-    SolvedBoth {
-        part_1: day_19::part_1,
-        part_2: day_19::part_2,
-    },
-    SolvedBoth {
-        part_1: day_20::part_1,
-        part_2: day_20::part_2,
-    },
-    SolvedBoth {
-        part_1: day_21::part_1,
-        part_2: day_21::part_2,
-    },
-    SolvedBoth {
-        part_1: day_22::part_1,
-        part_2: day_22::part_2,
-    },
-    SolvedBoth {
-        part_1: day_23::part_1,
-        part_2: day_23::part_2,
-    },
-    SolvedBoth {
-        part_1: day_24::part_1,
-        part_2: day_24::part_2,
-    },
-    SolvedBoth {
-        part_1: day_25::part_1,
-        part_2: |_: &str| Ok("Collect stars!".to_string()),
-    },
+const YEAR: u16 = 2018;
+
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &Parsed::<List<i32>>::both(YEAR, 1, day_01::parse, day_01::part_1, day_01::part_2)
+        .titled("Chronal Calibration"),
+    &Day::both(YEAR, 2, day_02::part_1, day_02::part_2).titled("Inventory Management System"),
+    &Parsed::<List<day_03::Claim>>::both(
+        YEAR,
+        3,
+        day_03::parse_claims,
+        day_03::part_1,
+        day_03::part_2,
+    )
+    .titled("No Matter How You Slice It"),
+    &Day::both(YEAR, 4, day_04::part_1, day_04::part_2).titled("Repose Record"),
+    &Day::both(YEAR, 5, day_05::part_1, day_05::part_2).titled("Alchemical Reduction"),
+    &Parsed::<List<day_06::Point>>::both(
+        YEAR,
+        6,
+        day_06::parse_input,
+        day_06::part_1,
+        day_06::part_2,
+    )
+    .titled("Chronal Coordinates"),
+    &Day::both(YEAR, 7, day_07::part_1, day_07::part_2).titled("The Sum of Its Parts"),
+    &Parsed::<List<u32>>::both(YEAR, 8, day_08::parse, day_08::part_1, day_08::part_2)
+        .titled("Memory Maneuver"),
+    &Parsed::<Owned<day_09::Game>>::both(
+        YEAR,
+        9,
+        day_09::parse_game,
+        day_09::part_1,
+        day_09::part_2,
+    )
+    .titled("Marble Mania"),
+    &Day::both(YEAR, 10, day_10::part_1, day_10::part_2).titled("The Stars Align"),
+    &Day::both(YEAR, 11, day_11::part_1, day_11::part_2).titled("Chronal Charge"),
+    &Day::both(YEAR, 12, day_12::part_1, day_12::part_2).titled("Subterranean Sustainability"),
+    &Day::both(YEAR, 13, day_13::part_1, day_13::part_2).titled("Mine Cart Madness"),
+    &Day::both(YEAR, 14, day_14::part_1, day_14::part_2).titled("Chocolate Charts"),
+    &Day::both(YEAR, 15, day_15::part_1, day_15::part_2).titled("Beverage Bandits"),
+    &Parsed::<Owned<day_16::Manual>>::both(YEAR, 16, day_16::parse, day_16::part_1, day_16::part_2)
+        .titled("Chronal Classification"),
+    &Day::both(YEAR, 17, day_17::part_1, day_17::part_2).titled("Reservoir Research"),
+    &Day::both(YEAR, 18, day_18::part_1, day_18::part_2).titled("Settlers of The North Pole"),
+    &Day::both(YEAR, 19, day_19::part_1, day_19::part_2).titled("Go With The Flow"),
+    &Day::both(YEAR, 20, day_20::part_1, day_20::part_2).titled("A Regular Map"),
+    &Day::both(YEAR, 21, day_21::part_1, day_21::part_2).titled("Chronal Conversion"),
+    &Day::both(YEAR, 22, day_22::part_1, day_22::part_2).titled("Mode Maze"),
+    &Parsed::<List<day_23::Bot>>::both(
+        YEAR,
+        23,
+        day_23::parse_bots,
+        day_23::part_1,
+        day_23::part_2,
+    )
+    .titled("Experimental Emergency Teleportation"),
+    &Parsed::<day_24::Armies>::both(YEAR, 24, day_24::parse, day_24::part_1, day_24::part_2)
+        .titled("Immune System Simulator 20XX"),
+    &Day::both(YEAR, 25, day_25::part_1, |_: &str| {
+        Ok("Collect stars!".into())
+    })
+    .titled("Four-Dimensional Adventure"),
 ];
@@ -1,13 +1,14 @@
 use anyhow::Result;
+use shared::AnswerValue;
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let fuel: u64 = input
         .lines()
         .filter(|line| !line.is_empty())
         .filter_map(|line| line.parse().ok())
         .map(|w: u64| w / 3 - 2)
         .sum();
-    Ok(format!("{fuel}"))
+    Ok(fuel.into())
 }
 
 fn fuel_required(mut weight: i64) -> i64 {
@@ -19,12 +20,12 @@ fn fuel_required(mut weight: i64) -> i64 {
     fuel
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let fuel: i64 = input
         .lines()
         .filter(|line| !line.is_empty())
         .filter_map(|line| line.parse().ok())
         .map(fuel_required)
         .sum();
-    Ok(format!("{fuel}"))
+    Ok(fuel.into())
 }
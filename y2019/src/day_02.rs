@@ -7,44 +7,47 @@ use nom::combinator::map_res;
 use nom::multi::separated_list1;
 use nom::{Finish, IResult};
 use rayon::prelude::*;
+use shared::AnswerValue;
 use std::str::FromStr;
 
 fn parse(input: &str) -> IResult<&str, Vec<i32>> {
     separated_list1(tag(","), map_res(digit1, FromStr::from_str))(input)
 }
 
+pub fn parse_program(input: &str) -> Result<Vec<i32>> {
+    let (_, prog) = parse(input)
+        .finish()
+        .map_err(|err| anyhow!("Unable to parse: {err}"))?;
+    Ok(prog)
+}
+
 fn run_intcode_program(prog: &[i32]) -> Option<i64> {
     let mut program = Program::new(prog);
     program.exec().ok()?;
     Some(program.read_addr(0, ParameterMode::Immediate))
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let (_, mut prog) = parse(input)
-        .finish()
-        .map_err(|err| anyhow!("Unable to parse: {err}"))?;
+pub fn part_1(prog: &[i32]) -> Result<AnswerValue> {
+    let mut prog = prog.to_vec();
     prog[1] = 12;
     prog[2] = 2;
     run_intcode_program(&prog)
         .context("Unable to look up addr 0")
-        .map(|n| format!("{n}"))
+        .map(AnswerValue::from)
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let (_, prog) = parse(input)
-        .finish()
-        .map_err(|err| anyhow!("Unable to parse: {err}"))?;
+pub fn part_2(prog: &[i32]) -> Result<AnswerValue> {
     let options = (0..100).cartesian_product(0..100).collect_vec();
     let (noun, verb) = options
         .into_par_iter()
         .find_any(|(noun, verb)| {
-            let mut modified_prog = prog.clone();
+            let mut modified_prog = prog.to_vec();
             modified_prog[1] = *noun;
             modified_prog[2] = *verb;
             run_intcode_program(&modified_prog) == Some(19690720)
         })
         .context("Unable to solve")?;
-    Ok(format!("{}", 100 * noun + verb))
+    Ok((100 * noun + verb).into())
 }
 
 #[cfg(test)]
@@ -7,6 +7,7 @@ use nom::combinator::{map, map_res};
 use nom::multi::separated_list1;
 use nom::sequence::pair;
 use nom::{Finish, IResult};
+use shared::AnswerValue;
 use std::str::FromStr;
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
@@ -30,7 +31,9 @@ impl Direction {
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
-struct Instruction(Direction, u32);
+pub struct Instruction(Direction, u32);
+
+pub type Wires = (Vec<Instruction>, Vec<Instruction>);
 
 fn parse_direction(i: &str) -> IResult<&str, Direction> {
     use Direction::*;
@@ -54,8 +57,8 @@ fn parse_wire(i: &str) -> IResult<&str, Vec<Instruction>> {
     separated_list1(complete::char(','), parse_instruction)(i)
 }
 
-fn parse_wires(i: &str) -> Result<(Vec<Instruction>, Vec<Instruction>)> {
-    fn inner(i: &str) -> IResult<&str, (Vec<Instruction>, Vec<Instruction>)> {
+pub fn parse_wires(i: &str) -> Result<Wires> {
+    fn inner(i: &str) -> IResult<&str, Wires> {
         let (i, first) = parse_wire(i)?;
         let (i, _) = tag("\n")(i)?;
         let (i, second) = parse_wire(i)?;
@@ -83,9 +86,8 @@ fn wire_points(wire: &Vec<Instruction>) -> FxHashMap<[i32; 2], u32> {
     hs
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let (first, second) = parse_wires(input)?;
-    let (first, second) = (wire_points(&first), wire_points(&second));
+pub fn part_1((first, second): &Wires) -> Result<AnswerValue> {
+    let (first, second) = (wire_points(first), wire_points(second));
     let first: FxHashSet<_> = first.keys().collect();
     let second: FxHashSet<_> = second.keys().collect();
     let intersection = first.intersection(&second);
@@ -94,12 +96,11 @@ pub fn part_1(input: &str) -> Result<String> {
         .map(|[x, y]| x.abs() + y.abs())
         .min()
         .context("No intersections")
-        .map(|n| format!("{n}"))
+        .map(AnswerValue::from)
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let (first, second) = parse_wires(input)?;
-    let (first, second) = (wire_points(&first), wire_points(&second));
+pub fn part_2((first, second): &Wires) -> Result<AnswerValue> {
+    let (first, second) = (wire_points(first), wire_points(second));
     let fk: FxHashSet<_> = first.keys().collect();
     let sk: FxHashSet<_> = second.keys().collect();
     let intersection = fk.intersection(&sk);
@@ -108,5 +109,5 @@ pub fn part_2(input: &str) -> Result<String> {
         .map(|&point| first.get(point).unwrap() + second.get(point).unwrap())
         .min()
         .context("No intersections")
-        .map(|n| format!("{n}"))
+        .map(AnswerValue::from)
 }
@@ -1,4 +1,5 @@
 use anyhow::{anyhow, Context, Result};
+use shared::AnswerValue;
 use std::ops::RangeInclusive;
 
 fn candidates(i: &str) -> Result<RangeInclusive<i32>> {
@@ -29,9 +30,9 @@ fn digits_of(mut n: i32) -> [u8; 6] {
     digits
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let n = candidates(input)?.filter(|n| validate(*n)).count();
-    Ok(format!("{n}"))
+    Ok(n.into())
 }
 
 fn validate_part_2(n: i32) -> bool {
@@ -54,9 +55,9 @@ fn validate_part_2(n: i32) -> bool {
     pairs.all(geq) && run_lengths.contains(&2)
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let n = candidates(input)?.filter(|n| validate_part_2(*n)).count();
-    Ok(format!("{n}"))
+    Ok(n.into())
 }
 
 #[cfg(test)]
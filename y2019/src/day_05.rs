@@ -1,18 +1,19 @@
 use crate::intcode::Program;
 use anyhow::{Context, Result};
+use shared::AnswerValue;
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let mut prog = Program::parse(input.lines().next().context("Missing line in input")?)?;
     prog.input(1);
     prog.exec()?;
     let n = prog.output().last().copied().context("No output")?;
-    Ok(format!("{n}"))
+    Ok(n.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let mut prog = Program::parse(input.lines().next().context("Missing line in input")?)?;
     prog.input(5);
     prog.exec()?;
     let n = prog.output().last().copied().context("No output")?;
-    Ok(format!("{n}"))
+    Ok(n.into())
 }
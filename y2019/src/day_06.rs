@@ -1,8 +1,17 @@
 use anyhow::{Context, Result};
 use fxhash::FxHashMap as HashMap;
 use itertools::Itertools;
+use shared::{AnswerValue, Input};
 
-fn parse(input: &str) -> Result<HashMap<&str, &str>> {
+/// Maps each object to the object it orbits
+pub struct Orbits<'a>(HashMap<&'a str, &'a str>);
+
+impl Input for Orbits<'static> {
+    type Of<'a> = Orbits<'a>;
+    type View<'a> = Orbits<'a>;
+}
+
+pub fn parse(input: &str) -> Result<Orbits<'_>> {
     input
         .lines()
         .filter(|line| !line.is_empty())
@@ -10,7 +19,8 @@ fn parse(input: &str) -> Result<HashMap<&str, &str>> {
             let (orbited, orbiter) = line.split_once(')').context("Missing )")?;
             Ok((orbiter, orbited))
         })
-        .collect()
+        .collect::<Result<_>>()
+        .map(Orbits)
 }
 
 fn count_orbits(orbits: &HashMap<&str, &str>) -> usize {
@@ -33,10 +43,8 @@ fn count_orbits(orbits: &HashMap<&str, &str>) -> usize {
     cache.values().sum()
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    parse(input)
-        .map(|orbits| count_orbits(&orbits))
-        .map(|n| format!("{n}"))
+pub fn part_1(orbits: &Orbits) -> Result<AnswerValue> {
+    Ok(count_orbits(&orbits.0).into())
 }
 
 fn find_santa(orbits: &HashMap<&str, &str>) -> Result<usize> {
@@ -58,10 +66,8 @@ fn find_santa(orbits: &HashMap<&str, &str>) -> Result<usize> {
     Ok(my_path.len() + santa_path.len() + 2) // add back the 2 unequal places that terminated the loop
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    parse(input)
-        .and_then(|orbits| find_santa(&orbits))
-        .map(|n| format!("{n}"))
+pub fn part_2(orbits: &Orbits) -> Result<AnswerValue> {
+    find_santa(&orbits.0).map(AnswerValue::from)
 }
 
 #[cfg(test)]
@@ -84,7 +90,7 @@ K)L
 ",
         )
         .unwrap();
-        assert_eq!(count_orbits(&orbits), 42);
+        assert_eq!(count_orbits(&orbits.0), 42);
     }
 
     #[test]
@@ -106,6 +112,6 @@ I)SAN
 ",
         )
         .unwrap();
-        assert_eq!(find_santa(&orbits).unwrap(), 4);
+        assert_eq!(find_santa(&orbits.0).unwrap(), 4);
     }
 }
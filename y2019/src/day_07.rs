@@ -1,8 +1,9 @@
 use crate::intcode::{Output, Program};
 use anyhow::{Context, Result};
 use itertools::Itertools;
+use shared::AnswerValue;
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let prog = Program::parse(input.lines().next().context("Missing line in input")?)?;
     (0..5)
         .permutations(5)
@@ -19,7 +20,7 @@ pub fn part_1(input: &str) -> Result<String> {
         })
         .max()
         .context("No combination found")
-        .map(|n| format!("{n}"))
+        .map(AnswerValue::from)
 }
 
 fn feedback_loop(prog: &Program, phase_signals: &[i64]) -> Result<i64> {
@@ -50,14 +51,14 @@ fn feedback_loop(prog: &Program, phase_signals: &[i64]) -> Result<i64> {
     }
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let prog = Program::parse(input.lines().next().context("Missing line in input")?)?;
     (5..=9)
         .permutations(5)
         .filter_map(|phase_signals| feedback_loop(&prog, &phase_signals).ok())
         .max()
         .context("No combination found")
-        .map(|n| format!("{n}"))
+        .map(AnswerValue::from)
 }
 
 #[cfg(test)]
@@ -1,4 +1,5 @@
 use anyhow::{Context, Result};
+use shared::AnswerValue;
 
 #[derive(Eq, PartialEq, Debug, Clone)]
 struct Image<const N: usize> {
@@ -21,7 +22,7 @@ fn parse_image<const N: usize>(input: &str) -> Image<N> {
     Image { layers }
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     const DIM: usize = 25 * 6;
     let img: Image<DIM> = parse_image(input);
     img.layers
@@ -32,7 +33,7 @@ pub fn part_1(input: &str) -> Result<String> {
                 * layer.iter().filter(|&&ch| ch == 2).count()
         })
         .context("No layers")
-        .map(|n| format!("{n}"))
+        .map(AnswerValue::from)
 }
 
 fn decode<const N: usize>(image: &Image<N>) -> [u8; N] {
@@ -48,7 +49,8 @@ fn decode<const N: usize>(image: &Image<N>) -> [u8; N] {
     out
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+/// The decoded image, before reading the letters
+pub fn render(input: &str) -> String {
     const HEIGHT: usize = 6;
     const WIDTH: usize = 25;
     const DIM: usize = HEIGHT * WIDTH;
@@ -66,7 +68,11 @@ pub fn part_2(input: &str) -> Result<String> {
             out.push('\n');
         }
     }
-    Ok(out)
+    out
+}
+
+pub fn part_2(input: &str) -> Result<AnswerValue> {
+    shared::ocr::read_letters(&render(input)).map(AnswerValue::from)
 }
 
 #[cfg(test)]
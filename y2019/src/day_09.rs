@@ -1,17 +1,18 @@
 use crate::intcode::Program;
 use anyhow::{Context, Result};
+use shared::AnswerValue;
 
-fn run_prog_with(prog: &str, input: i64) -> Result<String> {
+fn run_prog_with(prog: &str, input: i64) -> Result<AnswerValue> {
     let mut prog = Program::parse(prog.lines().next().context("Empty input")?)?;
     prog.input(input);
     prog.exec()?;
-    Ok(format!("{:?}", prog.output()[0]))
+    Ok(prog.output()[0].into())
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     run_prog_with(input, 1)
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     run_prog_with(input, 2)
 }
@@ -1,15 +1,17 @@
 use anyhow::{Context, Result};
 use fxhash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use itertools::Itertools;
+use shared::AnswerValue;
 use std::cmp::Ordering::Equal;
 use std::cmp::{Ordering, Reverse};
 use std::f64::consts::PI;
 use std::hash::{Hash, Hasher};
 
 type Asteroid = [i32; 2];
+pub type Asteroids = HashSet<Asteroid>;
 
-fn parse(input: &str) -> HashSet<Asteroid> {
-    input
+pub fn parse(input: &str) -> Result<Asteroids> {
+    let asteroids = input
         .lines()
         .filter(|line| !line.is_empty())
         .enumerate()
@@ -22,7 +24,8 @@ fn parse(input: &str) -> HashSet<Asteroid> {
                 }
             })
         })
-        .collect()
+        .collect();
+    Ok(asteroids)
 }
 
 /// Can't put f64 into any Set without promising that we can hash/compare them nicely
@@ -90,12 +93,11 @@ fn most_detection_angles(asteroids: &HashSet<Asteroid>) -> Option<(Asteroid, usi
         .max_by_key(|(_, angles)| *angles)
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let asteroids = parse(input);
-    let winner = most_detection_angles(&asteroids);
+pub fn part_1(asteroids: &Asteroids) -> Result<AnswerValue> {
+    let winner = most_detection_angles(asteroids);
     winner
         .context("Unable to find any asteroid")
-        .map(|(_, n)| format!("{n}"))
+        .map(|(_, n)| n.into())
 }
 
 fn angles_to_others(
@@ -152,13 +154,12 @@ fn fire_laser(
     None
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let asteroids = parse(input);
-    let (source, _) = most_detection_angles(&asteroids).context("Unable to find source")?;
-    let mut source_angles = angles_to_others(source, &asteroids);
+pub fn part_2(asteroids: &Asteroids) -> Result<AnswerValue> {
+    let (source, _) = most_detection_angles(asteroids).context("Unable to find source")?;
+    let mut source_angles = angles_to_others(source, asteroids);
     let winner = fire_laser(&mut source_angles, 200).context("Unable to shoot 200 times")?;
     let n = winner[0] * 100 + winner[1];
-    Ok(format!("{n}"))
+    Ok(n.into())
 }
 
 #[cfg(test)]
@@ -177,13 +178,14 @@ mod tests {
 .##.#..###
 ##...#..#.
 .#....####",
-        );
+        )
+        .unwrap();
         assert_eq!(most_detection_angles(&asteroids).map(|(_, a)| a), Some(33));
     }
 
     #[test]
     fn test_ex_4() {
-        let asteroids = parse(EX_4);
+        let asteroids = parse(EX_4).unwrap();
         assert_eq!(most_detection_angles(&asteroids).map(|(_, a)| a), Some(210));
     }
 
@@ -195,7 +197,7 @@ mod tests {
 
     #[test]
     fn test_part_2() {
-        let asteroids = parse(EX_4);
+        let asteroids = parse(EX_4).unwrap();
         let (source, _) = most_detection_angles(&asteroids).unwrap();
         let mut targets = angles_to_others(source, &asteroids);
         let n_shot = fire_laser(&mut targets, 200);
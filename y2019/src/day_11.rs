@@ -2,6 +2,7 @@ use crate::intcode::{Output, Program};
 use anyhow::{anyhow, Context, Result};
 use fxhash::FxHashMap as HashMap;
 use itertools::Itertools;
+use shared::AnswerValue;
 
 type Panel = [i32; 2];
 #[derive(Eq, PartialEq, Hash, Copy, Clone, Debug)]
@@ -61,13 +62,14 @@ fn paint_hull(program: &Program, initial: Paint) -> Result<Hull> {
     }
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let prog = Program::parse(input.lines().next().context("Empty input")?)?;
     let hull = paint_hull(&prog, Paint::Black)?;
-    Ok(format!("{}", hull.len()))
+    Ok(hull.len().into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+/// The painted registration identifier, before reading the letters
+pub fn render(input: &str) -> Result<String> {
     let prog = Program::parse(input.lines().next().context("Empty input")?)?;
     let hull = paint_hull(&prog, Paint::White)?;
     let ymax = hull.keys().map(|c| c[1]).max().context("No paint")?;
@@ -92,3 +94,7 @@ pub fn part_2(input: &str) -> Result<String> {
         .collect_vec();
     Ok(display.join("\n"))
 }
+
+pub fn part_2(input: &str) -> Result<AnswerValue> {
+    shared::ocr::read_letters(&render(input)?).map(AnswerValue::from)
+}
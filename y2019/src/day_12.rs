@@ -9,11 +9,14 @@ use nom::combinator::{map, map_res};
 use nom::multi::separated_list1;
 use nom::sequence::{delimited, preceded, terminated, tuple};
 use nom::IResult;
+use shared::geometry::Point;
+use shared::math::lcm_all;
+use shared::AnswerValue;
 use std::str::FromStr;
 
-type Vec3 = [i32; 3];
+type Vec3 = Point<i32, 3>;
 
-#[derive(Copy, Clone, Eq, PartialEq, Default, Debug, Hash)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
 struct Moon {
     pos: Vec3,
     vel: Vec3,
@@ -23,13 +26,11 @@ impl Moon {
     fn new(pos: Vec3) -> Self {
         Moon {
             pos,
-            ..Moon::default()
+            vel: Point::origin(),
         }
     }
     fn energy(self) -> i32 {
-        let potential: i32 = self.pos.into_iter().map(|n| n.abs()).sum();
-        let kinetic: i32 = self.vel.into_iter().map(|n| n.abs()).sum();
-        potential * kinetic
+        self.pos.manhattan() * self.vel.manhattan()
     }
 }
 
@@ -52,7 +53,7 @@ fn parse_vec3(i: &str) -> IResult<&str, Vec3> {
         let (i, x) = terminated(parse_coord('x'), tag(", "))(i)?;
         let (i, y) = terminated(parse_coord('y'), tag(", "))(i)?;
         let (i, z) = parse_coord('z')(i)?;
-        Ok((i, [x, y, z]))
+        Ok((i, Point([x, y, z])))
     }
     delimited(complete::char('<'), inner, complete::char('>'))(i)
 }
@@ -68,23 +69,13 @@ fn time_step(moons: &[Moon], buf: &mut Vec<Moon>) {
     buf.extend(moons.iter().copied());
     for i in 0..moons.len() {
         for j in i..moons.len() {
-            let dx = (moons[j].pos[0] - moons[i].pos[0]).signum();
-            let dy = (moons[j].pos[1] - moons[i].pos[1]).signum();
-            let dz = (moons[j].pos[2] - moons[i].pos[2]).signum();
-            buf[i].vel[0] += dx;
-            buf[j].vel[0] -= dx;
-            buf[i].vel[1] += dy;
-            buf[j].vel[1] -= dy;
-            buf[i].vel[2] += dz;
-            buf[j].vel[2] -= dz;
+            let pull = Point((moons[j].pos - moons[i].pos).0.map(i32::signum));
+            buf[i].vel = buf[i].vel + pull;
+            buf[j].vel = buf[j].vel - pull;
         }
     }
-    buf.iter_mut().for_each(|moon| {
-        moon.pos
-            .iter_mut()
-            .zip(moon.vel.iter().copied())
-            .for_each(|(pos, vel)| *pos += vel);
-    });
+    buf.iter_mut()
+        .for_each(|moon| moon.pos = moon.pos + moon.vel);
 }
 
 fn n_steps(n: usize, moons: &[Moon]) -> Vec<Moon> {
@@ -103,8 +94,8 @@ fn solve_1(n: usize, input: &str) -> Result<i32> {
     Ok(last_state.into_iter().map(|moon| moon.energy()).sum())
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    solve_1(1000, input).map(|n| format!("{n}"))
+pub fn part_1(input: &str) -> Result<AnswerValue> {
+    solve_1(1000, input).map(AnswerValue::from)
 }
 
 fn solve_2(input: &str) -> Result<i64> {
@@ -123,8 +114,8 @@ fn solve_2(input: &str) -> Result<i64> {
             if repeat.is_none() {
                 let state = moons
                     .iter()
-                    .map(|moon| moon.pos[dim])
-                    .chain(moons.iter().map(|moon| moon.vel[dim]))
+                    .map(|moon| moon.pos.0[dim])
+                    .chain(moons.iter().map(|moon| moon.vel.0[dim]))
                     .collect_vec();
                 if !cache.insert(state) {
                     *repeat = Some(time);
@@ -141,25 +132,12 @@ fn solve_2(input: &str) -> Result<i64> {
         std::mem::swap(&mut moons, &mut scratch);
         time += 1;
     }
-    Ok(lcm(
-        lcm(x_repeat.unwrap(), y_repeat.unwrap()),
-        z_repeat.unwrap(),
-    ))
+    lcm_all([x_repeat, y_repeat, z_repeat].into_iter().flatten())
+        .ok_or_else(|| anyhow!("The period doesn't fit in an i64"))
 }
 
-fn lcm(lhs: i64, rhs: i64) -> i64 {
-    fn gcd(mut lhs: i64, mut rhs: i64) -> i64 {
-        while rhs != 0 {
-            (lhs, rhs) = (rhs, lhs.rem_euclid(rhs))
-        }
-        lhs
-    }
-    let g = gcd(lhs, rhs);
-    lhs * rhs / g
-}
-
-pub fn part_2(input: &str) -> Result<String> {
-    solve_2(input).map(|n| format!("{n}"))
+pub fn part_2(input: &str) -> Result<AnswerValue> {
+    solve_2(input).map(AnswerValue::from)
 }
 
 #[cfg(test)]
@@ -171,8 +149,11 @@ mod tests {
         assert!(parse_int("- 131").is_err());
         assert_eq!(parse_int("139"), Ok(("", 139)));
         assert_eq!(parse_coord('x')("x=-131, "), Ok((", ", -131)));
-        assert_eq!(parse_vec3("<x=1, y=9, z=4>\n<"), Ok(("\n<", [1, 9, 4])));
-        let moons = vec![Moon::new([1, 2, 3]), Moon::new([-3, -2, -1])];
+        assert_eq!(
+            parse_vec3("<x=1, y=9, z=4>\n<"),
+            Ok(("\n<", Point([1, 9, 4])))
+        );
+        let moons = vec![Moon::new(Point([1, 2, 3])), Moon::new(Point([-3, -2, -1]))];
         assert_eq!(
             parse_moons("<x=1, y=2, z=3>\n<x=-3, y=-2, z=-1>").unwrap(),
             moons
@@ -189,10 +170,10 @@ mod tests {
     #[test]
     fn test_timestep() {
         let example = vec![
-            Moon::new([-1, 0, 2]),
-            Moon::new([2, -10, -7]),
-            Moon::new([4, -8, 8]),
-            Moon::new([3, 5, -1]),
+            Moon::new(Point([-1, 0, 2])),
+            Moon::new(Point([2, -10, -7])),
+            Moon::new(Point([4, -8, 8])),
+            Moon::new(Point([3, 5, -1])),
         ];
         let mut next = vec![];
         time_step(&example, &mut next);
@@ -200,20 +181,20 @@ mod tests {
             next,
             vec![
                 Moon {
-                    pos: [2, -1, 1],
-                    vel: [3, -1, -1]
+                    pos: Point([2, -1, 1]),
+                    vel: Point([3, -1, -1])
                 },
                 Moon {
-                    pos: [3, -7, -4],
-                    vel: [1, 3, 3]
+                    pos: Point([3, -7, -4]),
+                    vel: Point([1, 3, 3])
                 },
                 Moon {
-                    pos: [1, -7, 5],
-                    vel: [-3, 1, -3]
+                    pos: Point([1, -7, 5]),
+                    vel: Point([-3, 1, -3])
                 },
                 Moon {
-                    pos: [2, 2, 0],
-                    vel: [-1, -3, 1]
+                    pos: Point([2, 2, 0]),
+                    vel: Point([-1, -3, 1])
                 },
             ]
         )
@@ -1,8 +1,9 @@
 use crate::intcode::{ParameterMode, Program};
 use anyhow::{Context, Result};
 use fxhash::FxHashSet as HashSet;
+use shared::AnswerValue;
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let mut prog = Program::parse(input.lines().next().context("Empty input")?)?;
     prog.exec()?;
     let mut blocks = HashSet::default();
@@ -18,10 +19,10 @@ pub fn part_1(input: &str) -> Result<String> {
         }
     }
     let n = blocks.len();
-    Ok(format!("{n}"))
+    Ok(n.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let mut prog = Program::parse(input.lines().next().context("Empty input")?)?;
     prog.write_addr(0, 2, ParameterMode::Immediate);
     let mut blocks = HashSet::default();
@@ -71,5 +72,5 @@ pub fn part_2(input: &str) -> Result<String> {
         }
     }
 
-    Ok(format!("{score}"))
+    Ok(score.into())
 }
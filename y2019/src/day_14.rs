@@ -5,6 +5,7 @@ use nom::character::complete::alpha1;
 use nom::combinator::map_res;
 use nom::multi::separated_list1;
 use nom::{character::complete::digit1, IResult};
+use shared::AnswerValue;
 use std::collections::VecDeque;
 use std::str::FromStr;
 
@@ -84,8 +85,8 @@ fn solve_fuel_cost(input: &str, amount: u32) -> Result<i64> {
     Ok(order_fuel(&recipes, amount))
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    solve_fuel_cost(input, 1).map(|n| format!("{n}"))
+pub fn part_1(input: &str) -> Result<AnswerValue> {
+    solve_fuel_cost(input, 1).map(AnswerValue::from)
 }
 
 fn spend_max_ores(recipes: &HashMap<&str, Reaction>, ore_fund: i64) -> i64 {
@@ -117,8 +118,8 @@ fn solve_max_spend(input: &str, ore_fund: i64) -> Result<i64> {
     Ok(spend_max_ores(&recipes, ore_fund))
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    solve_max_spend(input, 1000000000000).map(|n| format!("{n}"))
+pub fn part_2(input: &str) -> Result<AnswerValue> {
+    solve_max_spend(input, 1000000000000).map(AnswerValue::from)
 }
 
 #[cfg(test)]
@@ -1,6 +1,7 @@
 use crate::intcode::{Output, Program};
 use anyhow::{Context, Result};
 use fxhash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use shared::AnswerValue;
 use std::collections::VecDeque;
 
 const OPPOSITE: [i64; 5] = [
@@ -99,13 +100,13 @@ fn update_location(mut x: i32, mut y: i32, input: i64) -> (i32, i32) {
     (x, y)
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let prog = Program::parse(input.lines().next().context("Empty input")?)?;
     let map = bfs(&prog)?;
     map.values()
         .find(|reachable| reachable.kind == Tile::Oxygen)
         .copied()
-        .map(|reachable| format!("{}", reachable.cost))
+        .map(|reachable| reachable.cost.into())
         .context("No oxygen found")
 }
 
@@ -146,7 +147,7 @@ fn floodfill(map: &HashMap<(i32, i32), Reachable>, origin: (i32, i32)) -> usize
     max_cost
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let prog = Program::parse(input.lines().next().context("Empty input")?)?;
     let map = bfs(&prog)?;
     let oxygen = map
@@ -156,5 +157,5 @@ pub fn part_2(input: &str) -> Result<String> {
         .copied()
         .context("No oxygen found")?;
     let cost = floodfill(&map, oxygen);
-    Ok(format!("{cost}"))
+    Ok(cost.into())
 }
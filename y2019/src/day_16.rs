@@ -1,6 +1,7 @@
 use anyhow::{Context, Result};
 use itertools::Itertools;
 use rayon::prelude::*;
+use shared::AnswerValue;
 
 const BASE_PATTERN: [i32; 4] = [0, 1, 0, -1];
 
@@ -28,7 +29,7 @@ fn apply_fft(inputs: &[i32]) -> Vec<i32> {
         .collect()
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let line = input.lines().next().context("Empty input")?;
     let mut inputs = line
         .as_bytes()
@@ -43,10 +44,10 @@ pub fn part_1(input: &str) -> Result<String> {
         .take(8)
         .map(|dig| ((dig as u8) + b'0') as char)
         .join("");
-    Ok(out)
+    Ok(out.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let line = input.lines().next().context("Empty input")?;
     let mut inputs = line
         .as_bytes()
@@ -75,7 +76,7 @@ pub fn part_2(input: &str) -> Result<String> {
         inputs.extend((0..len).map(|i| (sums[last] - sums[i]) % 10));
     }
     let n = inputs[..8].iter().copied().fold(0, |acc, n| acc * 10 + n);
-    Ok(format!("{n}"))
+    Ok(n.into())
 }
 
 #[cfg(test)]
@@ -2,6 +2,7 @@ use crate::intcode::ParameterMode::Immediate;
 use crate::intcode::Program;
 use anyhow::{anyhow, Context, Result};
 use fxhash::FxHashSet as HashSet;
+use shared::AnswerValue;
 use std::fmt::Debug;
 
 #[derive(Eq, PartialEq, Copy, Clone, Debug)]
@@ -77,7 +78,7 @@ fn initial_state(input: &str) -> Result<(Scaffold, Robot)> {
     Ok((scaffold, robot))
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let (scaffold, _) = initial_state(input)?;
 
     let intersections = scaffold.iter().filter(|(x, y)| {
@@ -87,7 +88,7 @@ pub fn part_1(input: &str) -> Result<String> {
             .all(|n| scaffold.contains(n))
     });
     let n: i32 = intersections.copied().map(|(x, y)| x * y).sum();
-    Ok(format!("{n}"))
+    Ok(n.into())
 }
 
 #[derive(Eq, PartialEq, Copy, Clone, Debug)]
@@ -186,7 +187,7 @@ fn navigate(mut robot: Robot, scaffold: &Scaffold) -> Result<Vec<Action>> {
     Ok(actions)
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let (scaffold, robot) = initial_state(input)?;
     let _actions = navigate(robot, &scaffold)?;
     // The boring answer here is that I solved it manually in emacs instead of coding it,
@@ -205,5 +206,5 @@ pub fn part_2(input: &str) -> Result<String> {
         }
     }
     prog.exec()?;
-    Ok(format!("{}", prog.output().last().unwrap()))
+    Ok((*prog.output().last().unwrap()).into())
 }
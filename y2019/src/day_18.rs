@@ -1,10 +1,11 @@
 use anyhow::{anyhow, Context, Result};
 use fxhash::FxHashMap as HashMap;
 use fxhash::FxHashSet as HashSet;
+use shared::AnswerValue;
 use std::collections::VecDeque;
 
 #[derive(Eq, PartialEq, Copy, Clone, Debug)]
-enum Tile {
+pub enum Tile {
     Key(char),
     Door(char),
     Empty,
@@ -12,8 +13,10 @@ enum Tile {
 }
 
 type Map = HashMap<(i32, i32), Tile>;
+/// The player start position and the map
+pub type Maze = ((i32, i32), Map);
 
-fn parse_map(input: &str) -> Result<((i32, i32), Map)> {
+pub fn parse_map(input: &str) -> Result<Maze> {
     let map: Result<Map> = input
         .lines()
         .enumerate()
@@ -130,9 +133,8 @@ fn solve_maze(loc: (i32, i32), map: &Map) -> Result<usize> {
     Err(anyhow!("Unable to solve maze"))
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let (player_pos, map) = parse_map(input)?;
-    solve_maze(player_pos, &map).map(|n| format!("{n}"))
+pub fn part_1(&(player_pos, ref map): &Maze) -> Result<AnswerValue> {
+    solve_maze(player_pos, map).map(AnswerValue::from)
 }
 
 #[derive(Eq, PartialEq, Debug, Copy, Clone)]
@@ -242,9 +244,8 @@ fn solve_maze_2(loc: (i32, i32), map: &Map) -> Result<usize> {
     Err(anyhow!("No solution found"))
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let (player_pos, map) = parse_map(input)?;
-    solve_maze_2(player_pos, &map).map(|n| format!("{n}"))
+pub fn part_2(&(player_pos, ref map): &Maze) -> Result<AnswerValue> {
+    solve_maze_2(player_pos, map).map(AnswerValue::from)
 }
 
 #[cfg(test)]
@@ -1,5 +1,6 @@
 use crate::intcode::Program;
 use anyhow::{Context, Result};
+use shared::AnswerValue;
 
 fn affected_by_beam(x: i64, y: i64, prog: &Program) -> Result<i64> {
     let mut prog = prog.clone();
@@ -9,12 +10,12 @@ fn affected_by_beam(x: i64, y: i64, prog: &Program) -> Result<i64> {
     Ok(prog.output().last().copied().unwrap_or(0))
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let prog = Program::parse(input.lines().next().context("Empty input")?)?;
     let s: i64 = (0..50)
         .filter_map(|y| min_max_x_at(y, &prog).map(|(left, right)| right - left + 1))
         .sum();
-    Ok(format!("{s}"))
+    Ok(s.into())
 }
 
 fn min_max_x_at(y: i64, prog: &Program) -> Option<(i64, i64)> {
@@ -39,7 +40,7 @@ fn min_max_x_at(y: i64, prog: &Program) -> Option<(i64, i64)> {
     }
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let prog = Program::parse(input.lines().next().context("Empty input")?)?;
     let mut y = 512;
     let in_beam = |x: i64, y: i64| affected_by_beam(x, y, &prog).map(|p| p == 1);
@@ -50,7 +51,7 @@ pub fn part_2(input: &str) -> Result<String> {
             x += 1;
         }
         if in_beam(x + 99, y - 99)? {
-            return Ok(format!("{}", 10_000 * x + (y - 99)));
+            return Ok((10_000 * x + (y - 99)).into());
         }
         y += 1;
     }
@@ -2,44 +2,32 @@ use anyhow::{anyhow, Result};
 use fxhash::FxHashMap as HashMap;
 use fxhash::{FxHashMap, FxHashSet as HashSet};
 use itertools::Itertools;
-use std::collections::VecDeque;
+use shared::{search, AnswerValue};
 
 type Tiles = HashSet<(i32, i32)>;
 type Portals = FxHashMap<(i32, i32), (i32, i32)>;
-type Map = (Tiles, Portals, (i32, i32), (i32, i32));
+pub type Map = (Tiles, Portals, (i32, i32), (i32, i32));
 
-pub fn part_1(input: &str) -> Result<String> {
-    let map = parse_map(input);
-    bfs(&map).map(|n| format!("{n}"))
+pub fn part_1(map: &Map) -> Result<AnswerValue> {
+    bfs(map).map(AnswerValue::from)
 }
 const DXDY: [(i32, i32); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
 
 fn bfs(map: &Map) -> Result<usize> {
     let (tiles, portals, start, goal) = map;
-    let mut cache = HashSet::default();
-    let mut queue = VecDeque::new();
-    queue.push_back((*start, 0));
-
-    while let Some((loc, steps)) = queue.pop_front() {
-        if *goal == loc {
-            return Ok(steps);
-        } else {
-            if let Some(other_side) = portals.get(&loc) {
-                if !cache.contains(other_side) {
-                    cache.insert(*other_side);
-                    queue.push_back((*other_side, steps + 1));
-                }
-            }
-            for (dx, dy) in DXDY {
-                let next = (loc.0 + dx, loc.1 + dy);
-                if tiles.contains(&next) && !cache.contains(&next) {
-                    cache.insert(next);
-                    queue.push_back((next, steps + 1));
-                }
-            }
-        }
-    }
-    Err(anyhow!("Unable to solve maze"))
+    search::bfs(
+        *start,
+        |&loc| {
+            let walk = DXDY
+                .iter()
+                .map(move |(dx, dy)| (loc.0 + dx, loc.1 + dy))
+                .filter(|next| tiles.contains(next));
+            walk.chain(portals.get(&loc).copied())
+        },
+        |loc| loc == goal,
+    )
+    .map(|path| path.cost)
+    .ok_or_else(|| anyhow!("Unable to solve maze"))
 }
 
 fn bfs_with_levels(map: &Map) -> Result<usize> {
@@ -53,44 +41,31 @@ fn bfs_with_levels(map: &Map) -> Result<usize> {
 
     let is_outside_edge = |(x, y)| x == left || x == right || y == top || y == bot;
 
-    let mut cache = HashSet::default();
-    let mut queue = VecDeque::new();
-
-    queue.push_back((*start, 0i32, 0));
-    let goal = (*goal, 0);
-
-    while let Some((loc, level, steps)) = queue.pop_front() {
-        if goal == (loc, level) {
-            return Ok(steps);
-        } else {
-            if let Some(other_side) = portals.get(&loc) {
+    search::bfs(
+        (*start, 0i32),
+        |&(loc, level)| {
+            let walk = DXDY
+                .iter()
+                .map(move |(dx, dy)| ((loc.0 + dx, loc.1 + dy), level))
+                .filter(|(next, _)| tiles.contains(next));
+            let warp = portals.get(&loc).and_then(|&other_side| {
                 let level_change = if is_outside_edge(loc) { -1 } else { 1 };
                 let next_level = level + level_change;
-                let cache_key = (*other_side, next_level);
-                if !cache.contains(&cache_key) && next_level >= 0 {
-                    cache.insert(cache_key);
-                    queue.push_back((*other_side, next_level, steps + 1));
-                }
-            }
-            for (dx, dy) in DXDY {
-                let next = (loc.0 + dx, loc.1 + dy);
-                let cache_key = (next, level);
-                if tiles.contains(&next) && !cache.contains(&cache_key) {
-                    cache.insert(cache_key);
-                    queue.push_back((next, level, steps + 1));
-                }
-            }
-        }
-    }
-    Err(anyhow!("Unable to solve maze"))
+                (next_level >= 0).then_some((other_side, next_level))
+            });
+            walk.chain(warp)
+        },
+        |&state| state == (*goal, 0),
+    )
+    .map(|path| path.cost)
+    .ok_or_else(|| anyhow!("Unable to solve maze"))
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let map = parse_map(input);
-    bfs_with_levels(&map).map(|n| format!("{n}"))
+pub fn part_2(map: &Map) -> Result<AnswerValue> {
+    bfs_with_levels(map).map(AnswerValue::from)
 }
 
-fn parse_map(input: &str) -> Map {
+pub fn parse_map(input: &str) -> Result<Map> {
     let tiles = parse_tiles(input);
     let mut portals_by_name = HashMap::default();
     let mut portals = HashMap::default();
@@ -107,12 +82,12 @@ fn parse_map(input: &str) -> Map {
         }
     }
 
-    (
+    Ok((
         tiles,
         portals,
         *portals_by_name.get(&(b'A', b'A')).unwrap(),
         *portals_by_name.get(&(b'Z', b'Z')).unwrap(),
-    )
+    ))
 }
 
 fn parse_tiles(input: &str) -> Tiles {
@@ -310,13 +285,13 @@ YN......#               VT..#....QG
 
     #[test]
     fn test_example() {
-        let map = parse_map(EXAMPLE);
+        let map = parse_map(EXAMPLE).unwrap();
         assert_eq!(bfs(&map).unwrap(), 23);
     }
 
     #[test]
     fn test_large_example() {
-        let map = parse_map(LARGE_EXAMPLE);
+        let map = parse_map(LARGE_EXAMPLE).unwrap();
         assert_eq!(bfs(&map).unwrap(), 58);
     }
 
@@ -360,7 +335,7 @@ RE....#.#                           #......RF
 ";
     #[test]
     fn test_p2() {
-        let map = parse_map(P2_EXAMPLE);
+        let map = parse_map(P2_EXAMPLE).unwrap();
         assert_eq!(bfs_with_levels(&map).unwrap(), 396);
     }
 }
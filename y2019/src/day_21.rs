@@ -1,5 +1,6 @@
 use crate::intcode::Program;
 use anyhow::{anyhow, Result};
+use shared::AnswerValue;
 
 fn line_to_inputs(line: &str) -> impl Iterator<Item = i64> + '_ {
     line.trim_end()
@@ -23,7 +24,7 @@ fn input_script(script: &str, prog: &mut Program) {
 // J is true if we should jump, false otherwise
 // 3 instructions: AND X Y => Y = X & Y, OR X Y => Y = X | Y, NOT X Y => Y = !X
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let mut prog = Program::parse(input.trim_end())?;
     // Only jump if D is ground and there's a hole in A, B or C
     input_script(
@@ -38,13 +39,14 @@ pub fn part_1(input: &str) -> Result<String> {
     prog.exec()?;
     prog.output()
         .last()
-        .map(|i| i.to_string())
+        .copied()
+        .map(AnswerValue::from)
         .ok_or_else(|| anyhow!("No output"))
 }
 
 // Five new registers for up to 9 tiles away: E through I
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let mut prog = Program::parse(input.trim_end())?;
     // Only jump if D is ground and there's a hole in A, B or C
     // In addition, don't jump if E or H are holes
@@ -63,6 +65,7 @@ pub fn part_2(input: &str) -> Result<String> {
     prog.exec()?;
     prog.output()
         .last()
-        .map(|i| i.to_string())
+        .copied()
+        .map(AnswerValue::from)
         .ok_or_else(|| anyhow!("No output"))
 }
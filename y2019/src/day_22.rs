@@ -1,10 +1,12 @@
 use anyhow::{anyhow, Result};
 use nom::IResult;
+use shared::math::mod_inverse;
+use shared::AnswerValue;
 
 const DECK_SIZE: usize = 10007;
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
-enum Technique {
+pub enum Technique {
     DealIntoNewStack,
     Cut(i16),
     DealWithIncrement(u16),
@@ -39,14 +41,16 @@ fn parse_input(input: &str) -> IResult<&str, Vec<Technique>> {
     nom::multi::separated_list1(nom::character::complete::line_ending, parse_technique)(input)
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let techniques = parse_input(input)
-        .map_err(|e| anyhow!("Failed to parse input: {e}"))?
-        .1;
+pub fn parse_techniques(input: &str) -> Result<Vec<Technique>> {
+    let (_, techniques) = parse_input(input).map_err(|e| anyhow!("Failed to parse input: {e}"))?;
+    Ok(techniques)
+}
+
+pub fn part_1(techniques: &[Technique]) -> Result<AnswerValue> {
     // The LinearShuffle was created for part 2, see below
     let shuffle: LinearShuffle<DECK_SIZE> = LinearShuffle::from(techniques.iter().copied());
     let card = shuffle.placement(2019);
-    Ok(format!("{card}"))
+    Ok(card.into())
 }
 
 // PART 2 SKETCHPAD
@@ -147,8 +151,8 @@ impl<const N: usize> LinearShuffle<N> {
 
     fn invert(self) -> LinearShuffle<N> {
         let target = self.normalize();
-        let (gcd, a, _) = extended_gcd(target.scale, N as i128);
-        assert!(gcd == 1 || gcd == -1);
+        let a = mod_inverse(target.scale as i64, N as i64)
+            .expect("The scale is invertible when N is prime") as i128;
         LinearShuffle {
             scale: a,
             shift: -a * target.shift,
@@ -167,29 +171,13 @@ impl<const N: usize> From<Technique> for LinearShuffle<N> {
     }
 }
 
-fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
-    if a == 0 {
-        if b < 0 {
-            (-b, 0, -1)
-        } else {
-            (b, 0, 1)
-        }
-    } else {
-        let (g, x, y) = extended_gcd(b % a, a);
-        (g, y - (b / a) * x, x)
-    }
-}
-
-pub fn part_2(input: &str) -> Result<String> {
-    let techniques = parse_input(input)
-        .map_err(|e| anyhow!("Failed to parse input: {e}"))?
-        .1;
-    let shuffle: LinearShuffle<119315717514047> = LinearShuffle::from(techniques.into_iter());
+pub fn part_2(techniques: &[Technique]) -> Result<AnswerValue> {
+    let shuffle: LinearShuffle<119315717514047> = LinearShuffle::from(techniques.iter().copied());
     let shuffle_repeats: u64 = 101741582076661;
     let final_shuffle = shuffle.repeat(shuffle_repeats).normalize();
     let invert = final_shuffle.invert();
     assert_eq!(final_shuffle.compose(invert), LinearShuffle::default());
-    Ok(format!("{}", invert.placement(2020)))
+    Ok(invert.placement(2020).into())
 }
 
 #[cfg(test)]
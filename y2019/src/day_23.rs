@@ -1,6 +1,7 @@
 use crate::intcode::Program;
 use anyhow::anyhow;
 use anyhow::Result;
+use shared::AnswerValue;
 use std::collections::VecDeque;
 
 fn make_computers(nic: &Program) -> Vec<Program> {
@@ -40,7 +41,7 @@ fn distribute_packets(packet_queue: &mut VecDeque<(usize, i64, i64)>, nics: &mut
     }
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let prog = Program::parse(input.trim_end())?;
     let mut computers = make_computers(&prog);
     let mut output_pointers = vec![0; 50];
@@ -55,7 +56,7 @@ pub fn part_1(input: &str) -> Result<String> {
             halted[src] = nic.step(-1)?;
             if let Some((dst, x, y)) = postprocess_nic(src, &mut output_pointers, nic) {
                 if dst == 255 {
-                    return Ok(y.to_string());
+                    return Ok(y.into());
                 } else {
                     input_queue.push_back((dst as usize, x, y));
                 }
@@ -67,7 +68,7 @@ pub fn part_1(input: &str) -> Result<String> {
     Err(anyhow!("No solution found"))
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let prog = Program::parse(input.trim_end())?;
     let mut computers = make_computers(&prog);
     let mut output_pointers = vec![0; 50];
@@ -90,7 +91,7 @@ pub fn part_2(input: &str) -> Result<String> {
             if let Some((x, y)) = current_nat_packet {
                 if let Some(last_y) = last_natted_y {
                     if last_y == y {
-                        return Ok(y.to_string());
+                        return Ok(y.into());
                     }
                 }
                 last_natted_y = Some(y);
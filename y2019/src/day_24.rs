@@ -2,6 +2,7 @@ use anyhow::Result;
 use fxhash::FxHashMap as HashMap;
 use fxhash::FxHashSet as HashSet;
 use once_cell::sync::Lazy;
+use shared::AnswerValue;
 use std::hash::Hash;
 
 trait Grid<T> {
@@ -124,8 +125,8 @@ fn calc_part_1(input: &str) -> u32 {
     }
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    Ok(format!("{}", calc_part_1(input)))
+pub fn part_1(input: &str) -> Result<AnswerValue> {
+    Ok(calc_part_1(input).into())
 }
 
 #[derive(Default, Eq, PartialEq)]
@@ -211,8 +212,8 @@ fn calc_part_2(input: &str, count: usize) -> usize {
     grid.living.len()
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    Ok(format!("{}", calc_part_2(input, 200)))
+pub fn part_2(input: &str) -> Result<AnswerValue> {
+    Ok(calc_part_2(input, 200).into())
 }
 
 #[cfg(test)]
@@ -1,6 +1,7 @@
 use crate::intcode::Program;
 use anyhow::Result;
 use itertools::Itertools;
+use shared::AnswerValue;
 
 const PLAYTHROUGH: &str = "south
 west
@@ -33,16 +34,16 @@ south
 east
 east";
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let mut game = Program::parse(input.trim_end())?;
     game.ascii_input(PLAYTHROUGH);
     let _ = game.require_ascii_output();
     let history: String = game.output().iter().map(|ch| (*ch as u8) as char).collect();
     let post = history.lines().rev().take(3).collect_vec();
     let out = post.iter().rev().join("\n");
-    Ok(out)
+    Ok(out.into())
 }
 
-pub fn part_2(_input: &str) -> Result<String> {
-    Ok("Enter the solutions, collect stars".to_string())
+pub fn part_2(_input: &str) -> Result<AnswerValue> {
+    Ok("Enter the solutions, collect stars".into())
 }
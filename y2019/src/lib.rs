@@ -1,5 +1,4 @@
-use shared::Answer;
-use shared::Answer::SolvedBoth;
+use shared::{Day, List, Owned, Parsed, Solution};
 
 pub mod intcode;
 
@@ -29,105 +28,76 @@ pub mod day_23;
 pub mod day_24;
 pub mod day_25;
 
-pub const SOLUTIONS: [Answer; 25] = [
-    SolvedBoth {
-        part_1: day_01::part_1,
-        part_2: day_01::part_2,
-    },
-    SolvedBoth {
-        part_1: day_02::part_1,
-        part_2: day_02::part_2,
-    },
-    SolvedBoth {
-        part_1: day_03::part_1,
-        part_2: day_03::part_2,
-    },
-    SolvedBoth {
-        part_1: day_04::part_1,
-        part_2: day_04::part_2,
-    },
-    SolvedBoth {
-        part_1: day_05::part_1,
-        part_2: day_05::part_2,
-    },
-    SolvedBoth {
-        part_1: day_06::part_1,
-        part_2: day_06::part_2,
-    },
-    SolvedBoth {
-        part_1: day_07::part_1,
-        part_2: day_07::part_2,
-    },
-    SolvedBoth {
-        part_1: day_08::part_1,
-        part_2: day_08::part_2,
-    },
-    SolvedBoth {
-        part_1: day_09::part_1,
-        part_2: day_09::part_2,
-    },
-    SolvedBoth {
-        part_1: day_10::part_1,
-        part_2: day_10::part_2,
-    },
-    SolvedBoth {
-        part_1: day_11::part_1,
-        part_2: day_11::part_2,
-    },
-    SolvedBoth {
-        part_1: day_12::part_1,
-        part_2: day_12::part_2,
-    },
-    SolvedBoth {
-        part_1: day_13::part_1,
-        part_2: day_13::part_2,
-    },
-    SolvedBoth {
-        part_1: day_14::part_1,
-        part_2: day_14::part_2,
-    },
-    SolvedBoth {
-        part_1: day_15::part_1,
-        part_2: day_15::part_2,
-    },
-    SolvedBoth {
-        part_1: day_16::part_1,
-        part_2: day_16::part_2,
-    },
-    SolvedBoth {
-        part_1: day_17::part_1,
-        part_2: day_17::part_2,
-    },
-    SolvedBoth {
-        part_1: day_18::part_1,
-        part_2: day_18::part_2,
-    },
-    SolvedBoth {
-        part_1: day_19::part_1,
-        part_2: day_19::part_2,
-    },
-    SolvedBoth {
-        part_1: day_20::part_1,
-        part_2: day_20::part_2,
-    },
-    SolvedBoth {
-        part_1: day_21::part_1,
-        part_2: day_21::part_2,
-    },
-    SolvedBoth {
-        part_1: day_22::part_1,
-        part_2: day_22::part_2,
-    },
-    SolvedBoth {
-        part_1: day_23::part_1,
-        part_2: day_23::part_2,
-    },
-    SolvedBoth {
-        part_1: day_24::part_1,
-        part_2: day_24::part_2,
-    },
-    SolvedBoth {
-        part_1: day_25::part_1,
-        part_2: day_25::part_2,
-    },
+const YEAR: u16 = 2019;
+
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &Day::both(YEAR, 1, day_01::part_1, day_01::part_2)
+        .titled("The Tyranny of the Rocket Equation"),
+    &Parsed::<List<i32>>::both(
+        YEAR,
+        2,
+        day_02::parse_program,
+        day_02::part_1,
+        day_02::part_2,
+    )
+    .titled("1202 Program Alarm"),
+    &Parsed::<Owned<day_03::Wires>>::both(
+        YEAR,
+        3,
+        day_03::parse_wires,
+        day_03::part_1,
+        day_03::part_2,
+    )
+    .titled("Crossed Wires"),
+    &Day::both(YEAR, 4, day_04::part_1, day_04::part_2).titled("Secure Container"),
+    &Day::both(YEAR, 5, day_05::part_1, day_05::part_2).titled("Sunny with a Chance of Asteroids"),
+    &Parsed::<day_06::Orbits>::both(YEAR, 6, day_06::parse, day_06::part_1, day_06::part_2)
+        .titled("Universal Orbit Map"),
+    &Day::both(YEAR, 7, day_07::part_1, day_07::part_2).titled("Amplification Circuit"),
+    &Day::both(YEAR, 8, day_08::part_1, day_08::part_2).titled("Space Image Format"),
+    &Day::both(YEAR, 9, day_09::part_1, day_09::part_2).titled("Sensor Boost"),
+    &Parsed::<Owned<day_10::Asteroids>>::both(
+        YEAR,
+        10,
+        day_10::parse,
+        day_10::part_1,
+        day_10::part_2,
+    )
+    .titled("Monitoring Station"),
+    &Day::both(YEAR, 11, day_11::part_1, day_11::part_2).titled("Space Police"),
+    &Day::both(YEAR, 12, day_12::part_1, day_12::part_2).titled("The N-Body Problem"),
+    &Day::both(YEAR, 13, day_13::part_1, day_13::part_2).titled("Care Package"),
+    &Day::both(YEAR, 14, day_14::part_1, day_14::part_2).titled("Space Stoichiometry"),
+    &Day::both(YEAR, 15, day_15::part_1, day_15::part_2).titled("Oxygen System"),
+    &Day::both(YEAR, 16, day_16::part_1, day_16::part_2).titled("Flawed Frequency Transmission"),
+    &Day::both(YEAR, 17, day_17::part_1, day_17::part_2).titled("Set and Forget"),
+    &Parsed::<Owned<day_18::Maze>>::both(
+        YEAR,
+        18,
+        day_18::parse_map,
+        day_18::part_1,
+        day_18::part_2,
+    )
+    .titled("Many-Worlds Interpretation"),
+    &Day::both(YEAR, 19, day_19::part_1, day_19::part_2).titled("Tractor Beam"),
+    &Parsed::<Owned<day_20::Map>>::both(
+        YEAR,
+        20,
+        day_20::parse_map,
+        day_20::part_1,
+        day_20::part_2,
+    )
+    .titled("Donut Maze"),
+    &Day::both(YEAR, 21, day_21::part_1, day_21::part_2).titled("Springdroid Adventure"),
+    &Parsed::<List<day_22::Technique>>::both(
+        YEAR,
+        22,
+        day_22::parse_techniques,
+        day_22::part_1,
+        day_22::part_2,
+    )
+    .titled("Slam Shuffle"),
+    &Day::both(YEAR, 23, day_23::part_1, day_23::part_2).titled("Category Six"),
+    &Day::both(YEAR, 24, day_24::part_1, day_24::part_2).titled("Planet of Discord"),
+    &Day::both(YEAR, 25, day_25::part_1, day_25::part_2).titled("Cryostasis"),
 ];
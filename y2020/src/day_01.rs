@@ -1,8 +1,9 @@
 use anyhow::{anyhow, Context};
 use fxhash::FxHashSet as HashSet;
 use itertools::Itertools;
+use shared::AnswerValue;
 
-pub fn part_1(input: &str) -> anyhow::Result<String> {
+pub fn part_1(input: &str) -> anyhow::Result<AnswerValue> {
     let nums: Result<HashSet<_>, _> = input.lines().map(str::parse::<i32>).collect();
     let nums = nums?;
     let x = nums
@@ -10,10 +11,10 @@ pub fn part_1(input: &str) -> anyhow::Result<String> {
         .find(|&&n| nums.contains(&(2020 - n)))
         .with_context(|| anyhow!("Unable to find x"))?;
     let answer = *x * (2020 - *x);
-    Ok(format!("{answer}"))
+    Ok(answer.into())
 }
 
-pub fn part_2(input: &str) -> anyhow::Result<String> {
+pub fn part_2(input: &str) -> anyhow::Result<AnswerValue> {
     let nums: Result<HashSet<_>, _> = input.lines().map(str::parse::<i32>).collect();
     let nums = nums?;
     let (&x, &y) = nums
@@ -22,5 +23,5 @@ pub fn part_2(input: &str) -> anyhow::Result<String> {
         .find(|&(x, y)| nums.contains(&(2020 - *x - *y)))
         .with_context(|| anyhow!("Unable to find x, y"))?;
     let z = 2020 - x - y;
-    Ok(format!("{}", x * y * z))
+    Ok((x * y * z).into())
 }
@@ -1,6 +1,7 @@
 use anyhow::Result;
+use shared::AnswerValue;
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let valid = input
         .lines()
         .filter_map(|line| {
@@ -15,10 +16,10 @@ pub fn part_1(input: &str) -> Result<String> {
         })
         .filter(|matched| *matched)
         .count();
-    Ok(format!("{valid}"))
+    Ok(valid.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let valid = input
         .lines()
         .filter_map(|line| {
@@ -39,5 +40,5 @@ pub fn part_2(input: &str) -> Result<String> {
         })
         .filter(|matched| *matched)
         .count();
-    Ok(format!("{valid}"))
+    Ok(valid.into())
 }
@@ -1,4 +1,5 @@
 use anyhow::{anyhow, Context, Result};
+use shared::AnswerValue;
 
 #[derive(Eq, PartialEq, Debug, Copy, Clone)]
 enum Tile {
@@ -6,13 +7,13 @@ enum Tile {
     Empty,
 }
 #[derive(Eq, PartialEq, Debug, Clone)]
-struct Area {
+pub struct Area {
     height: usize,
     width: usize,
     tiles: Vec<Tile>,
 }
 
-fn parse(input: &str) -> Result<Area> {
+pub fn parse(input: &str) -> Result<Area> {
     let width = input
         .lines()
         .next()
@@ -50,20 +51,18 @@ fn traverse(area: &Area, dx: usize, dy: usize) -> i32 {
     trees
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let area = parse(input)?;
-    let trees_visited = traverse(&area, 3, 1);
-    Ok(format!("{trees_visited}"))
+pub fn part_1(area: &Area) -> Result<AnswerValue> {
+    let trees_visited = traverse(area, 3, 1);
+    Ok(trees_visited.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let area = parse(input)?;
+pub fn part_2(area: &Area) -> Result<AnswerValue> {
     let paths = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
     let answer: i32 = paths
         .into_iter()
-        .map(|(dx, dy)| traverse(&area, dx, dy))
+        .map(|(dx, dy)| traverse(area, dx, dy))
         .product();
-    Ok(format!("{answer}"))
+    Ok(answer.into())
 }
 
 #[cfg(test)]
@@ -71,11 +70,17 @@ mod tests {
     use super::*;
     #[test]
     fn test_p1() {
-        assert_eq!(part_1(EXAMPLE).unwrap(), "7".to_string());
+        assert_eq!(
+            part_1(&parse(EXAMPLE).unwrap()).unwrap(),
+            AnswerValue::Integer(7)
+        );
     }
     #[test]
     fn test_p2() {
-        assert_eq!(part_2(EXAMPLE).unwrap(), "336".to_string());
+        assert_eq!(
+            part_2(&parse(EXAMPLE).unwrap()).unwrap(),
+            AnswerValue::Integer(336)
+        );
     }
     const EXAMPLE: &str = "..##.......
 #...#...#..
@@ -1,15 +1,16 @@
 use anyhow::Result;
 use fxhash::FxHashMap as HashMap;
 use itertools::Itertools;
+use shared::AnswerValue;
 use std::ops::RangeInclusive;
 
 const REQUIRED: [&str; 7] = ["byr:", "iyr:", "eyr:", "hgt:", "ecl:", "hcl:", "pid:"];
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let n = input
         .split("\n\n")
         .filter(|rec| REQUIRED.iter().all(|field| rec.contains(field)))
         .count();
-    Ok(format!("{n}"))
+    Ok(n.into())
 }
 
 fn validate_dig(record: &HashMap<&str, &str>, key: &str, range: RangeInclusive<i32>) -> bool {
@@ -88,9 +89,9 @@ fn valid(block: &&str) -> bool {
         .collect();
     RULES.iter().all(|validate| validate(&rec))
 }
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let n = input.split("\n\n").filter(valid).count();
-    Ok(format!("{n}"))
+    Ok(n.into())
 }
 
 #[cfg(test)]
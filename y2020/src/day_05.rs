@@ -1,6 +1,7 @@
 use anyhow::{anyhow, Context, Result};
 use fxhash::FxHashSet;
 use itertools::Itertools;
+use shared::AnswerValue;
 
 fn row_of(bp: &str) -> i32 {
     bp[..7].chars().fold(0, |s, n| s * 2 + i32::from(n == 'B'))
@@ -14,22 +15,22 @@ fn seat_id(bp: &str) -> i32 {
     row_of(bp) * 8 + col_of(bp)
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let m = input
         .lines()
         .map(seat_id)
         .max()
         .with_context(|| anyhow!("No boarding passes"));
-    m.map(|n| format!("{n}"))
+    m.map(AnswerValue::from)
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let seats: FxHashSet<_> = input.lines().map(seat_id).sorted().collect();
     let low = *seats
         .iter()
         .find(|&&n| seats.contains(&(n + 2)) && !seats.contains(&(n + 1)))
         .with_context(|| anyhow!("Missing seat not found"))?;
-    Ok(format!("{}", low + 1))
+    Ok((low + 1).into())
 }
 
 #[cfg(test)]
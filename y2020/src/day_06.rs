@@ -1,7 +1,8 @@
 use anyhow::Result;
 use fxhash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use shared::AnswerValue;
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let n: usize = input
         .split("\n\n")
         .map(|block| {
@@ -9,10 +10,10 @@ pub fn part_1(input: &str) -> Result<String> {
             hm.len()
         })
         .sum();
-    Ok(format!("{n}"))
+    Ok(n.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let n: usize = input
         .split("\n\n")
         .map(|block| {
@@ -24,5 +25,5 @@ pub fn part_2(input: &str) -> Result<String> {
             hm.iter().filter(|(_, &v)| v == len).count()
         })
         .sum();
-    Ok(format!("{n}"))
+    Ok(n.into())
 }
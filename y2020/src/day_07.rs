@@ -1,5 +1,6 @@
 use anyhow::{anyhow, Context, Result};
 use fxhash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use shared::AnswerValue;
 use std::collections::VecDeque;
 
 #[derive(Eq, PartialEq, Debug)]
@@ -82,8 +83,8 @@ fn solve_1(input: &str) -> Result<usize> {
     Ok(visited.len())
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    solve_1(input).map(|sol| format!("{sol}"))
+pub fn part_1(input: &str) -> Result<AnswerValue> {
+    solve_1(input).map(AnswerValue::from)
 }
 
 fn solve_2(input: &str) -> Result<usize> {
@@ -107,8 +108,8 @@ fn solve_2(input: &str) -> Result<usize> {
     Ok(total_cost)
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    solve_2(input).map(|n| format!("{n}"))
+pub fn part_2(input: &str) -> Result<AnswerValue> {
+    solve_2(input).map(AnswerValue::from)
 }
 
 #[cfg(test)]
@@ -1,8 +1,9 @@
 use anyhow::Result;
 use fxhash::FxHashSet as HashSet;
+use shared::AnswerValue;
 
 #[derive(Eq, PartialEq, Copy, Clone, Debug)]
-enum Instr {
+pub enum Instr {
     Nop(i32),
     Jmp(i32),
     Acc(i32),
@@ -10,9 +11,9 @@ enum Instr {
 
 type Program = Vec<Instr>;
 
-fn parse(input: &str) -> Program {
+pub fn parse(input: &str) -> Result<Program> {
     use Instr::*;
-    input
+    let program = input
         .lines()
         .filter(|line| !line.is_empty())
         .filter_map(|line| {
@@ -26,7 +27,8 @@ fn parse(input: &str) -> Program {
                 _ => None,
             }
         })
-        .collect()
+        .collect();
+    Ok(program)
 }
 
 #[derive(Eq, PartialEq, Copy, Clone, Debug)]
@@ -35,13 +37,12 @@ struct Registers {
     acc: i32,
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let prog = parse(input);
-    let reg = run_program(&prog);
-    Ok(format!("{}", reg.acc))
+pub fn part_1(prog: &[Instr]) -> Result<AnswerValue> {
+    let reg = run_program(prog);
+    Ok(reg.acc.into())
 }
 
-fn run_program(prog: &Program) -> Registers {
+fn run_program(prog: &[Instr]) -> Registers {
     use Instr::*;
     let mut seen = HashSet::default();
     let mut reg = Registers { instr: 0, acc: 0 };
@@ -65,9 +66,9 @@ fn run_program(prog: &Program) -> Registers {
     reg
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(prog: &[Instr]) -> Result<AnswerValue> {
     use Instr::*;
-    let mut prog = parse(input);
+    let mut prog = prog.to_vec();
     let mut res = run_program(&prog);
     let max_bad_instr = res.instr as usize;
 
@@ -97,7 +98,7 @@ pub fn part_2(input: &str) -> Result<String> {
         }
     }
 
-    Ok(format!("{}", res.acc))
+    Ok(res.acc.into())
 }
 
 #[cfg(test)]
@@ -107,7 +108,8 @@ mod tests {
     #[test]
     fn p1_example() {
         let r = part_1(
-            "nop +0
+            &parse(
+                "nop +0
 acc +1
 jmp +4
 acc +3
@@ -117,8 +119,10 @@ acc +1
 jmp -4
 acc +6
 ",
+            )
+            .unwrap(),
         )
         .unwrap();
-        assert_eq!(r.as_str(), "5");
+        assert_eq!(r, AnswerValue::Integer(5));
     }
 }
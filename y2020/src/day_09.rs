@@ -1,8 +1,9 @@
 use anyhow::{anyhow, Context, Result};
 use itertools::Itertools;
+use shared::AnswerValue;
 
-fn parse(input: &str) -> Vec<u64> {
-    input.lines().filter_map(|line| line.parse().ok()).collect()
+pub fn parse(input: &str) -> Result<Vec<u64>> {
+    Ok(input.lines().filter_map(|line| line.parse().ok()).collect())
 }
 
 fn xmas(input: &[u64], preamble: usize) -> Option<u64> {
@@ -20,11 +21,10 @@ fn xmas(input: &[u64], preamble: usize) -> Option<u64> {
     None
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let input = parse(input);
-    xmas(&input, 25)
+pub fn part_1(input: &[u64]) -> Result<AnswerValue> {
+    xmas(input, 25)
         .with_context(|| anyhow!("Unable to solve"))
-        .map(|n| format!("{n}"))
+        .map(AnswerValue::from)
 }
 
 fn sum_spans(input: &[u64], target: u64, nums: usize) -> Option<u64> {
@@ -46,11 +46,10 @@ fn find_weakness(input: &[u64], preamble: usize) -> Option<u64> {
         .next()
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let input = parse(input);
-    find_weakness(&input, 25)
+pub fn part_2(input: &[u64]) -> Result<AnswerValue> {
+    find_weakness(input, 25)
         .with_context(|| anyhow!("Unable to solve"))
-        .map(|n| format!("{n}"))
+        .map(AnswerValue::from)
 }
 
 #[cfg(test)]
@@ -81,7 +80,8 @@ mod tests {
 309
 576
 ",
-        );
+        )
+        .unwrap();
         assert_eq!(xmas(&input, 5), Some(127));
         assert_eq!(find_weakness(&input, 5), Some(62));
     }
@@ -1,6 +1,7 @@
 use anyhow::Result;
 use fxhash::FxHashMap as HashMap;
 use itertools::Itertools;
+use shared::AnswerValue;
 
 fn parse(input: &str) -> Vec<i32> {
     input
@@ -27,8 +28,8 @@ fn solve_1(input: &str) -> i32 {
     *diffs.get(&3).unwrap_or(&0) * diffs.get(&1).unwrap_or(&0)
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    Ok(format!("{}", solve_1(input)))
+pub fn part_1(input: &str) -> Result<AnswerValue> {
+    Ok(solve_1(input).into())
 }
 
 fn solve_2(input: &str) -> i64 {
@@ -50,8 +51,8 @@ fn solve_2(input: &str) -> i64 {
     connect_count[max_val as usize]
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    Ok(format!("{}", solve_2(input)))
+pub fn part_2(input: &str) -> Result<AnswerValue> {
+    Ok(solve_2(input).into())
 }
 
 #[cfg(test)]
@@ -1,4 +1,5 @@
 use anyhow::{anyhow, Result};
+use shared::AnswerValue;
 use std::fmt::{Debug, Formatter, Write};
 
 #[derive(Eq, PartialEq, Copy, Clone)]
@@ -112,8 +113,8 @@ fn solve(input: &str, make_next: fn(&WaitingArea) -> WaitingArea) -> Result<usiz
     }
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    solve(input, next_waiting_area).map(|n| format!("{n}"))
+pub fn part_1(input: &str) -> Result<AnswerValue> {
+    solve(input, next_waiting_area).map(AnswerValue::from)
 }
 
 fn scan(
@@ -167,8 +168,8 @@ fn next_waiting_area_2(current: &WaitingArea) -> WaitingArea {
     (*width, *height, next)
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    solve(input, next_waiting_area_2).map(|n| format!("{n}"))
+pub fn part_2(input: &str) -> Result<AnswerValue> {
+    solve(input, next_waiting_area_2).map(AnswerValue::from)
 }
 
 #[cfg(test)]
@@ -1,4 +1,5 @@
 use anyhow::{anyhow, Result};
+use shared::AnswerValue;
 
 type Facing = i32;
 const NORTH: Facing = 3;
@@ -67,8 +68,8 @@ fn solve_1(input: &str) -> Result<i32> {
     Ok(ship.manhattan())
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    solve_1(input).map(|n| format!("{n}"))
+pub fn part_1(input: &str) -> Result<AnswerValue> {
+    solve_1(input).map(AnswerValue::from)
 }
 
 #[derive(Debug, Eq, PartialEq)]
@@ -139,8 +140,8 @@ fn solve_2(input: &str) -> Result<i32> {
     Ok(ship.manhattan())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    solve_2(input).map(|n| format!("{n}"))
+pub fn part_2(input: &str) -> Result<AnswerValue> {
+    solve_2(input).map(AnswerValue::from)
 }
 
 #[cfg(test)]
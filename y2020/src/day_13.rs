@@ -1,5 +1,7 @@
 use anyhow::{anyhow, Context, Result};
 use itertools::Itertools;
+use shared::math::crt;
+use shared::AnswerValue;
 
 pub fn solve_1(input: &str) -> Result<i32> {
     let mut lines = input.lines();
@@ -20,8 +22,8 @@ pub fn solve_1(input: &str) -> Result<i32> {
         .map(|(id, wait)| id * wait)
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    solve_1(input).map(|n| format!("{n}"))
+pub fn part_1(input: &str) -> Result<AnswerValue> {
+    solve_1(input).map(AnswerValue::from)
 }
 
 fn solve_2(input: &str) -> Result<i64> {
@@ -34,27 +36,14 @@ fn solve_2(input: &str) -> Result<i64> {
         .filter_map(|(i, n)| n.parse().ok().map(|n| (i as i64, n)))
         .collect_vec();
 
-    let ans =
-        offset_busid
-            .into_iter()
-            .fold((1, 0), |(mut lcm, mut current_time), (idx, bus_id)| {
-                // For example iteration 1 where idx = 0 and bus_id = 13:
-                // lcm becomes 7 and we find time = 77 where 78 % 13 == 0
-                while (current_time + idx) % bus_id != 0 {
-                    current_time += lcm;
-                }
-                // Then set lcm = 7 * 13 which is 91, because the next solution to t % 7 == 0 and (t + 1) % 13 is that far away
-                // and we need the next t to solve that -- this should be linear in the number of buses
-                lcm *= bus_id;
-
-                (lcm, current_time)
-            });
-
-    Ok(ans.1)
+    // The bus with id n leaves idx minutes after t when t + idx = 0 (mod n)
+    crt(offset_busid.into_iter().map(|(idx, bus_id)| (-idx, bus_id)))
+        .map(|(time, _)| time)
+        .with_context(|| anyhow!("The buses never line up"))
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    solve_2(input).map(|n| format!("{n}"))
+pub fn part_2(input: &str) -> Result<AnswerValue> {
+    solve_2(input).map(AnswerValue::from)
 }
 #[cfg(test)]
 mod tests {
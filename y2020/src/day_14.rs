@@ -3,6 +3,7 @@ use fxhash::FxHashMap as HashMap;
 use itertools::Itertools;
 use once_cell::sync::OnceCell;
 use regex::Regex;
+use shared::AnswerValue;
 
 #[derive(Eq, PartialEq, Debug, Copy, Clone)]
 enum MaskBit {
@@ -108,8 +109,8 @@ fn solve_1(input: &str) -> Result<u64> {
     Ok(memory.into_iter().sum())
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    solve_1(input).map(|n| format!("{n}"))
+pub fn part_1(input: &str) -> Result<AnswerValue> {
+    solve_1(input).map(AnswerValue::from)
 }
 
 fn memory_decoder(memory: &mut HashMap<u64, u64>, mask: &Mask, mut addr: u64, val: u64) {
@@ -167,8 +168,8 @@ fn solve_2(input: &str) -> Result<u64> {
     Ok(memory.values().sum())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    solve_2(input).map(|n| format!("{n}"))
+pub fn part_2(input: &str) -> Result<AnswerValue> {
+    solve_2(input).map(AnswerValue::from)
 }
 
 #[cfg(test)]
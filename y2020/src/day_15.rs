@@ -1,4 +1,5 @@
 use anyhow::{anyhow, Context, Result};
+use shared::AnswerValue;
 
 fn solve(n: usize, initial: &[u32]) -> u32 {
     // Map from number to last time it was said -- 0 means new
@@ -29,7 +30,7 @@ fn solve(n: usize, initial: &[u32]) -> u32 {
     last
 }
 
-fn solve_str(input: &str, n: usize) -> Result<String> {
+fn solve_str(input: &str, n: usize) -> Result<AnswerValue> {
     let input: Result<Vec<_>> = input
         .lines()
         .next()
@@ -40,14 +41,14 @@ fn solve_str(input: &str, n: usize) -> Result<String> {
             Ok(n)
         })
         .collect();
-    input.map(|v| format!("{}", solve(n, &v)))
+    input.map(|v| solve(n, &v).into())
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     solve_str(input, 2020)
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     solve_str(input, 30_000_000)
 }
 
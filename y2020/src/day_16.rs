@@ -1,6 +1,7 @@
 use anyhow::{anyhow, Context, Result};
 use fxhash::FxHashMap as HashMap;
 use itertools::Itertools;
+use shared::AnswerValue;
 use std::ops::RangeInclusive;
 
 #[derive(Eq, PartialEq, Clone, Debug)]
@@ -111,8 +112,8 @@ fn solve_1(input: &str) -> Result<u64> {
     Ok(invalid_numbers)
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    solve_1(input).map(|n| format!("{n}"))
+pub fn part_1(input: &str) -> Result<AnswerValue> {
+    solve_1(input).map(AnswerValue::from)
 }
 
 fn remove_possibility(possibilites: &mut Vec<Vec<bool>>, column: usize, rule_column: usize) {
@@ -202,8 +203,8 @@ fn solve_2(input: &str) -> Result<u64> {
     }
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    solve_2(input).map(|n| format!("{n}"))
+pub fn part_2(input: &str) -> Result<AnswerValue> {
+    solve_2(input).map(AnswerValue::from)
 }
 
 #[cfg(test)]
@@ -1,12 +1,13 @@
 use anyhow::Result;
 use fxhash::FxHashSet as HashSet;
 use itertools::Itertools;
+use shared::AnswerValue;
 
 type Cube = [i64; 3];
 type HyperCube = [i64; 4];
 
 #[derive(Eq, PartialEq, Debug)]
-struct ActiveCubes {
+pub struct ActiveCubes {
     minbounds: Cube,
     maxbounds: Cube,
     cubes: HashSet<Cube>,
@@ -19,7 +20,7 @@ struct ActiveHyperCubes {
     hyper_cubes: HashSet<HyperCube>,
 }
 
-fn parse(input: &str) -> ActiveCubes {
+pub fn parse(input: &str) -> Result<ActiveCubes> {
     let cubes: HashSet<_> = input
         .lines()
         .filter(|line| !line.is_empty())
@@ -45,11 +46,11 @@ fn parse(input: &str) -> ActiveCubes {
     maxbounds[0] += 1;
     maxbounds[1] += 1;
 
-    ActiveCubes {
+    Ok(ActiveCubes {
         minbounds,
         maxbounds,
         cubes,
-    }
+    })
 }
 
 fn add(lhs: &Cube, rhs: &Cube) -> Cube {
@@ -154,10 +155,9 @@ fn stepsh(cubes: &ActiveHyperCubes, steps: usize) -> usize {
     active.len()
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let initial = parse(input);
-    let result = steps(&initial, 6);
-    Ok(format!("{result}"))
+pub fn part_1(initial: &ActiveCubes) -> Result<AnswerValue> {
+    let result = steps(initial, 6);
+    Ok(result.into())
 }
 
 fn add_extra_dim(cubes: &ActiveCubes) -> ActiveHyperCubes {
@@ -185,11 +185,10 @@ fn add_extra_dim(cubes: &ActiveCubes) -> ActiveHyperCubes {
     }
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let initial = parse(input);
-    let initial = add_extra_dim(&initial);
+pub fn part_2(initial: &ActiveCubes) -> Result<AnswerValue> {
+    let initial = add_extra_dim(initial);
     let result = stepsh(&initial, 6);
-    Ok(format!("{result}"))
+    Ok(result.into())
 }
 
 #[cfg(test)]
@@ -201,7 +200,8 @@ mod tests {
             ".#.
 ..#
 ###",
-        );
+        )
+        .unwrap();
         let living = steps(&initial, 6);
         assert_eq!(living, 112);
     }
@@ -212,7 +212,8 @@ mod tests {
             ".#.
 ..#
 ###",
-        );
+        )
+        .unwrap();
         let initial = add_extra_dim(&initial);
         let living = stepsh(&initial, 6);
         assert_eq!(living, 848);
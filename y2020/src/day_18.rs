@@ -5,6 +5,7 @@ use nom::combinator::{map, map_res};
 use nom::multi::fold_many0;
 use nom::sequence::{pair, preceded, terminated};
 use nom::{Finish, IResult};
+use shared::AnswerValue;
 
 // int = -digit | digi
 // digit = [0-9] | digit[0-9]
@@ -65,13 +66,13 @@ fn calc_1(line: &str) -> Result<i64, anyhow::Error> {
         })
 }
 
-pub fn part_1(input: &str) -> Result<String, anyhow::Error> {
+pub fn part_1(input: &str) -> Result<AnswerValue, anyhow::Error> {
     let mut sum = 0;
     for line in input.lines() {
         let add = calc_1(line)?;
         sum += add;
     }
-    Ok(format!("{sum}"))
+    Ok(sum.into())
 }
 
 fn calc_2(line: &str) -> Result<i64, anyhow::Error> {
@@ -126,13 +127,13 @@ fn calc_2(line: &str) -> Result<i64, anyhow::Error> {
         .map(|(_, res)| res)
 }
 
-pub fn part_2(input: &str) -> anyhow::Result<String> {
+pub fn part_2(input: &str) -> anyhow::Result<AnswerValue> {
     let mut sum = 0;
     for line in input.lines() {
         let add = calc_2(line)?;
         sum += add;
     }
-    Ok(format!("{sum}"))
+    Ok(sum.into())
 }
 
 #[cfg(test)]
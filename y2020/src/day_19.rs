@@ -9,10 +9,11 @@ use nom::sequence::delimited;
 use nom::sequence::preceded;
 use nom::sequence::terminated;
 use nom::{Finish, IResult};
+use shared::{AnswerValue, Input};
 use std::str::FromStr;
 
 #[derive(Eq, PartialEq, Debug, Clone)]
-enum Rule {
+pub enum Rule {
     Lit(char),
     Seq(Vec<usize>),
     Choice(Box<Rule>, Box<Rule>),
@@ -54,6 +55,26 @@ fn parse_rules(i: &str) -> IResult<&str, Vec<(usize, Rule)>> {
     )(i)
 }
 
+pub struct Messages<'a> {
+    rules: HashMap<usize, Rule>,
+    messages: &'a str,
+}
+
+impl Input for Messages<'static> {
+    type Of<'a> = Messages<'a>;
+    type View<'a> = Messages<'a>;
+}
+
+pub fn parse(input: &str) -> Result<Messages<'_>, anyhow::Error> {
+    let (messages, rules) = parse_rules(input)
+        .finish()
+        .map_err(|e| anyhow!("Unable to parse due to {e:?}"))?;
+    Ok(Messages {
+        rules: rules.into_iter().collect(),
+        messages,
+    })
+}
+
 #[derive(Eq, PartialEq, Clone, Debug)]
 enum Validation<'a> {
     Continue(&'a [char]),
@@ -137,24 +158,18 @@ fn validate_rule_no<'a>(
         .unwrap_or(Validation::Invalid)
 }
 
-pub fn part_1(input: &str) -> Result<String, anyhow::Error> {
-    let (rest, rules) = parse_rules(input)
-        .finish()
-        .map_err(|e| anyhow!("Unable to parse due to {e:?}"))?;
-    let rules: HashMap<usize, Rule> = rules.into_iter().collect();
-    let n = rest
+pub fn part_1(messages: &Messages) -> Result<AnswerValue, anyhow::Error> {
+    let n = messages
+        .messages
         .lines()
         .filter(|line| !line.is_empty())
-        .filter(|line| is_valid(line, &rules))
+        .filter(|line| is_valid(line, &messages.rules))
         .count();
-    Ok(format!("{n}"))
+    Ok(n.into())
 }
 
-pub fn part_2(input: &str) -> Result<String, anyhow::Error> {
-    let (rest, rules) = parse_rules(input)
-        .finish()
-        .map_err(|e| anyhow!("Unable to parse due to {e:?}"))?;
-    let mut rules: HashMap<usize, Rule> = rules.into_iter().collect();
+pub fn part_2(messages: &Messages) -> Result<AnswerValue, anyhow::Error> {
+    let mut rules = messages.rules.clone();
     let (_, new_rules) = parse_rules(
         "8: 42 | 42 8
 11: 42 31 | 42 11 31
@@ -166,13 +181,14 @@ pub fn part_2(input: &str) -> Result<String, anyhow::Error> {
     for (id, rule) in new_rules.into_iter() {
         rules.insert(id, rule);
     }
-    let n = rest
+    let n = messages
+        .messages
         .lines()
         .filter(|line| !line.is_empty())
         .filter(|line| is_valid(line, &rules))
         .count();
 
-    Ok(format!("{n}"))
+    Ok(n.into())
 }
 
 #[cfg(test)]
@@ -1,6 +1,7 @@
 use anyhow::{anyhow, Context, Result};
 use fxhash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use itertools::Itertools;
+use shared::AnswerValue;
 
 #[derive(Eq, PartialEq, Debug, Clone)]
 struct Tile {
@@ -180,10 +181,10 @@ fn corners(tiles: &[Tile]) -> [usize; 4] {
     take_corners(tiles, &edge_map)
 }
 
-pub fn part_1(_input: &str) -> Result<String> {
+pub fn part_1(_input: &str) -> Result<AnswerValue> {
     let tiles = parse(_input);
     let n: usize = corners(&tiles).into_iter().product();
-    Ok(format!("{n}"))
+    Ok(n.into())
 }
 
 fn topleft(tiles: &[Tile], edge_map: &HashMap<u16, HashSet<usize>>) -> Option<Tile> {
@@ -369,8 +370,8 @@ fn solve_2(input: &str) -> Result<usize> {
     Ok(bits_set - seamonster_bits)
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    solve_2(input).map(|n| format!("{n}"))
+pub fn part_2(input: &str) -> Result<AnswerValue> {
+    solve_2(input).map(AnswerValue::from)
 }
 
 #[cfg(test)]
@@ -7,6 +7,7 @@ use nom::character::complete::{alpha1, space0};
 use nom::multi::{many0, many1};
 use nom::sequence::{delimited, pair, preceded, terminated};
 use nom::{Finish, IResult};
+use shared::AnswerValue;
 
 fn parse_food(i: &str) -> IResult<&str, &str> {
     delimited(space0, alpha1, space0)(i)
@@ -135,18 +136,18 @@ fn solve_1(input: &str) -> Result<usize> {
     Ok(n)
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    solve_1(input).map(|n| format!("{n}"))
+pub fn part_1(input: &str) -> Result<AnswerValue> {
+    solve_1(input).map(AnswerValue::from)
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let foods = parse(input)?;
     let allergen_sources = solve_constraints(&foods);
     let mut allergen_sources = allergen_sources
         .iter()
         .sorted_by_key(|(k, _v)| **k)
         .flat_map(|(_k, v)| v.iter().copied());
-    Ok(allergen_sources.join(","))
+    Ok(allergen_sources.join(",").into())
 }
 
 #[cfg(test)]
@@ -163,7 +164,7 @@ sqjhc mxmxvkd sbzzf (contains fish)";
         assert_eq!(n, 5);
         assert_eq!(
             part_2(example).unwrap(),
-            String::from("mxmxvkd,sqjhc,fvjkl")
+            AnswerValue::from("mxmxvkd,sqjhc,fvjkl")
         );
     }
 
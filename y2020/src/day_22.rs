@@ -8,6 +8,7 @@ use nom::combinator::map_res;
 use nom::multi::{many0, many1};
 use nom::sequence::{delimited, pair, terminated};
 use nom::{Finish, IResult};
+use shared::AnswerValue;
 use std::collections::VecDeque;
 use std::str::FromStr;
 
@@ -29,12 +30,21 @@ fn parse_player(i: &str) -> IResult<&str, Player> {
 }
 
 #[derive(Eq, PartialEq, Debug, Clone)]
-struct Player(u8, VecDeque<u8>);
+pub struct Player(u8, VecDeque<u8>);
 
-fn parse_players(i: &str) -> IResult<&str, (Player, Player)> {
+pub type Players = (Player, Player);
+
+fn parse_players(i: &str) -> IResult<&str, Players> {
     pair(parse_player, parse_player)(i)
 }
 
+pub fn parse(input: &str) -> Result<Players, anyhow::Error> {
+    let (_, players) = parse_players(input)
+        .finish()
+        .map_err(|nomerr| anyhow!("Unable to parse due to {nomerr:?}"))?;
+    Ok(players)
+}
+
 fn play<'a>(p1: &'a mut Player, p2: &'a mut Player) -> &'a Player {
     if p1.1.is_empty() {
         p2
@@ -70,13 +80,11 @@ fn score(player: &Player) -> usize {
         .fold(0usize, |acc, (idx, card)| acc + (idx + 1) * (card as usize))
 }
 
-pub fn part_1(input: &str) -> Result<String, anyhow::Error> {
-    let (_, (mut p1, mut p2)) = parse_players(input)
-        .finish()
-        .map_err(|nomerr| anyhow!("Unable to parse due to {nomerr:?}"))?;
+pub fn part_1(players: &Players) -> Result<AnswerValue, anyhow::Error> {
+    let (mut p1, mut p2) = players.clone();
     let winner = play(&mut p1, &mut p2);
     let score = score(winner);
-    Ok(format!("{score}"))
+    Ok(score.into())
 }
 
 type Fingerprint = (Vec<u8>, Vec<u8>);
@@ -120,13 +128,11 @@ fn recursive_game(p1: &mut Player, p2: &mut Player) -> Player {
     }
 }
 
-pub fn part_2(input: &str) -> Result<String, anyhow::Error> {
-    let (_, (mut p1, mut p2)) = parse_players(input)
-        .finish()
-        .map_err(|nomerr| anyhow!("Unable to parse due to {nomerr:?}"))?;
+pub fn part_2(players: &Players) -> Result<AnswerValue, anyhow::Error> {
+    let (mut p1, mut p2) = players.clone();
     let winner = recursive_game(&mut p1, &mut p2);
     let score = score(&winner);
-    Ok(format!("{score}"))
+    Ok(score.into())
 }
 
 #[cfg(test)]
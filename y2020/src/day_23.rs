@@ -1,4 +1,5 @@
 use anyhow::{anyhow, Context, Result};
+use shared::AnswerValue;
 
 fn parse_input(input: &str) -> Option<Vec<usize>> {
     input.lines().next().map(|line| {
@@ -10,6 +11,10 @@ fn parse_input(input: &str) -> Option<Vec<usize>> {
     })
 }
 
+pub fn parse(input: &str) -> Result<Vec<usize>> {
+    parse_input(input).with_context(|| anyhow!("Invalid input: {input}"))
+}
+
 // Given the cups; we need to efficiently be able to:
 // - Inspect the current cup
 // - Take the 3 cups on the RHS of it (we're in the middle of the cups; they're a circle) out of the circle
@@ -49,7 +54,7 @@ fn perform_move(cups: &mut Cups, active_cup: usize, max_cup: usize) -> usize {
     cups[active_cup]
 }
 
-fn initialize_cups(labels: &Cups, max_cup: usize) -> Cups {
+fn initialize_cups(labels: &[usize], max_cup: usize) -> Cups {
     let mut cups: Cups = (0..=max_cup).collect();
     cups[max_cup] = 1; // Make it loop
     for (prev, next) in labels.iter().copied().zip(labels.iter().skip(1).copied()) {
@@ -68,14 +73,13 @@ fn initialize_cups(labels: &Cups, max_cup: usize) -> Cups {
     cups
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let initial = parse_input(input).with_context(|| anyhow!("Invalid input: {input}"))?;
+pub fn part_1(initial: &[usize]) -> Result<AnswerValue> {
     let max_cup = initial
         .iter()
         .max()
         .copied()
         .context(anyhow!("Needed at least 1 cup"))?;
-    let mut cups = initialize_cups(&initial, max_cup);
+    let mut cups = initialize_cups(initial, max_cup);
     let mut current_cup = initial[0];
     for _ in 0..100 {
         current_cup = perform_move(&mut cups, current_cup, max_cup);
@@ -86,20 +90,19 @@ pub fn part_1(input: &str) -> Result<String> {
         out.push(((collect as u8) + b'0') as char);
         collect = cups[collect];
     }
-    Ok(out)
+    Ok(out.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let initial = parse_input(input).with_context(|| anyhow!("Invalid input: {input}"))?;
+pub fn part_2(initial: &[usize]) -> Result<AnswerValue> {
     let max_cup = 1_000_000;
-    let mut cups = initialize_cups(&initial, max_cup);
+    let mut cups = initialize_cups(initial, max_cup);
     let mut current_up = initial[0];
     for _ in 0..10_000_000 {
         current_up = perform_move(&mut cups, current_up, max_cup);
     }
     let next = cups[1];
     let n = next * cups[next];
-    Ok(format!("{n}"))
+    Ok(n.into())
 }
 
 #[cfg(test)]
@@ -109,16 +112,16 @@ mod tests {
 
     #[test]
     fn test_move_by_example() {
-        let mut cups = initialize_cups(&vec![3, 8, 9, 1, 2, 5, 4, 6, 7], 9);
+        let mut cups = initialize_cups(&[3, 8, 9, 1, 2, 5, 4, 6, 7], 9);
         let next_current = perform_move(&mut cups, 3, 9);
         assert_eq!(next_current, 2);
     }
     #[test]
     fn test_example() {
-        let result = part_1("389125467");
-        assert_eq!(result.unwrap(), "67384529".to_string());
-        let result = part_2("389125467");
-        assert_eq!(result.unwrap(), "149245887792".to_string());
+        let result = part_1(&parse("389125467").unwrap());
+        assert_eq!(result.unwrap(), AnswerValue::from("67384529"));
+        let result = part_2(&parse("389125467").unwrap());
+        assert_eq!(result.unwrap(), AnswerValue::Integer(149245887792));
     }
 
     #[test]
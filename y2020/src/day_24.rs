@@ -1,6 +1,7 @@
 use anyhow::Result;
 use fxhash::FxHashMap as HashMap;
 use fxhash::FxHashSet as HashSet;
+use shared::AnswerValue;
 
 // https://en.wikipedia.org/wiki/Hexagonal_Efficient_Coordinate_System#/media/File:HECS_Nearest_Neighbors.png
 #[derive(Hash, Eq, PartialEq, Copy, Clone, Debug)]
@@ -97,12 +98,12 @@ fn lay_floor(input: &str) -> HashSet<HexCoord> {
     flipped_tiles
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let n = lay_floor(input).len();
-    Ok(format!("{n}"))
+    Ok(n.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let mut black_tiles = lay_floor(input);
     for _ in 0..100 {
         let mut neighbour_black_tiles: HashMap<HexCoord, u8> = HashMap::default();
@@ -122,5 +123,5 @@ pub fn part_2(input: &str) -> Result<String> {
             .collect();
     }
     let n = black_tiles.len();
-    Ok(format!("{n}"))
+    Ok(n.into())
 }
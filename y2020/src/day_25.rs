@@ -1,43 +1,31 @@
 use anyhow::{Context, Result};
+use shared::math::{discrete_log, mod_pow};
+use shared::AnswerValue;
 
 const SUBJECT_NUMBER: u64 = 7;
 
-fn do_loop(value: u64, subject_number: u64) -> u64 {
-    value * subject_number % 20201227
-}
+const MODULUS: i64 = 20201227;
 
-fn loop_size_of(public_key: u64) -> u64 {
-    let mut value = 1;
-    for loop_size in 1.. {
-        value = do_loop(value, SUBJECT_NUMBER);
-        if value == public_key {
-            return loop_size;
-        }
-    }
-    unreachable!()
+fn loop_size_of(public_key: u64) -> Option<u64> {
+    discrete_log(SUBJECT_NUMBER as i64, public_key as i64, MODULUS).map(|n| n as u64)
 }
 
 fn derive_encryption_key(public_key: u64, loop_size: u64) -> u64 {
-    let mut value = 1;
-    for _ in 0..loop_size {
-        value = do_loop(value, public_key)
-    }
-    value
+    mod_pow(public_key as i64, loop_size, MODULUS) as u64
 }
 
-fn solve(card_public_key: u64, door_public_key: u64) -> u64 {
-    #[cfg(debug_assertions)]
-    let card_loop_size = loop_size_of(card_public_key);
-    let door_loop_size = loop_size_of(door_public_key);
+fn solve(card_public_key: u64, door_public_key: u64) -> Result<u64> {
+    let door_loop_size = loop_size_of(door_public_key).context("No loop size for the door")?;
     let key = derive_encryption_key(card_public_key, door_loop_size);
     #[cfg(debug_assertions)]
-    let alt_key = derive_encryption_key(door_public_key, card_loop_size);
-    #[cfg(debug_assertions)]
-    assert_eq!(key, alt_key);
-    key
+    {
+        let card_loop_size = loop_size_of(card_public_key).context("No loop size for the card")?;
+        assert_eq!(key, derive_encryption_key(door_public_key, card_loop_size));
+    }
+    Ok(key)
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let mut lines = input.lines();
     let card_pkey = lines
         .next()
@@ -47,12 +35,12 @@ pub fn part_1(input: &str) -> Result<String> {
         .next()
         .context("Missing line in input")
         .and_then(|line| Ok(line.parse()?))?;
-    let key = solve(card_pkey, door_pkey);
-    Ok(format!("{key}"))
+    let key = solve(card_pkey, door_pkey)?;
+    Ok(key.into())
 }
 
-pub fn part_2(_input: &str) -> Result<String> {
-    Ok("Enter the solutions, collect stars".to_string())
+pub fn part_2(_input: &str) -> Result<AnswerValue> {
+    Ok("Enter the solutions, collect stars".into())
 }
 
 #[cfg(test)]
@@ -61,8 +49,8 @@ mod tests {
 
     #[test]
     fn test_loop_size() {
-        assert_eq!(loop_size_of(5764801), 8);
-        assert_eq!(loop_size_of(17807724), 11);
+        assert_eq!(loop_size_of(5764801), Some(8));
+        assert_eq!(loop_size_of(17807724), Some(11));
     }
 
     #[test]
@@ -73,6 +61,6 @@ mod tests {
 
     #[test]
     fn test_solve() {
-        assert_eq!(solve(17807724, 5764801), 14897079);
+        assert_eq!(solve(17807724, 5764801).unwrap(), 14897079);
     }
 }
@@ -1,5 +1,4 @@
-use shared::Answer;
-use shared::Answer::SolvedBoth;
+use shared::{Day, List, Owned, Parsed, Solution};
 
 pub mod day_01;
 pub mod day_02;
@@ -27,105 +26,51 @@ pub mod day_23;
 pub mod day_24;
 pub mod day_25;
 
-pub const SOLUTIONS: [Answer; 25] = [
-    SolvedBoth {
-        part_1: day_01::part_1,
-        part_2: day_01::part_2,
-    },
-    SolvedBoth {
-        part_1: day_02::part_1,
-        part_2: day_02::part_2,
-    },
-    SolvedBoth {
-        part_1: day_03::part_1,
-        part_2: day_03::part_2,
-    },
-    SolvedBoth {
-        part_1: day_04::part_1,
-        part_2: day_04::part_2,
-    },
-    SolvedBoth {
-        part_1: day_05::part_1,
-        part_2: day_05::part_2,
-    },
-    SolvedBoth {
-        part_1: day_06::part_1,
-        part_2: day_06::part_2,
-    },
-    SolvedBoth {
-        part_1: day_07::part_1,
-        part_2: day_07::part_2,
-    },
-    SolvedBoth {
-        part_1: day_08::part_1,
-        part_2: day_08::part_2,
-    },
-    SolvedBoth {
-        part_1: day_09::part_1,
-        part_2: day_09::part_2,
-    },
-    SolvedBoth {
-        part_1: day_10::part_1,
-        part_2: day_10::part_2,
-    },
-    SolvedBoth {
-        part_1: day_11::part_1,
-        part_2: day_11::part_2,
-    },
-    SolvedBoth {
-        part_1: day_12::part_1,
-        part_2: day_12::part_2,
-    },
-    SolvedBoth {
-        part_1: day_13::part_1,
-        part_2: day_13::part_2,
-    },
-    SolvedBoth {
-        part_1: day_14::part_1,
-        part_2: day_14::part_2,
-    },
-    SolvedBoth {
-        part_1: day_15::part_1,
-        part_2: day_15::part_2,
-    },
-    SolvedBoth {
-        part_1: day_16::part_1,
-        part_2: day_16::part_2,
-    },
-    SolvedBoth {
-        part_1: day_17::part_1,
-        part_2: day_17::part_2,
-    },
-    SolvedBoth {
-        part_1: day_18::part_1,
-        part_2: day_18::part_2,
-    },
-    SolvedBoth {
-        part_1: day_19::part_1,
-        part_2: day_19::part_2,
-    },
-    SolvedBoth {
-        part_1: day_20::part_1,
-        part_2: day_20::part_2,
-    },
-    SolvedBoth {
-        part_1: day_21::part_1,
-        part_2: day_21::part_2,
-    },
-    SolvedBoth {
-        part_1: day_22::part_1,
-        part_2: day_22::part_2,
-    },
-    SolvedBoth {
-        part_1: day_23::part_1,
-        part_2: day_23::part_2,
-    },
-    SolvedBoth {
-        part_1: day_24::part_1,
-        part_2: day_24::part_2,
-    },
-    SolvedBoth {
-        part_1: day_25::part_1,
-        part_2: day_25::part_2,
-    },
+const YEAR: u16 = 2020;
+
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &Day::both(YEAR, 1, day_01::part_1, day_01::part_2).titled("Report Repair"),
+    &Day::both(YEAR, 2, day_02::part_1, day_02::part_2).titled("Password Philosophy"),
+    &Parsed::<Owned<day_03::Area>>::both(YEAR, 3, day_03::parse, day_03::part_1, day_03::part_2)
+        .titled("Toboggan Trajectory"),
+    &Day::both(YEAR, 4, day_04::part_1, day_04::part_2).titled("Passport Processing"),
+    &Day::both(YEAR, 5, day_05::part_1, day_05::part_2).titled("Binary Boarding"),
+    &Day::both(YEAR, 6, day_06::part_1, day_06::part_2).titled("Custom Customs"),
+    &Day::both(YEAR, 7, day_07::part_1, day_07::part_2).titled("Handy Haversacks"),
+    &Parsed::<List<day_08::Instr>>::both(YEAR, 8, day_08::parse, day_08::part_1, day_08::part_2)
+        .titled("Handheld Halting"),
+    &Parsed::<List<u64>>::both(YEAR, 9, day_09::parse, day_09::part_1, day_09::part_2)
+        .titled("Encoding Error"),
+    &Day::both(YEAR, 10, day_10::part_1, day_10::part_2).titled("Adapter Array"),
+    &Day::both(YEAR, 11, day_11::part_1, day_11::part_2).titled("Seating System"),
+    &Day::both(YEAR, 12, day_12::part_1, day_12::part_2).titled("Rain Risk"),
+    &Day::both(YEAR, 13, day_13::part_1, day_13::part_2).titled("Shuttle Search"),
+    &Day::both(YEAR, 14, day_14::part_1, day_14::part_2).titled("Docking Data"),
+    &Day::both(YEAR, 15, day_15::part_1, day_15::part_2).titled("Rambunctious Recitation"),
+    &Day::both(YEAR, 16, day_16::part_1, day_16::part_2).titled("Ticket Translation"),
+    &Parsed::<Owned<day_17::ActiveCubes>>::both(
+        YEAR,
+        17,
+        day_17::parse,
+        day_17::part_1,
+        day_17::part_2,
+    )
+    .titled("Conway Cubes"),
+    &Day::both(YEAR, 18, day_18::part_1, day_18::part_2).titled("Operation Order"),
+    &Parsed::<day_19::Messages>::both(YEAR, 19, day_19::parse, day_19::part_1, day_19::part_2)
+        .titled("Monster Messages"),
+    &Day::both(YEAR, 20, day_20::part_1, day_20::part_2).titled("Jurassic Jigsaw"),
+    &Day::both(YEAR, 21, day_21::part_1, day_21::part_2).titled("Allergen Assessment"),
+    &Parsed::<Owned<day_22::Players>>::both(
+        YEAR,
+        22,
+        day_22::parse,
+        day_22::part_1,
+        day_22::part_2,
+    )
+    .titled("Crab Combat"),
+    &Parsed::<List<usize>>::both(YEAR, 23, day_23::parse, day_23::part_1, day_23::part_2)
+        .titled("Crab Cups"),
+    &Day::both(YEAR, 24, day_24::part_1, day_24::part_2).titled("Lobby Layout"),
+    &Day::both(YEAR, 25, day_25::part_1, day_25::part_2).titled("Combo Breaker"),
 ];
@@ -1,8 +1,9 @@
 use anyhow::Result;
 use itertools::Itertools;
+use shared::AnswerValue;
 use std::num::ParseIntError;
 
-fn parse(input: &str) -> Result<Vec<i32>> {
+pub fn parse(input: &str) -> Result<Vec<i32>> {
     let r: Result<Vec<_>, ParseIntError> = input
         .lines()
         .filter(|line| !line.is_empty())
@@ -34,16 +35,14 @@ fn solve_2(depths: &[i32]) -> usize {
         .count()
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let depths = parse(input)?;
-    let sol = solve_1(&depths);
-    Ok(format!("{sol}"))
+pub fn part_1(depths: &[i32]) -> Result<AnswerValue> {
+    let sol = solve_1(depths);
+    Ok(sol.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let depths = parse(input)?;
-    let sol = solve_2(&depths);
-    Ok(format!("{sol}"))
+pub fn part_2(depths: &[i32]) -> Result<AnswerValue> {
+    let sol = solve_2(depths);
+    Ok(sol.into())
 }
 
 #[cfg(test)]
@@ -1,4 +1,5 @@
 use anyhow::{anyhow, Context, Result};
+use shared::AnswerValue;
 
 #[cfg(test)]
 pub mod tests {
@@ -34,7 +35,7 @@ forward 2
 }
 
 #[derive(Eq, PartialEq, Debug)]
-enum Move {
+pub enum Move {
     Down(i64),
     Up(i64),
     Forward(i64),
@@ -52,7 +53,7 @@ fn parse_line(line: &str) -> Result<Move> {
     }
 }
 
-fn parse(inp: &str) -> Result<Vec<Move>> {
+pub fn parse(inp: &str) -> Result<Vec<Move>> {
     inp.lines()
         .filter(|line| !line.is_empty())
         .map(parse_line)
@@ -81,14 +82,12 @@ fn solve_2(moves: &[Move]) -> i64 {
     depth * hor_pos
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let moves = parse(input)?;
-    let sol = solve_1(&moves);
-    Ok(format!("{sol}"))
+pub fn part_1(moves: &[Move]) -> Result<AnswerValue> {
+    let sol = solve_1(moves);
+    Ok(sol.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let moves = parse(input)?;
-    let sol = solve_2(&moves);
-    Ok(format!("{sol}"))
+pub fn part_2(moves: &[Move]) -> Result<AnswerValue> {
+    let sol = solve_2(moves);
+    Ok(sol.into())
 }
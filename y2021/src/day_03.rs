@@ -1,5 +1,6 @@
 use anyhow::{anyhow, Result};
 use itertools::Itertools;
+use shared::AnswerValue;
 use std::cmp::Ordering;
 
 fn bitcounts(input: &str) -> Result<(Vec<i32>, Vec<i32>)> {
@@ -39,9 +40,9 @@ fn solve_1(input: &str) -> Result<i64> {
     Ok(gamma * epsilon)
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let r = solve_1(input)?;
-    Ok(format!("{r}"))
+    Ok(r.into())
 }
 
 fn bin2dec(inp: &Vec<char>) -> i64 {
@@ -93,9 +94,9 @@ fn solve_2(input: &str) -> Result<i64> {
     Ok(o2_rating * co2_rating)
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let r = solve_2(input)?;
-    Ok(format!("{r}"))
+    Ok(r.into())
 }
 
 #[cfg(test)]
@@ -1,5 +1,6 @@
 use anyhow::{Context, Result};
 use itertools::Itertools;
+use shared::AnswerValue;
 use std::num::ParseIntError;
 
 fn parse_comma_sep(line: &str) -> Result<Vec<u16>> {
@@ -16,7 +17,7 @@ fn parse_row(line: &&str) -> Result<Vec<Cell>> {
 }
 
 #[derive(Eq, PartialEq, Debug, Copy, Clone)]
-enum Cell {
+pub enum Cell {
     Marked(u16),
     Unmarked(u16),
 }
@@ -44,8 +45,10 @@ impl Cell {
 }
 
 type Board = Vec<Vec<Cell>>;
+/// The sequence of drawn numbers and the boards
+pub type Bingo = (Vec<u16>, Vec<Board>);
 
-fn parse(input: &str) -> Result<(Vec<u16>, Vec<Board>)> {
+pub fn parse(input: &str) -> Result<Bingo> {
     let mut lines = input.lines();
     let mut boards = vec![];
     let seq = lines
@@ -133,16 +136,14 @@ fn solve_2(boards: &Vec<Board>, seq: &Vec<u16>) -> u32 {
     *scores.last().unwrap_or(&0)
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let (seq, boards) = parse(input)?;
-    let sol = solve_1(&boards, &seq);
-    Ok(format!("{sol}"))
+pub fn part_1((seq, boards): &Bingo) -> Result<AnswerValue> {
+    let sol = solve_1(boards, seq);
+    Ok(sol.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let (seq, boards) = parse(input)?;
-    let sol = solve_2(&boards, &seq);
-    Ok(format!("{sol}"))
+pub fn part_2((seq, boards): &Bingo) -> Result<AnswerValue> {
+    let sol = solve_2(boards, seq);
+    Ok(sol.into())
 }
 
 #[cfg(test)]
@@ -1,12 +1,13 @@
 use anyhow::{Context, Result};
 use fxhash::FxHashSet as HashSet;
 use itertools::Itertools;
+use shared::AnswerValue;
 use std::cmp::{max, min};
 use std::ops::RangeInclusive;
 use std::str::FromStr;
 
 #[derive(Eq, PartialEq, Debug, Copy, Clone)]
-struct Line {
+pub struct Line {
     source: (i32, i32),
     dest: (i32, i32),
 }
@@ -272,7 +273,7 @@ impl Points {
     }
 }
 
-fn parse_lines(input: &str) -> Result<Vec<Line>> {
+pub fn parse_lines(input: &str) -> Result<Vec<Line>> {
     input
         .lines()
         .filter(|l| !l.is_empty())
@@ -306,20 +307,19 @@ fn solve(lines: &[Line]) -> usize {
     ps.len()
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let lines = parse_lines(input)?;
+pub fn part_1(lines: &[Line]) -> Result<AnswerValue> {
     let lines = lines
-        .into_iter()
+        .iter()
         .filter(|line| line.straight())
+        .copied()
         .collect_vec();
     let sol = solve(&lines);
-    Ok(format!("{sol}"))
+    Ok(sol.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let lines = parse_lines(input)?;
-    let sol = solve(&lines);
-    Ok(format!("{sol}"))
+pub fn part_2(lines: &[Line]) -> Result<AnswerValue> {
+    let sol = solve(lines);
+    Ok(sol.into())
 }
 
 #[cfg(test)]
@@ -1,8 +1,9 @@
 use anyhow::Result;
+use shared::AnswerValue;
 use std::num::ParseIntError;
 
-fn parse(input: &str) -> Result<Vec<u8>> {
-    let r: Result<_, ParseIntError> = input.split(',').map(str::parse::<u8>).collect();
+pub fn parse(input: &str) -> Result<Vec<u8>> {
+    let r: Result<_, ParseIntError> = input.trim().split(',').map(str::parse::<u8>).collect();
     Ok(r?)
 }
 
@@ -26,16 +27,14 @@ fn lantern_fishes(initial: &[u8], steps: usize) -> u64 {
     fishes_in_state.iter().sum()
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let fishes = parse(input.trim())?;
-    let sol = lantern_fishes(&fishes, 80);
-    Ok(format!("{sol}"))
+pub fn part_1(fishes: &[u8]) -> Result<AnswerValue> {
+    let sol = lantern_fishes(fishes, 80);
+    Ok(sol.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let fishes = parse(input.trim())?;
-    let sol = lantern_fishes(&fishes, 256);
-    Ok(format!("{sol}"))
+pub fn part_2(fishes: &[u8]) -> Result<AnswerValue> {
+    let sol = lantern_fishes(fishes, 256);
+    Ok(sol.into())
 }
 
 #[cfg(test)]
@@ -1,4 +1,5 @@
 use anyhow::Result;
+use shared::AnswerValue;
 use std::num::ParseIntError;
 
 fn horizontal_distance(pos: i64, crab: i64) -> i64 {
@@ -25,12 +26,12 @@ fn solve(input: &str, cost_fn: fn(i64, i64) -> i64) -> Result<i64> {
     Ok((min..=max).map(cost).min().unwrap_or(0))
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let fuel = solve(input, horizontal_distance)?;
-    Ok(format!("{fuel}"))
+    Ok(fuel.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let fuel = solve(input, fuel_cost)?;
-    Ok(format!("{fuel}"))
+    Ok(fuel.into())
 }
@@ -1,28 +1,34 @@
 use anyhow::{Context, Result};
 use fxhash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use shared::{AnswerValue, Input};
 
-pub fn part_1(input: &str) -> Result<String> {
-    let examples = parse(input)?;
-    let sol: Vec<u8> = examples.iter().flat_map(solve).collect();
+pub fn part_1(notes: &Notes) -> Result<AnswerValue> {
+    let sol: Vec<u8> = notes.0.iter().flat_map(solve).collect();
     let n = sol.iter().filter(|n| [1, 4, 7, 8].contains(n)).count();
-    Ok(format!("{n}"))
+    Ok(n.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let examples = parse(input)?;
-    let sol: Vec<Vec<u8>> = examples.iter().map(solve).collect();
+pub fn part_2(notes: &Notes) -> Result<AnswerValue> {
+    let sol: Vec<Vec<u8>> = notes.0.iter().map(solve).collect();
     let n = sol.iter().fold(0, |s, n| {
         s + n
             .iter()
             .fold((1000, 0u32), |(f, s), n| (f / 10, (*n as u32) * f + s))
             .1
     });
-    Ok(format!("{n}"))
+    Ok(n.into())
 }
 
 type Example<'a> = (Vec<&'a [u8]>, Vec<&'a [u8]>);
 
-fn parse(input: &str) -> Result<Vec<Example<'_>>> {
+pub struct Notes<'a>(Vec<Example<'a>>);
+
+impl Input for Notes<'static> {
+    type Of<'a> = Notes<'a>;
+    type View<'a> = Notes<'a>;
+}
+
+pub fn parse(input: &str) -> Result<Notes<'_>> {
     let lines = input.lines().filter(|line| !line.is_empty());
     lines
         .map(|line| {
@@ -37,7 +43,8 @@ fn parse(input: &str) -> Result<Vec<Example<'_>>> {
                     .collect(),
             ))
         })
-        .collect()
+        .collect::<Result<_>>()
+        .map(Notes)
 }
 
 fn solve(example: &Example) -> Vec<u8> {
@@ -80,7 +87,7 @@ pub mod tests {
     #[test]
     fn test_example() {
         let examples = parse(EXAMPLE).unwrap();
-        assert_eq!(solve(&examples[0]), vec![8u8, 3u8, 9u8, 4u8]);
+        assert_eq!(solve(&examples.0[0]), vec![8u8, 3u8, 9u8, 4u8]);
     }
 
     const EXAMPLE: &str =
@@ -1,6 +1,7 @@
 use anyhow::{Context, Result};
 use fxhash::FxHashSet;
 use itertools::Itertools;
+use shared::AnswerValue;
 
 #[derive(Eq, PartialEq, Debug)]
 struct Map {
@@ -125,14 +126,14 @@ fn solve_2(input: &str) -> Result<usize> {
     Ok(s)
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let s = solve_1(input)?;
-    Ok(format!("{s}"))
+    Ok(s.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let s = solve_2(input)?;
-    Ok(format!("{s}"))
+    Ok(s.into())
 }
 
 #[cfg(test)]
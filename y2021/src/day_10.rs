@@ -1,5 +1,6 @@
 use anyhow::Result;
 use itertools::Itertools;
+use shared::AnswerValue;
 
 fn corrupted(line: &str) -> Option<char> {
     let mut stack = vec![];
@@ -85,14 +86,14 @@ fn solve_2(input: &str) -> usize {
     v[v.len() / 2]
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let score = solve_1(input);
-    Ok(format!("{score}"))
+    Ok(score.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let score = solve_2(input);
-    Ok(format!("{score}"))
+    Ok(score.into())
 }
 
 #[cfg(test)]
@@ -1,4 +1,5 @@
 use anyhow::{anyhow, Result};
+use shared::AnswerValue;
 
 #[derive(Eq, PartialEq, Clone, Debug)]
 pub struct Map {
@@ -6,7 +7,7 @@ pub struct Map {
     octopi: Vec<u16>,
 }
 
-fn parse_map(input: &str) -> Result<Map> {
+pub fn parse_map(input: &str) -> Result<Map> {
     let mut lines = input.lines().filter(|l| !l.is_empty());
     if let Some(first) = lines.next() {
         let w = first.len();
@@ -92,19 +93,19 @@ fn step_octopi(map: &mut Map) -> usize {
     flashes.iter().filter(|t| **t).count()
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let mut map = parse_map(input)?;
+pub fn part_1(map: &Map) -> Result<AnswerValue> {
+    let mut map = map.clone();
     let flashes: usize = (0..100).map(|_| step_octopi(&mut map)).sum();
-    Ok(format!("{flashes}"))
+    Ok(flashes.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let mut map = parse_map(input)?;
+pub fn part_2(map: &Map) -> Result<AnswerValue> {
+    let mut map = map.clone();
     let mut i = 1;
     while step_octopi(&mut map) != map.octopi.len() {
         i += 1;
     }
-    Ok(format!("{i}"))
+    Ok(i.into())
 }
 
 #[cfg(test)]
@@ -1,8 +1,9 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
+use shared::{AnswerValue, Input};
 use std::collections::HashMap;
 
 #[derive(Eq, PartialEq, Debug)]
-enum Cave {
+pub enum Cave {
     Small(usize),
     Large(usize),
 }
@@ -37,14 +38,19 @@ impl Cave {
 type Graph = Vec<Vec<Cave>>;
 
 #[derive(Debug, Eq, PartialEq)]
-struct Search<'a> {
+pub struct Search<'a> {
     labels: HashMap<&'a str, usize>,
     graph: Graph,
     start: Cave,
     end: Cave,
 }
 
-fn parse_graph(input: &str) -> Search<'_> {
+impl Input for Search<'static> {
+    type Of<'a> = Search<'a>;
+    type View<'a> = Search<'a>;
+}
+
+pub fn parse_graph(input: &str) -> Result<Search<'_>> {
     let mut labels: HashMap<_, usize> = HashMap::new();
     let mut idx = 0;
     let mut graph = Graph::new();
@@ -67,14 +73,14 @@ fn parse_graph(input: &str) -> Search<'_> {
             graph[right].push(Cave::new(vtx1, left));
         }
     }
-    let start = *labels.get("start").unwrap();
-    let end = *labels.get("end").unwrap();
-    Search {
+    let start = *labels.get("start").context("No start cave")?;
+    let end = *labels.get("end").context("No end cave")?;
+    Ok(Search {
         labels,
         graph,
         start: Cave::new("start", start),
         end: Cave::new("end", end),
-    }
+    })
 }
 
 fn dfs_count(search: &Search, allow_dup: bool) -> usize {
@@ -100,15 +106,13 @@ fn dfs_count(search: &Search, allow_dup: bool) -> usize {
     completed_paths
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let search = parse_graph(input);
-    let paths = dfs_count(&search, false);
-    Ok(format!("{paths}"))
+pub fn part_1(search: &Search) -> Result<AnswerValue> {
+    let paths = dfs_count(search, false);
+    Ok(paths.into())
 }
-pub fn part_2(input: &str) -> Result<String> {
-    let search = parse_graph(input);
-    let paths = dfs_count(&search, true);
-    Ok(format!("{paths}"))
+pub fn part_2(search: &Search) -> Result<AnswerValue> {
+    let paths = dfs_count(search, true);
+    Ok(paths.into())
 }
 
 #[cfg(test)]
@@ -117,25 +121,25 @@ pub mod tests {
 
     #[test]
     fn small_example_p1() {
-        let search = parse_graph(SMALL_EXAMPLE);
+        let search = parse_graph(SMALL_EXAMPLE).unwrap();
         assert_eq!(dfs_count(&search, false), 10);
     }
 
     #[test]
     fn small_example_p2() {
-        let search = parse_graph(SMALL_EXAMPLE);
+        let search = parse_graph(SMALL_EXAMPLE).unwrap();
         assert_eq!(dfs_count(&search, true), 36);
     }
 
     #[test]
     fn large_example_p1() {
-        let search = parse_graph(EXAMPLE);
+        let search = parse_graph(EXAMPLE).unwrap();
         assert_eq!(dfs_count(&search, false), 226);
     }
 
     #[test]
     fn large_example_p2() {
-        let search = parse_graph(EXAMPLE);
+        let search = parse_graph(EXAMPLE).unwrap();
         assert_eq!(dfs_count(&search, true), 3509);
     }
 
@@ -1,5 +1,6 @@
 use anyhow::{Context, Result};
 use regex::Regex;
+use shared::AnswerValue;
 use std::cmp::{max, min};
 use std::collections::HashSet;
 
@@ -80,10 +81,10 @@ fn solve_1(input: &Input) -> usize {
     folded_locations(&input.0, &input.1[0], 0, 0).len()
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let input = parse(input)?;
     let sol = solve_1(&input);
-    Ok(format!("{sol}"))
+    Ok(sol.into())
 }
 
 fn bounds(hs: &HashSet<(i64, i64)>) -> (i64, i64, i64, i64) {
@@ -93,7 +94,8 @@ fn bounds(hs: &HashSet<(i64, i64)>) -> (i64, i64, i64, i64) {
         })
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+/// The folded paper, before reading the letters
+pub fn render(input: &str) -> Result<String> {
     let input = parse(input)?;
     let mut hs = input.0;
     let (mut xmin, mut xmax, mut ymin, mut ymax) = bounds(&hs);
@@ -118,6 +120,10 @@ pub fn part_2(input: &str) -> Result<String> {
     Ok(out)
 }
 
+pub fn part_2(input: &str) -> Result<AnswerValue> {
+    shared::ocr::read_letters(&render(input)?).map(AnswerValue::from)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -136,9 +142,10 @@ mod tests {
     }
 
     #[test]
-    fn test_part_2() {
-        let s = part_2(EXAMPLE).unwrap();
-        println!("{s}");
+    fn test_render() {
+        let s = render(EXAMPLE).unwrap();
+        assert_eq!(s, "#####\n#   #\n#   #\n#   #\n#####\n     \n");
+        assert!(part_2(EXAMPLE).is_err());
     }
 
     const EXAMPLE: &str = "6,10
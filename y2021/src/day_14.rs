@@ -1,5 +1,6 @@
 use anyhow::{Context, Result};
 use fxhash::FxHashMap as HashMap;
+use shared::AnswerValue;
 
 type Pair = (char, char);
 type PairMap = HashMap<Pair, usize>;
@@ -80,12 +81,12 @@ fn solve(input: &str, iterations: usize) -> Result<usize> {
     Ok(score)
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    solve(input, 10).map(|score| format!("{score}"))
+pub fn part_1(input: &str) -> Result<AnswerValue> {
+    solve(input, 10).map(AnswerValue::from)
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    solve(input, 40).map(|score| format!("{score}"))
+pub fn part_2(input: &str) -> Result<AnswerValue> {
+    solve(input, 40).map(AnswerValue::from)
 }
 
 #[cfg(test)]
@@ -1,5 +1,6 @@
 use anyhow::{Context, Result};
 use itertools::Itertools;
+use shared::AnswerValue;
 use std::cmp::Reverse;
 use std::collections::BinaryHeap;
 
@@ -12,7 +13,7 @@ pub struct Chiton {
     risk: Vec<Vec<u8>>,
 }
 
-fn parse(input: &str) -> Result<Chiton> {
+pub fn parse(input: &str) -> Result<Chiton> {
     let width = input.lines().next().context("Empty input")?.chars().count() as i32;
     let mut risk = Vec::with_capacity(width as usize);
     for line in input.lines().filter(|line| !line.is_empty()) {
@@ -57,10 +58,8 @@ fn shortest_path(chiton: &Chiton, start_loc: Location, end_loc: Location) -> Res
     Ok(costs[ey as usize][ex as usize])
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let chiton = parse(input)?;
-    shortest_path(&chiton, (0, 0), (chiton.width - 1, chiton.height - 1))
-        .map(|cost| format!("{cost}"))
+pub fn part_1(chiton: &Chiton) -> Result<AnswerValue> {
+    shortest_path(chiton, (0, 0), (chiton.width - 1, chiton.height - 1)).map(AnswerValue::from)
 }
 
 const EXTRA_COST: [[u8; 5]; 5] = [
@@ -70,8 +69,7 @@ const EXTRA_COST: [[u8; 5]; 5] = [
     [3, 4, 5, 6, 7],
     [4, 5, 6, 7, 8],
 ];
-pub fn part_2(input: &str) -> Result<String> {
-    let chiton = parse(input)?;
+pub fn part_2(chiton: &Chiton) -> Result<AnswerValue> {
     let real_width = 5 * chiton.width;
     let real_height = 5 * chiton.height;
 
@@ -102,7 +100,7 @@ pub fn part_2(input: &str) -> Result<String> {
         (0, 0),
         (real_chiton.width - 1, real_chiton.height - 1),
     )
-    .map(|cost| format!("{cost}"))
+    .map(AnswerValue::from)
 }
 
 #[cfg(test)]
@@ -118,8 +116,8 @@ pub mod tests {
 
     #[test]
     fn test_example_part2() {
-        let answer = part_2(EXAMPLE).unwrap();
-        assert_eq!(answer.as_str(), "315");
+        let answer = part_2(&parse(EXAMPLE).unwrap()).unwrap();
+        assert_eq!(answer, AnswerValue::Integer(315));
     }
 
     const EXAMPLE: &str = "1163751742
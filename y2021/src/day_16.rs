@@ -1,5 +1,6 @@
 use anyhow::Result;
 use itertools::Itertools;
+use shared::AnswerValue;
 
 #[derive(Debug, Eq, PartialEq)]
 struct BinaryIterator {
@@ -169,18 +170,18 @@ fn packet_arithmetic(packet: &Packet) -> usize {
     }
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let mut it = BinaryIterator::parse(input);
     let packets = parse_packet(&mut it);
     let sol = sum_versions(&packets);
-    Ok(format!("{sol}"))
+    Ok(sol.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let mut it = BinaryIterator::parse(input);
     let packets = parse_packet(&mut it);
     let sol = packet_arithmetic(&packets);
-    Ok(format!("{sol}"))
+    Ok(sol.into())
 }
 
 #[cfg(test)]
@@ -1,16 +1,17 @@
 use anyhow::{anyhow, Result};
 use rayon::prelude::*;
 use regex::Regex;
+use shared::AnswerValue;
 use std::cmp::{max, min};
 use std::ops::RangeInclusive;
 
 #[derive(Eq, PartialEq, Debug)]
-struct Area {
+pub struct Area {
     xrange: RangeInclusive<i32>,
     yrange: RangeInclusive<i32>,
 }
 
-fn parse_area(input: &str) -> Result<Area> {
+pub fn parse_area(input: &str) -> Result<Area> {
     let re = Regex::new(r"(-?[0-9]+)+")?;
     let caps = re.captures_iter(input);
     let r: Result<Vec<_>, _> = caps
@@ -56,16 +57,14 @@ fn count_distinct_velocities(area: &Area) -> usize {
         .sum()
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let area = parse_area(input)?;
+pub fn part_1(area: &Area) -> Result<AnswerValue> {
     let ymin = *area.yrange.start();
     let max_y = (ymin * ymin + ymin) / 2;
-    Ok(format!("{max_y}"))
+    Ok(max_y.into())
 }
-pub fn part_2(input: &str) -> Result<String> {
-    let area = parse_area(input)?;
-    let sol = count_distinct_velocities(&area);
-    Ok(format!("{sol}"))
+pub fn part_2(area: &Area) -> Result<AnswerValue> {
+    let sol = count_distinct_velocities(area);
+    Ok(sol.into())
 }
 
 #[cfg(test)]
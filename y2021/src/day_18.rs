@@ -1,5 +1,6 @@
 use anyhow::Result;
 use itertools::Itertools;
+use shared::AnswerValue;
 
 #[derive(Eq, PartialEq, Debug, Clone)]
 enum SnailfishNumber {
@@ -138,14 +139,14 @@ fn do_extra_credit(input: &str) -> u32 {
     out
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let sol = do_homework(input);
-    Ok(format!("{sol}"))
+    Ok(sol.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let sol = do_extra_credit(input);
-    Ok(format!("{sol}"))
+    Ok(sol.into())
 }
 
 #[cfg(test)]
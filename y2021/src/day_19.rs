@@ -2,18 +2,20 @@ use anyhow::{anyhow, Context, Result};
 use fxhash::FxHashSet as HashSet;
 use itertools::{FoldWhile, Itertools};
 use regex::Regex;
+use shared::geometry::Point3d;
+use shared::AnswerValue;
 
 type CoordSize = i32;
-type Point = (CoordSize, CoordSize, CoordSize);
+type Point = Point3d<CoordSize>;
 
 #[derive(Eq, PartialEq, Debug, Clone)]
-struct Scanner {
+pub struct Scanner {
     id: u8,
     relative_beacons: Vec<Point>,
     distances_squared: Vec<HashSet<CoordSize>>,
 }
 
-fn parse(input: &str) -> Result<Vec<Scanner>> {
+pub fn parse(input: &str) -> Result<Vec<Scanner>> {
     let mut out = vec![];
     let id_re = Regex::new(r"^--- scanner (\d+) ---$")?;
 
@@ -38,22 +40,19 @@ fn parse(input: &str) -> Result<Vec<Scanner>> {
                     let x = x.parse::<CoordSize>()?;
                     let y = y.parse::<CoordSize>()?;
                     let z = z.parse::<CoordSize>()?;
-                    let r: Result<Point> = Ok((x, y, z));
+                    let r: Result<Point> = Ok(Point3d::new(x, y, z));
                     r
                 })
                 .collect();
             let relative_beacons = beacons?;
             let distances_squared = (0..relative_beacons.len())
                 .map(|i| {
-                    let (x1, y1, z1) = &relative_beacons[i];
+                    let here = relative_beacons[i];
                     (0..relative_beacons.len())
                         .filter(move |j| i != *j)
                         .map(|j| {
-                            let (x2, y2, z2) = &relative_beacons[j];
-                            let dx = x1 - x2;
-                            let dy = y1 - y2;
-                            let dz = z1 - z2;
-                            dx * dx + dy * dy + dz * dz
+                            let diff = here - relative_beacons[j];
+                            diff.dot(diff)
                         })
                         .collect()
                 })
@@ -79,12 +78,12 @@ impl AxisInterpretation {
     fn get(&self, point: &Point) -> CoordSize {
         use AxisInterpretation::*;
         match self {
-            X(true) => -point.0,
-            X(false) => point.0,
-            Y(true) => -point.1,
-            Y(false) => point.1,
-            Z(true) => -point.2,
-            Z(false) => point.2,
+            X(true) => -point.x,
+            X(false) => point.x,
+            Y(true) => -point.y,
+            Y(false) => point.y,
+            Z(true) => -point.z,
+            Z(false) => point.z,
         }
     }
 }
@@ -118,7 +117,7 @@ impl Rotation {
             .collect()
     }
     fn rotate(&self, point: &Point) -> Point {
-        (self.x.get(point), self.y.get(point), self.z.get(point))
+        Point3d::new(self.x.get(point), self.y.get(point), self.z.get(point))
     }
     fn discover(
         reference_diff: &Point,
@@ -126,12 +125,13 @@ impl Rotation {
         allowed_rotations: &[Rotation],
     ) -> Option<Rotation> {
         // If these don't hold, there might be more than 1 valid rotation
-        assert_ne!(reference_diff.0.abs(), reference_diff.1.abs());
-        assert_ne!(reference_diff.0.abs(), reference_diff.2.abs());
-        assert_ne!(reference_diff.1.abs(), reference_diff.2.abs());
-        assert_ne!(target.0.abs(), target.1.abs());
-        assert_ne!(target.0.abs(), target.2.abs());
-        assert_ne!(target.1.abs(), target.2.abs());
+        let (reference, target_abs) = (reference_diff.abs(), target.abs());
+        assert_ne!(reference.x, reference.y);
+        assert_ne!(reference.x, reference.z);
+        assert_ne!(reference.y, reference.z);
+        assert_ne!(target_abs.x, target_abs.y);
+        assert_ne!(target_abs.x, target_abs.z);
+        assert_ne!(target_abs.y, target_abs.z);
         allowed_rotations
             .iter()
             .find(|rotation| rotation.rotate(target) == *reference_diff)
@@ -148,13 +148,12 @@ fn borders(left: &Scanner, right: &Scanner) -> bool {
 #[derive(Eq, PartialEq, Debug, Copy, Clone)]
 struct Translation {
     rotation: Rotation,
-    translation: (CoordSize, CoordSize, CoordSize),
+    translation: Point,
 }
 
 impl Translation {
     fn apply(&self, point: &Point) -> Point {
-        let rot = self.rotation.rotate(point);
-        add(&rot, &self.translation)
+        self.rotation.rotate(point) + self.translation
     }
 
     fn on_all<'a>(&self, points: &mut impl Iterator<Item = &'a Point>) -> Vec<Point> {
@@ -170,14 +169,6 @@ impl Translation {
     }
 }
 
-fn sub(lhs: &Point, rhs: &Point) -> Point {
-    (lhs.0 - rhs.0, lhs.1 - rhs.1, lhs.2 - rhs.2)
-}
-
-fn add(lhs: &Point, rhs: &Point) -> Point {
-    (lhs.0 + rhs.0, lhs.1 + rhs.1, lhs.2 + rhs.2)
-}
-
 fn connect(
     reference_scanner: &Scanner,
     candidate: &Scanner,
@@ -205,14 +196,28 @@ fn connect(
                 } else {
                     let (i, j) = c;
                     let known_ref = reference_scanner.relative_beacons[i];
-                    let (rx, ry, rz) = known_ref;
-                    let known_candidate = candidate.relative_beacons[j];
-                    let (cx, cy, cz) = known_candidate;
+                    let Point3d {
+                        x: rx,
+                        y: ry,
+                        z: rz,
+                    } = known_ref;
+                    let Point3d {
+                        x: cx,
+                        y: cy,
+                        z: cz,
+                    } = candidate.relative_beacons[j];
                     for (i1, j1) in v.iter() {
                         let consider_ref = reference_scanner.relative_beacons[*i1];
-                        let (rx1, ry1, rz1) = consider_ref;
-                        let consider_candidate = candidate.relative_beacons[*j1];
-                        let (cx1, cy1, cz1) = consider_candidate;
+                        let Point3d {
+                            x: rx1,
+                            y: ry1,
+                            z: rz1,
+                        } = consider_ref;
+                        let Point3d {
+                            x: cx1,
+                            y: cy1,
+                            z: cz1,
+                        } = candidate.relative_beacons[*j1];
                         // Our chosen beacons on either side must not share any coordinate
                         // or we can not use them to discover what rotation the candidate has
                         if rx == rx1
@@ -224,12 +229,9 @@ fn connect(
                         {
                             return FoldWhile::Continue(v);
                         }
-                        let rdiff = sub(&known_ref, &consider_ref);
+                        let rdiff = (known_ref - consider_ref).abs();
                         // Would be ambiguous, multiple rotations might be possible
-                        if rdiff.0.abs() == rdiff.1.abs()
-                            || rdiff.1.abs() == rdiff.2.abs()
-                            || rdiff.0.abs() == rdiff.2.abs()
-                        {
+                        if rdiff.x == rdiff.y || rdiff.y == rdiff.z || rdiff.x == rdiff.z {
                             return FoldWhile::Continue(v);
                         }
                     }
@@ -246,13 +248,13 @@ fn connect(
             let cand_v1 = candidate.relative_beacons[v1.1];
             let ref_v2 = reference_scanner.relative_beacons[v2.0];
             let cand_v2 = candidate.relative_beacons[v2.1];
-            let ref_diff = sub(&ref_v1, &ref_v2);
-            let cand_diff = sub(&cand_v1, &cand_v2);
+            let ref_diff = ref_v1 - ref_v2;
+            let cand_diff = cand_v1 - cand_v2;
             let rot = Rotation::discover(&ref_diff, &cand_diff, allowed_rotations);
             assert!(rot.is_some());
             let rot = rot?;
             let rot_c1 = rot.rotate(&cand_v1);
-            let trans = sub(&ref_v1, &rot_c1);
+            let trans = ref_v1 - rot_c1;
             let trans = Translation {
                 rotation: rot,
                 translation: trans,
@@ -271,7 +273,7 @@ fn connect_scanners(scanners: &[Scanner]) -> (Vec<Scanner>, Vec<Point>) {
     let mut work = scanners.iter().rev().clone().collect_vec();
     let mut done = vec![work.pop().unwrap().clone()];
     let mut place = 0;
-    let mut scanner_locations = vec![(0, 0, 0)];
+    let mut scanner_locations = vec![Point::origin()];
     let allowed_rotations = Rotation::possible();
 
     while !work.is_empty() {
@@ -285,16 +287,15 @@ fn connect_scanners(scanners: &[Scanner]) -> (Vec<Scanner>, Vec<Point>) {
             })
             .collect_vec();
         work.retain(|scanner| !to_add.iter().map(|s| s.id).contains(&scanner.id));
-        done.extend(to_add.into_iter());
+        done.extend(to_add);
         place += 1;
     }
 
     (done, scanner_locations)
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let scanners = parse(input)?;
-    let (connected_scanners, _) = connect_scanners(&scanners);
+pub fn part_1(scanners: &[Scanner]) -> Result<AnswerValue> {
+    let (connected_scanners, _) = connect_scanners(scanners);
 
     let beacons: HashSet<_> = connected_scanners
         .iter()
@@ -302,29 +303,22 @@ pub fn part_1(input: &str) -> Result<String> {
         .cloned()
         .collect();
 
-    Ok(format!("{}", beacons.len()))
-}
-
-fn manhattan(points: (&Point, &Point)) -> CoordSize {
-    let (lhs, rhs) = points;
-    let dist = sub(lhs, rhs);
-    dist.0.abs() + dist.1.abs() + dist.2.abs()
+    Ok(beacons.len().into())
 }
 
 fn max_manhattan(points: &[Point]) -> Option<CoordSize> {
     points
         .iter()
         .cartesian_product(points.iter())
-        .map(manhattan)
+        .map(|(&lhs, &rhs)| (lhs - rhs).manhattan())
         .max()
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let scanners = parse(input)?;
-    let (_, locations) = connect_scanners(&scanners);
+pub fn part_2(scanners: &[Scanner]) -> Result<AnswerValue> {
+    let (_, locations) = connect_scanners(scanners);
     let dist = max_manhattan(&locations);
     dist.ok_or_else(|| anyhow!("Unable to find scanner locations"))
-        .map(|distance| format!("{distance}"))
+        .map(AnswerValue::from)
 }
 
 #[cfg(test)]
@@ -2,6 +2,7 @@ use anyhow::{anyhow, Context, Result};
 use fxhash::FxHashMap as HashMap;
 use itertools::Itertools;
 use rayon::prelude::*;
+use shared::AnswerValue;
 use std::cmp::{max, min};
 
 type Pixel = bool;
@@ -12,8 +13,9 @@ type Kernel = [Pixel; 9]; // 3 x 3 kernel
 type Coord = i32;
 type Coordinate = (Coord, Coord);
 type Image = HashMap<Coordinate, Pixel>;
+pub type Problem = (Algorithm, Image);
 
-fn parse(input: &str) -> Option<(Algorithm, Image)> {
+fn parse(input: &str) -> Option<Problem> {
     let (algorithm, image) = input.split_once("\n\n")?;
 
     assert_eq!(algorithm.len(), SIZE);
@@ -88,20 +90,23 @@ fn convolve(image: &Image, algorithm: &Algorithm, background: Pixel) -> Image {
         .collect()
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let (alg, image) = parse(input).with_context(|| anyhow!("Bad input"))?;
-    let image = convolve(&image, &alg, !alg[0]);
-    let image = convolve(&image, &alg, alg[0]);
+pub fn parse_input(input: &str) -> Result<Problem> {
+    parse(input).with_context(|| anyhow!("Bad input"))
+}
+
+pub fn part_1((alg, image): &Problem) -> Result<AnswerValue> {
+    let image = convolve(image, alg, !alg[0]);
+    let image = convolve(&image, alg, alg[0]);
     let lit = image.values().filter(|pixel| **pixel).count();
-    Ok(format!("{lit}"))
+    Ok(lit.into())
 }
-pub fn part_2(input: &str) -> Result<String> {
-    let (alg, mut image) = parse(input).with_context(|| anyhow!("Bad input"))?;
+pub fn part_2((alg, image): &Problem) -> Result<AnswerValue> {
+    let mut image = image.clone();
     for round in 0..50 {
-        image = convolve(&image, &alg, alg[0] && (round & 1 == 1));
+        image = convolve(&image, alg, alg[0] && (round & 1 == 1));
     }
     let lit = image.values().filter(|pixel| **pixel).count();
-    Ok(format!("{lit}"))
+    Ok(lit.into())
 }
 
 #[cfg(test)]
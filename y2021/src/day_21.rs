@@ -1,9 +1,10 @@
 use anyhow::{anyhow, Context, Result};
 use fxhash::FxHashMap as HashMap;
 use itertools::Itertools;
+use shared::AnswerValue;
 use std::cmp::{max, min};
 
-fn parse(input: &str) -> Result<(u16, u16)> {
+pub fn parse(input: &str) -> Result<(u16, u16)> {
     let r: Result<Vec<_>> = input
         .lines()
         .filter(|line| !line.is_empty())
@@ -143,16 +144,14 @@ fn dirac_dice(one: u16, two: u16) -> u64 {
     max(tally_p1, tally_p2)
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let (one, two) = parse(input)?;
+pub fn part_1(&(one, two): &(u16, u16)) -> Result<AnswerValue> {
     let sol = deterministic_dice(one, two);
-    Ok(format!("{sol}"))
+    Ok(sol.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let (one, two) = parse(input)?;
+pub fn part_2(&(one, two): &(u16, u16)) -> Result<AnswerValue> {
     let sol = dirac_dice(one, two);
-    Ok(format!("{sol}"))
+    Ok(sol.into())
 }
 
 #[cfg(test)]
@@ -1,32 +1,32 @@
 use anyhow::Result;
 use itertools::Itertools;
 use regex::Regex;
+use shared::AnswerValue;
 
-pub fn part_1(input: &str) -> Result<String> {
-    let input = parse(input)?;
+pub fn part_1(input: &[(Cuboid, bool)]) -> Result<AnswerValue> {
     let p1_volume = Cuboid {
         x: Span(-50, 50),
         y: Span(-50, 50),
         z: Span(-50, 50),
     };
     let input = input
-        .into_iter()
+        .iter()
         .filter(|(cuboid, _)| p1_volume.contains(cuboid))
+        .copied()
         .collect_vec();
     let volume = build_volume(&input);
     let volume: i64 = volume.iter().map(|set| set.volume()).sum();
-    Ok(format!("{volume}"))
+    Ok(volume.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let input = parse(input)?;
-    let volume = build_volume(&input);
+pub fn part_2(input: &[(Cuboid, bool)]) -> Result<AnswerValue> {
+    let volume = build_volume(input);
     let volume: i64 = volume.iter().map(|set| set.volume()).sum();
-    Ok(format!("{volume}"))
+    Ok(volume.into())
 }
 
 #[derive(Eq, PartialEq, Debug, Ord, PartialOrd, Clone, Copy, Hash)]
-struct Span(i64, i64);
+pub struct Span(i64, i64);
 
 impl Span {
     fn is_empty(&self) -> bool {
@@ -56,7 +56,7 @@ impl Span {
 }
 
 #[derive(Eq, PartialEq, Debug, Ord, PartialOrd, Clone, Copy, Hash)]
-struct Cuboid {
+pub struct Cuboid {
     x: Span,
     y: Span,
     z: Span,
@@ -162,7 +162,7 @@ fn build_volume(cuboids: &[(Cuboid, bool)]) -> Vec<CuboidSet> {
     out
 }
 
-fn parse(input: &str) -> Result<Vec<(Cuboid, bool)>> {
+pub fn parse(input: &str) -> Result<Vec<(Cuboid, bool)>> {
     let re = Regex::new(r"(-?[0-9]+)")?;
     input
         .lines()
@@ -1,10 +1,11 @@
 use anyhow::Result;
 use fxhash::FxHashMap as HashMap;
+use shared::AnswerValue;
 use std::cmp::Reverse;
 use std::collections::BinaryHeap;
 
 #[derive(Eq, PartialEq, Debug, Copy, Clone, Hash, Ord, PartialOrd)]
-enum Amphipod {
+pub enum Amphipod {
     Amber,
     Bronze,
     Copper,
@@ -38,7 +39,7 @@ const VARIANTS: [Amphipod; 4] = [
 ];
 
 #[derive(Eq, PartialEq, Debug, Copy, Clone, Hash, Ord, PartialOrd)]
-enum Tile {
+pub enum Tile {
     Contains(Amphipod),
     Empty,
 }
@@ -237,10 +238,10 @@ fn shortest_path<const N: usize>(initial: &State<N>) -> usize {
     panic!("Unable to find path")
 }
 
-fn parse(input: &str) -> Vec<Vec<Tile>> {
+pub fn parse(input: &str) -> Result<Vec<Vec<Tile>>> {
     use Amphipod::*;
     use Tile::*;
-    input
+    let board = input
         .lines()
         .filter(|line| !line.is_empty())
         .map(|line| {
@@ -257,14 +258,14 @@ fn parse(input: &str) -> Vec<Vec<Tile>> {
                 .collect()
         })
         .filter(|row: &Vec<Tile>| !row.is_empty())
-        .collect()
+        .collect();
+    Ok(board)
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let board = parse(input);
-    let initial = state_from(&board);
+pub fn part_1(board: &[Vec<Tile>]) -> Result<AnswerValue> {
+    let initial = state_from(board);
     let sol = shortest_path(&initial);
-    Ok(format!("{sol}"))
+    Ok(sol.into())
 }
 
 fn part_2_state_from(board: &[Vec<Tile>]) -> State<4> {
@@ -291,11 +292,10 @@ fn part_2_state_from(board: &[Vec<Tile>]) -> State<4> {
     state_4
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let board = parse(input);
-    let initial = part_2_state_from(&board);
+pub fn part_2(board: &[Vec<Tile>]) -> Result<AnswerValue> {
+    let initial = part_2_state_from(board);
     let sol = shortest_path(&initial);
-    Ok(format!("{sol}"))
+    Ok(sol.into())
 }
 
 #[cfg(test)]
@@ -304,7 +304,7 @@ pub mod tests {
 
     #[test]
     fn test_parse() {
-        let board = parse(EXAMPLE);
+        let board = parse(EXAMPLE).unwrap();
         let state = state_from(&board);
         let sol = shortest_path(&state);
         assert_eq!(sol, 12521);
@@ -312,7 +312,7 @@ pub mod tests {
 
     #[test]
     fn test_part2() {
-        let board = parse(EXAMPLE);
+        let board = parse(EXAMPLE).unwrap();
         let state = part_2_state_from(&board);
         let sol = shortest_path(&state);
         assert_eq!(44169, sol);
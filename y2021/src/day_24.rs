@@ -1,6 +1,7 @@
 use anyhow::{anyhow, Context, Result};
 use itertools::Itertools;
 use regex::Regex;
+use shared::AnswerValue;
 
 // General notes:
 // The assignment has 14 distinct subprograms, one for each digit. `z`
@@ -26,13 +27,13 @@ use regex::Regex;
 // So each program section is actually identified by only 3 numbers:
 // the divisor of Z, A which is added to x and B which is added to y
 #[derive(Eq, PartialEq, Debug, Copy, Clone)]
-struct Program {
+pub struct Program {
     div_z: i64,
     add_x: i64,
     add_y: i64,
 }
 
-fn parse(input: &str) -> Result<Vec<Program>> {
+pub fn parse(input: &str) -> Result<Vec<Program>> {
     let div_z_re = Regex::new(r"div z ([0-9]+)")?;
     let add_x_re = Regex::new(r"add x (-?[0-9]+)")?;
     let add_y_re = Regex::new(r"add y (-?[0-9]+)")?;
@@ -83,15 +84,13 @@ fn solve(progs: &[Program], digits: &[i64]) -> Option<i64> {
     None
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let progs = parse(input)?;
-    let sol = solve(&progs, &(1..=9).collect_vec()).with_context(|| anyhow!("Unable to solve"))?;
-    Ok(format!("{sol:?}"))
+pub fn part_1(progs: &[Program]) -> Result<AnswerValue> {
+    let sol = solve(progs, &(1..=9).collect_vec()).with_context(|| anyhow!("Unable to solve"))?;
+    Ok(sol.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let progs = parse(input)?;
+pub fn part_2(progs: &[Program]) -> Result<AnswerValue> {
     let sol =
-        solve(&progs, &(1..=9).rev().collect_vec()).with_context(|| anyhow!("Unable to solve"))?;
-    Ok(format!("{sol:?}"))
+        solve(progs, &(1..=9).rev().collect_vec()).with_context(|| anyhow!("Unable to solve"))?;
+    Ok(sol.into())
 }
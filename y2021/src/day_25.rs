@@ -1,5 +1,6 @@
 use anyhow::{anyhow, Result};
 use itertools::Itertools;
+use shared::AnswerValue;
 use std::fmt::{Debug, Formatter, Write};
 
 #[derive(Eq, PartialEq, Debug, Copy, Clone)]
@@ -112,13 +113,13 @@ fn solve(mut seafloor: Seafloor) -> usize {
     rounds
 }
 
-pub fn part_1(_input: &str) -> Result<String> {
+pub fn part_1(_input: &str) -> Result<AnswerValue> {
     let seafloor = parse(_input)?;
     let solution = solve(seafloor);
-    Ok(format!("{solution}"))
+    Ok(solution.into())
 }
 
-pub fn part_2(_input: &str) -> Result<String> {
+pub fn part_2(_input: &str) -> Result<AnswerValue> {
     Ok("Submit the answers and click the button".into())
 }
 
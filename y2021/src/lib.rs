@@ -1,4 +1,4 @@
-use shared::{Answer, Answer::SolvedBoth};
+use shared::{Day, List, Owned, Parsed, Solution};
 
 pub mod day_01;
 pub mod day_02;
@@ -26,105 +26,90 @@ pub mod day_23;
 pub mod day_24;
 pub mod day_25;
 
-pub const SOLUTIONS: [Answer; 25] = [
-    SolvedBoth {
-        part_1: day_01::part_1,
-        part_2: day_01::part_2,
-    },
-    SolvedBoth {
-        part_1: day_02::part_1,
-        part_2: day_02::part_2,
-    },
-    SolvedBoth {
-        part_1: day_03::part_1,
-        part_2: day_03::part_2,
-    },
-    SolvedBoth {
-        part_1: day_04::part_1,
-        part_2: day_04::part_2,
-    },
-    SolvedBoth {
-        part_1: day_05::part_1,
-        part_2: day_05::part_2,
-    },
-    SolvedBoth {
-        part_1: day_06::part_1,
-        part_2: day_06::part_2,
-    },
-    SolvedBoth {
-        part_1: day_07::part_1,
-        part_2: day_07::part_2,
-    },
-    SolvedBoth {
-        part_1: day_08::part_1,
-        part_2: day_08::part_2,
-    },
-    SolvedBoth {
-        part_1: day_09::part_1,
-        part_2: day_09::part_2,
-    },
-    SolvedBoth {
-        part_1: day_10::part_1,
-        part_2: day_10::part_2,
-    },
-    SolvedBoth {
-        part_1: day_11::part_1,
-        part_2: day_11::part_2,
-    },
-    SolvedBoth {
-        part_1: day_12::part_1,
-        part_2: day_12::part_2,
-    },
-    SolvedBoth {
-        part_1: day_13::part_1,
-        part_2: day_13::part_2,
-    },
-    SolvedBoth {
-        part_1: day_14::part_1,
-        part_2: day_14::part_2,
-    },
-    SolvedBoth {
-        part_1: day_15::part_1,
-        part_2: day_15::part_2,
-    },
-    SolvedBoth {
-        part_1: day_16::part_1,
-        part_2: day_16::part_2,
-    },
-    SolvedBoth {
-        part_1: day_17::part_1,
-        part_2: day_17::part_2,
-    },
-    SolvedBoth {
-        part_1: day_18::part_1,
-        part_2: day_18::part_2,
-    },
-    SolvedBoth {
-        part_1: day_19::part_1,
-        part_2: day_19::part_2,
-    },
-    SolvedBoth {
-        part_1: day_20::part_1,
-        part_2: day_20::part_2,
-    },
-    SolvedBoth {
-        part_1: day_21::part_1,
-        part_2: day_21::part_2,
-    },
-    SolvedBoth {
-        part_1: day_22::part_1,
-        part_2: day_22::part_2,
-    },
-    SolvedBoth {
-        part_1: day_23::part_1,
-        part_2: day_23::part_2,
-    },
-    SolvedBoth {
-        part_1: day_24::part_1,
-        part_2: day_24::part_2,
-    },
-    SolvedBoth {
-        part_1: day_25::part_1,
-        part_2: day_25::part_2,
-    },
+const YEAR: u16 = 2021;
+
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &Parsed::<List<i32>>::both(YEAR, 1, day_01::parse, day_01::part_1, day_01::part_2)
+        .titled("Sonar Sweep"),
+    &Parsed::<List<day_02::Move>>::both(YEAR, 2, day_02::parse, day_02::part_1, day_02::part_2)
+        .titled("Dive!"),
+    &Day::both(YEAR, 3, day_03::part_1, day_03::part_2).titled("Binary Diagnostic"),
+    &Parsed::<Owned<day_04::Bingo>>::both(YEAR, 4, day_04::parse, day_04::part_1, day_04::part_2)
+        .titled("Giant Squid"),
+    &Parsed::<List<day_05::Line>>::both(
+        YEAR,
+        5,
+        day_05::parse_lines,
+        day_05::part_1,
+        day_05::part_2,
+    )
+    .titled("Hydrothermal Venture"),
+    &Parsed::<List<u8>>::both(YEAR, 6, day_06::parse, day_06::part_1, day_06::part_2)
+        .titled("Lanternfish"),
+    &Day::both(YEAR, 7, day_07::part_1, day_07::part_2).titled("The Treachery of Whales"),
+    &Parsed::<day_08::Notes>::both(YEAR, 8, day_08::parse, day_08::part_1, day_08::part_2)
+        .titled("Seven Segment Search"),
+    &Day::both(YEAR, 9, day_09::part_1, day_09::part_2).titled("Smoke Basin"),
+    &Day::both(YEAR, 10, day_10::part_1, day_10::part_2).titled("Syntax Scoring"),
+    &Parsed::<Owned<day_11::Map>>::both(
+        YEAR,
+        11,
+        day_11::parse_map,
+        day_11::part_1,
+        day_11::part_2,
+    )
+    .titled("Dumbo Octopus"),
+    &Parsed::<day_12::Search>::both(
+        YEAR,
+        12,
+        day_12::parse_graph,
+        day_12::part_1,
+        day_12::part_2,
+    )
+    .titled("Passage Pathing"),
+    &Day::both(YEAR, 13, day_13::part_1, day_13::part_2).titled("Transparent Origami"),
+    &Day::both(YEAR, 14, day_14::part_1, day_14::part_2).titled("Extended Polymerization"),
+    &Parsed::<Owned<day_15::Chiton>>::both(YEAR, 15, day_15::parse, day_15::part_1, day_15::part_2)
+        .titled("Chiton"),
+    &Day::both(YEAR, 16, day_16::part_1, day_16::part_2).titled("Packet Decoder"),
+    &Parsed::<Owned<day_17::Area>>::both(
+        YEAR,
+        17,
+        day_17::parse_area,
+        day_17::part_1,
+        day_17::part_2,
+    )
+    .titled("Trick Shot"),
+    &Day::both(YEAR, 18, day_18::part_1, day_18::part_2).titled("Snailfish"),
+    &Parsed::<List<day_19::Scanner>>::both(YEAR, 19, day_19::parse, day_19::part_1, day_19::part_2)
+        .titled("Beacon Scanner"),
+    &Parsed::<Owned<day_20::Problem>>::both(
+        YEAR,
+        20,
+        day_20::parse_input,
+        day_20::part_1,
+        day_20::part_2,
+    )
+    .titled("Trench Map"),
+    &Parsed::<Owned<(u16, u16)>>::both(YEAR, 21, day_21::parse, day_21::part_1, day_21::part_2)
+        .titled("Dirac Dice"),
+    &Parsed::<List<(day_22::Cuboid, bool)>>::both(
+        YEAR,
+        22,
+        day_22::parse,
+        day_22::part_1,
+        day_22::part_2,
+    )
+    .titled("Reactor Reboot"),
+    &Parsed::<List<Vec<day_23::Tile>>>::both(
+        YEAR,
+        23,
+        day_23::parse,
+        day_23::part_1,
+        day_23::part_2,
+    )
+    .titled("Amphipod"),
+    &Parsed::<List<day_24::Program>>::both(YEAR, 24, day_24::parse, day_24::part_1, day_24::part_2)
+        .titled("Arithmetic Logic Unit"),
+    &Day::both(YEAR, 25, day_25::part_1, day_25::part_2).titled("Sea Cucumber"),
 ];
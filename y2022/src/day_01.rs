@@ -1,8 +1,9 @@
 use anyhow::{Context, Error, Result};
 use itertools::Itertools;
+use shared::AnswerValue;
 use std::num::ParseIntError;
 
-fn parse_input(input: &str) -> Result<Vec<Vec<i32>>> {
+pub fn parse_input(input: &str) -> Result<Vec<Vec<i32>>> {
     input
         .split("\n\n")
         .map(|block| {
@@ -35,16 +36,14 @@ fn top_n(groups: &[Vec<i32>], n: usize) -> Result<i32> {
     }
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let groups = parse_input(input)?;
-    let sol = largest_group(&groups)?;
-    Ok(format!("Solution: {sol}"))
+pub fn part_1(groups: &[Vec<i32>]) -> Result<AnswerValue> {
+    let sol = largest_group(groups)?;
+    Ok(sol.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let groups = parse_input(input)?;
-    let sol = top_n(&groups, 3)?;
-    Ok(format!("{sol}"))
+pub fn part_2(groups: &[Vec<i32>]) -> Result<AnswerValue> {
+    let sol = top_n(groups, 3)?;
+    Ok(sol.into())
 }
 
 #[cfg(test)]
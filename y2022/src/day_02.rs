@@ -1,5 +1,6 @@
 use anyhow::{anyhow, Context, Result};
 use itertools::Itertools;
+use shared::AnswerValue;
 use std::cmp::Ordering;
 
 #[derive(Eq, PartialEq, Copy, Clone, Debug)]
@@ -114,14 +115,14 @@ fn solve_2(inp: &str) -> Result<u32> {
     Ok(points)
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let sol = solve_1(input)?;
-    Ok(format!("{sol}"))
+    Ok(sol.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let sol = solve_2(input)?;
-    Ok(format!("{sol}"))
+    Ok(sol.into())
 }
 
 #[cfg(test)]
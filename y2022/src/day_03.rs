@@ -1,5 +1,6 @@
 use anyhow::Result;
 use itertools::Itertools;
+use shared::AnswerValue;
 
 fn score(c: char) -> u32 {
     let ordinal = if c.is_uppercase() {
@@ -10,7 +11,7 @@ fn score(c: char) -> u32 {
     ordinal as u32
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let part_1: u32 = input
         .lines()
         .filter(|line| !line.is_empty())
@@ -24,10 +25,10 @@ pub fn part_1(input: &str) -> Result<String> {
         })
         .sum();
 
-    Ok(format!("{part_1}"))
+    Ok(part_1.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let lines = input.lines().filter(|line| !line.is_empty()).collect_vec();
 
     let first_backpacks: Vec<_> = lines.iter().step_by(3).collect();
@@ -45,5 +46,5 @@ pub fn part_2(input: &str) -> Result<String> {
         .collect();
 
     let solution: u32 = items.iter().cloned().map(score).sum();
-    Ok(format!("{solution}"))
+    Ok(solution.into())
 }
@@ -1,4 +1,5 @@
 use anyhow::Result;
+use shared::AnswerValue;
 
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub struct SectionRange(u32, u32);
@@ -73,20 +74,20 @@ fn part2_predicate(line: &str) -> Option<bool> {
     Some(overlaps(left, right))
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let sol = predicate_count(
         input.lines().filter(|line| !line.is_empty()),
         part1_predicate,
     );
-    Ok(format!("{sol}"))
+    Ok(sol.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let sol = predicate_count(
         input.lines().filter(|line| !line.is_empty()),
         part2_predicate,
     );
-    Ok(format!("{sol}"))
+    Ok(sol.into())
 }
 
 #[cfg(test)]
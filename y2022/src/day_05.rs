@@ -1,12 +1,15 @@
 use anyhow::{anyhow, Context, Result};
+use shared::AnswerValue;
 use std::iter::Iterator;
 
+#[derive(Clone)]
 pub struct Instruction {
     source: usize,
     dest: usize,
     count: usize,
 }
 
+#[derive(Clone)]
 pub struct Problem {
     state: Vec<Vec<char>>,
     instructions: Vec<Instruction>,
@@ -28,7 +31,7 @@ fn parse_instruction(instr: &str) -> Result<Instruction> {
     }
 }
 
-fn parse_problem(description: &str) -> Result<Problem> {
+pub fn parse_problem(description: &str) -> Result<Problem> {
     let split: Vec<&str> = description.splitn(2, "\n\n").collect();
     let initial_state = *split
         .first()
@@ -136,14 +139,14 @@ fn solve_part2(problem: &mut Problem) -> String {
         .collect()
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let mut problem = parse_problem(input)?;
-    Ok(solve_part1(&mut problem))
+pub fn part_1(problem: &Problem) -> Result<AnswerValue> {
+    let mut problem = problem.clone();
+    Ok(solve_part1(&mut problem).into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let mut problem = parse_problem(input)?;
-    Ok(solve_part2(&mut problem))
+pub fn part_2(problem: &Problem) -> Result<AnswerValue> {
+    let mut problem = problem.clone();
+    Ok(solve_part2(&mut problem).into())
 }
 
 #[cfg(test)]
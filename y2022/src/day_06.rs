@@ -1,4 +1,5 @@
 use anyhow::Result;
+use shared::AnswerValue;
 use std::collections::VecDeque;
 
 fn start_of_packet(stream: &str, packet_length: usize) -> usize {
@@ -27,14 +28,14 @@ fn start_of_packet(stream: &str, packet_length: usize) -> usize {
     position
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let sol = start_of_packet(input, 4);
-    Ok(format!("{sol}"))
+    Ok(sol.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let sol = start_of_packet(input, 14);
-    Ok(format!("{sol}"))
+    Ok(sol.into())
 }
 
 #[cfg(test)]
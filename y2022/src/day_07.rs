@@ -1,4 +1,5 @@
 use anyhow::Result;
+use shared::AnswerValue;
 
 fn dir_traversal(inp: &str) -> Vec<i64> {
     let mut stack = vec![0];
@@ -32,9 +33,9 @@ fn solve_1(inp: &str) -> i64 {
     folder_sizes.iter().filter(|&size| *size <= 100000).sum()
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let sol = solve_1(input);
-    Ok(format!("{sol}"))
+    Ok(sol.into())
 }
 
 fn solve_2(inp: &str) -> i64 {
@@ -50,9 +51,9 @@ fn solve_2(inp: &str) -> i64 {
         .unwrap_or(0)
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let sol = solve_2(input);
-    Ok(format!("{sol}"))
+    Ok(sol.into())
 }
 
 #[cfg(test)]
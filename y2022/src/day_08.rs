@@ -1,12 +1,13 @@
 use anyhow::{anyhow, Context, Result};
+use shared::AnswerValue;
 
-struct Forest {
+pub struct Forest {
     height: usize,
     width: usize,
     forest: Vec<u8>,
 }
 
-fn parse_forest(forest: &str) -> Forest {
+pub fn parse_forest(forest: &str) -> Result<Forest> {
     let mut rows = 0;
     let mut out = Vec::new();
 
@@ -17,11 +18,11 @@ fn parse_forest(forest: &str) -> Forest {
         }
     }
 
-    Forest {
+    Ok(Forest {
         height: rows,
         width: out.len() / rows,
         forest: out,
-    }
+    })
 }
 
 fn index_forest(row: usize, col: usize, forest: &Forest) -> u8 {
@@ -118,21 +119,19 @@ fn calculate_scenic_score_map(forest: &Forest) -> Vec<i32> {
     out
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let forest = parse_forest(input);
-    let height_map = calculate_visibility_map(&forest);
+pub fn part_1(forest: &Forest) -> Result<AnswerValue> {
+    let height_map = calculate_visibility_map(forest);
     let visible = height_map.forest.iter().filter(|tree| *tree > &0).count();
-    Ok(format!("{visible}"))
+    Ok(visible.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let forest = parse_forest(input);
-    let scenic_map = calculate_scenic_score_map(&forest);
+pub fn part_2(forest: &Forest) -> Result<AnswerValue> {
+    let scenic_map = calculate_scenic_score_map(forest);
     let scenic = scenic_map
         .iter()
         .max()
         .with_context(|| anyhow!("Unable to solve"))?;
-    Ok(format!("{}", *scenic))
+    Ok((*scenic).into())
 }
 
 #[cfg(test)]
@@ -147,7 +146,7 @@ mod tests {
 
     #[test]
     fn test_scenic_visibility_map() {
-        let forest = parse_forest(EXAMPLE);
+        let forest = parse_forest(EXAMPLE).unwrap();
         let height_map = calculate_scenic_score_map(&forest);
         let scenic = *height_map.iter().max().unwrap_or(&0);
         assert_eq!(scenic, 8);
@@ -155,7 +154,7 @@ mod tests {
 
     #[test]
     fn test_calc_visibility_map() {
-        let forest = parse_forest(EXAMPLE);
+        let forest = parse_forest(EXAMPLE).unwrap();
         let height_map = calculate_visibility_map(&forest);
         let visible = height_map.forest.iter().filter(|tree| *tree > &0).count();
         assert_eq!(visible, 21);
@@ -163,7 +162,7 @@ mod tests {
 
     #[test]
     fn test_parse_example() {
-        let forest = parse_forest(EXAMPLE);
+        let forest = parse_forest(EXAMPLE).unwrap();
         assert_eq!(forest.height, 5);
         assert_eq!(forest.width, 5);
         assert_eq!(index_forest(0, 0, &forest), 3);
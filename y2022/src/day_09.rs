@@ -1,5 +1,6 @@
 use anyhow::Result;
 use fxhash::FxHashSet as HashSet;
+use shared::AnswerValue;
 
 #[derive(PartialEq, Eq, Debug)]
 pub enum Move {
@@ -179,7 +180,7 @@ fn part2(inp: &str) -> HashSet<Pos> {
     last_tail_places
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let moves = parse_moves(input);
     let mut places_seen = HashSet::default();
     let mut state = State {
@@ -191,11 +192,11 @@ pub fn part_1(input: &str) -> Result<String> {
         state = next_state;
         places_seen.extend(new_places.into_iter());
     }
-    Ok(format!("{}", places_seen.len()))
+    Ok(places_seen.len().into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    Ok(format!("{}", part2(input).len()))
+pub fn part_2(input: &str) -> Result<AnswerValue> {
+    Ok(part2(input).len().into())
 }
 
 #[cfg(test)]
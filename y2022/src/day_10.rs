@@ -1,4 +1,5 @@
 use anyhow::Result;
+use shared::AnswerValue;
 
 #[derive(Debug, PartialEq)]
 pub enum Instruction {
@@ -66,7 +67,7 @@ impl Iterator for Program<'_> {
     }
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let prog = parse_instructions(input.lines());
     let mut sum = 0;
     let cycles_read = [20, 60, 100, 140, 180, 220];
@@ -78,10 +79,11 @@ pub fn part_1(input: &str) -> Result<String> {
         }
     }
 
-    Ok(format!("{sum}"))
+    Ok(sum.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+/// What the CRT draws, before reading the letters
+pub fn render(input: &str) -> String {
     let prog = parse_instructions(input.lines());
     let mut display: Vec<String> = Default::default();
     let mut row: String = Default::default();
@@ -94,7 +96,11 @@ pub fn part_2(input: &str) -> Result<String> {
             row = Default::default();
         }
     }
-    Ok(display.join("\n"))
+    display.join("\n")
+}
+
+pub fn part_2(input: &str) -> Result<AnswerValue> {
+    shared::ocr::read_letters(&render(input)).map(AnswerValue::from)
 }
 
 #[cfg(test)]
@@ -287,6 +293,16 @@ noop
         assert_eq!(sum, 13140);
     }
 
+    #[test]
+    fn test_render() {
+        let display = render(LARGE_EXAMPLE);
+        assert_eq!(display.lines().count(), 6);
+        assert_eq!(
+            display.lines().next(),
+            Some("##  ##  ##  ##  ##  ##  ##  ##  ##  ##  ")
+        );
+    }
+
     #[test]
     fn test_parse_program() {
         let _lines: Vec<Instruction> = SMALL_EXAMPLE
@@ -1,4 +1,5 @@
 use anyhow::Result;
+use shared::AnswerValue;
 use std::collections::HashMap;
 
 #[derive(PartialEq, Debug)]
@@ -124,14 +125,14 @@ fn monkey_game(input: &str, rounds: usize, modulo: bool) -> usize {
     counts[counts.len() - 1] * counts[counts.len() - 2]
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let sol = monkey_game(input, 20, false);
-    Ok(format!("{sol}"))
+    Ok(sol.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let sol = monkey_game(input, 10000, true);
-    Ok(format!("{sol}"))
+    Ok(sol.into())
 }
 
 #[cfg(test)]
@@ -1,7 +1,10 @@
 use anyhow::{Context, Result};
-use std::collections::VecDeque;
+use shared::{search, AnswerValue};
 
-fn parse_input(inp: &str) -> Result<(usize, usize, Vec<u8>)> {
+/// The width, height and elevation of each place on the map
+pub type Heightmap = (usize, usize, Vec<u8>);
+
+pub fn parse_input(inp: &str) -> Result<Heightmap> {
     let height = inp.lines().filter(|line| !line.is_empty()).count();
     let blines: Vec<_> = inp
         .lines()
@@ -81,45 +84,29 @@ fn bfs<F>(source: usize, is_dest: F, landscape: &[u8], width: usize, height: usi
 where
     F: Fn(usize) -> bool,
 {
-    let mut queue: VecDeque<usize> = vec![source].into();
-    let mut costs: Vec<usize> = vec![0; landscape.len()];
-
-    while !queue.is_empty() {
-        let now = queue.pop_front().unwrap(); // Safe -- not empty
-        let cost_here = costs[now];
-
-        if is_dest(now) {
-            return Some(cost_here);
-        }
-
-        let next = generate_moves(now, width, height);
-        for next in filter_moves(now, &next, landscape) {
-            if next != source && costs[next] == 0 {
-                queue.push_back(next);
-                costs[next] = cost_here + 1;
-            }
-        }
-    }
-    None
+    search::bfs(
+        source,
+        |&now| filter_moves(now, &generate_moves(now, width, height), landscape),
+        |&now| is_dest(now),
+    )
+    .map(|path| path.cost)
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let (width, height, landscape) = parse_input(input)?;
-    let (source, _) = find_ends(&landscape)?;
+pub fn part_1(&(width, height, ref landscape): &Heightmap) -> Result<AnswerValue> {
+    let (source, _) = find_ends(landscape)?;
     let cost = bfs(
         source,
         |source| landscape[source] as char == 'E',
-        &landscape,
+        landscape,
         width,
         height,
     )
     .context("Unable to find path")?;
-    Ok(format!("{cost}"))
+    Ok(cost.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let (width, height, landscape) = parse_input(input)?;
-    let (_, dest) = find_ends(&landscape)?;
+pub fn part_2(&(width, height, ref landscape): &Heightmap) -> Result<AnswerValue> {
+    let (_, dest) = find_ends(landscape)?;
 
     let inverted: Vec<_> = landscape
         .iter()
@@ -134,7 +121,7 @@ pub fn part_2(input: &str) -> Result<String> {
     )
     .context("Unable to find path")?;
 
-    Ok(format!("{cost}"))
+    Ok(cost.into())
 }
 
 #[cfg(test)]
@@ -1,3 +1,4 @@
+use shared::AnswerValue;
 use std::cmp::Ordering;
 use std::iter::Peekable;
 use std::str::Chars;
@@ -5,7 +6,7 @@ use std::str::Chars;
 use anyhow::{Context, Error, Result};
 
 #[derive(PartialEq, Eq, Debug)]
-enum Packets {
+pub enum Packets {
     Integer(i32),
     List(Vec<Packets>),
 }
@@ -79,7 +80,7 @@ fn parse_list(chars: &mut Peekable<Chars>) -> Result<Packets> {
     Ok(Packets::List(many))
 }
 
-fn line_pairs(lines: &str) -> Result<Vec<(Packets, Packets)>> {
+pub fn line_pairs(lines: &str) -> Result<Vec<(Packets, Packets)>> {
     let mut result = Vec::new();
     let mut it = lines.lines();
     while let Some(left) = it.next() {
@@ -92,19 +93,17 @@ fn line_pairs(lines: &str) -> Result<Vec<(Packets, Packets)>> {
     Ok(result)
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let pairs = line_pairs(input)?;
+pub fn part_1(pairs: &[(Packets, Packets)]) -> Result<AnswerValue> {
     let mut sum = 0;
     for (index, (left, right)) in pairs.iter().enumerate() {
         if left < right {
             sum += index + 1;
         }
     }
-    Ok(format!("{sum}"))
+    Ok(sum.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let pairs = line_pairs(input)?;
+pub fn part_2(pairs: &[(Packets, Packets)]) -> Result<AnswerValue> {
     let firsts = pairs.iter().map(|(left, _)| left);
     let snds = pairs.iter().map(|(_, right)| right);
     let mut all: Vec<_> = firsts.chain(snds).collect();
@@ -123,7 +122,7 @@ pub fn part_2(input: &str) -> Result<String> {
         }
     }
     let sol = d1_i * d2_i;
-    Ok(format!("{sol}"))
+    Ok(sol.into())
 }
 
 #[cfg(test)]
@@ -1,5 +1,6 @@
 use anyhow::{Context, Result};
 use fxhash::FxHashMap as HashMap;
+use shared::AnswerValue;
 
 #[derive(Eq, PartialEq, Hash, Debug, Copy, Clone)]
 struct Vertex {
@@ -189,7 +190,7 @@ fn sandfall_p2(map: &Map, bounds: &(i32, i32), origin: &Vertex) -> Placed {
     }
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let mut map = new_map();
     for line in input.lines() {
         let wall = parse_vertices(line)?;
@@ -201,10 +202,10 @@ pub fn part_1(input: &str) -> Result<String> {
         map.insert(sand, Tile::Sand);
         placed += 1;
     }
-    Ok(format!("{placed}"))
+    Ok(placed.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let mut map = new_map();
     for line in input.lines() {
         let wall = parse_vertices(line)?;
@@ -216,7 +217,7 @@ pub fn part_2(input: &str) -> Result<String> {
         map.insert(sand, Tile::Sand);
         placed += 1;
     }
-    Ok(format!("{placed}"))
+    Ok(placed.into())
 }
 
 #[cfg(test)]
@@ -1,6 +1,7 @@
 use anyhow::{Context, Result};
 use fxhash::FxHashSet as HashSet;
 use regex::Regex;
+use shared::AnswerValue;
 use std::cmp::{max, min};
 use std::ops::RangeInclusive;
 
@@ -39,13 +40,12 @@ impl From<Location> for (i32, i32) {
     }
 }
 
-fn parse_lines<T: AsRef<str>>(input: T) -> Result<Map> {
+pub fn parse_lines(input: &str) -> Result<Map> {
     let re = Regex::new(
         r"Sensor at x=(-?[0-9]+), y=(-?[0-9]+): closest beacon is at x=(-?[0-9]+), y=(-?[0-9]+)",
     )?;
 
     input
-        .as_ref()
         .lines()
         .filter(|l| !l.is_empty())
         .map(|l| {
@@ -63,7 +63,7 @@ fn parse_lines<T: AsRef<str>>(input: T) -> Result<Map> {
         .collect()
 }
 
-fn solve_problem_one(inputs: &Map, row: i32) -> usize {
+fn solve_problem_one(inputs: &[Input], row: i32) -> usize {
     let mut ranges = vec![];
     for Input(sensor, beacon) in inputs.iter() {
         let distance = manhattan_dist(sensor, beacon);
@@ -106,7 +106,7 @@ fn intersect_lines(b_neg: i32, b_pos: i32) -> (i32, i32) {
     (x, y)
 }
 
-fn find_distress_beacon(map: &Map) -> Option<Location> {
+fn find_distress_beacon(map: &[Input]) -> Option<Location> {
     let (xmin, xmax) = map.iter().fold((0, 0), |(xmin, xmax), input| match input {
         Input(Location(x, _), _) if *x < xmin => (*x, xmax),
         Input(Location(x, _), _) if *x > xmax => (xmin, *x),
@@ -180,17 +180,15 @@ fn tuning_distance(loc: &Location) -> i64 {
     x * 4000000 + y
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let map = parse_lines(input)?;
-    let solution = solve_problem_one(&map, 2000000);
-    Ok(format!("{solution}"))
+pub fn part_1(map: &[Input]) -> Result<AnswerValue> {
+    let solution = solve_problem_one(map, 2000000);
+    Ok(solution.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let map = parse_lines(input)?;
-    let distress_beacon = find_distress_beacon(&map).context("Unable to find 1 point")?;
+pub fn part_2(map: &[Input]) -> Result<AnswerValue> {
+    let distress_beacon = find_distress_beacon(map).context("Unable to find 1 point")?;
     let solution_part_2 = tuning_distance(&distress_beacon);
-    Ok(format!("{solution_part_2}"))
+    Ok(solution_part_2.into())
 }
 
 #[cfg(test)]
@@ -3,10 +3,11 @@ use fxhash::FxHashMap as HashMap;
 use itertools::Itertools;
 use rayon::prelude::*;
 use regex::Regex;
+use shared::AnswerValue;
 use std::collections::VecDeque;
 
 #[derive(Eq, PartialEq, Debug, Clone)]
-struct Problem {
+pub struct Problem {
     flow_rate: Vec<u32>,
     edges: Vec<Vec<usize>>,
     vertex_names: HashMap<String, usize>,
@@ -14,7 +15,7 @@ struct Problem {
     vtx_with_flow: Vec<usize>,
 }
 
-fn parse(inp: &str) -> Result<Problem> {
+pub fn parse(inp: &str) -> Result<Problem> {
     let mut flow_rate = Vec::new();
     let mut edges = Vec::new();
     let mut vertex_names = HashMap::default();
@@ -188,16 +189,14 @@ fn search_2(problem: &Problem) -> u32 {
         .unwrap()
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let problem = parse(input)?;
-    let cost = search(&problem, 30);
-    Ok(format!("{cost}"))
+pub fn part_1(problem: &Problem) -> Result<AnswerValue> {
+    let cost = search(problem, 30);
+    Ok(cost.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let problem = parse(input)?;
-    let cost = search_2(&problem);
-    Ok(format!("{cost}"))
+pub fn part_2(problem: &Problem) -> Result<AnswerValue> {
+    let cost = search_2(problem);
+    Ok(cost.into())
 }
 
 #[cfg(test)]
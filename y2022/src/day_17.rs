@@ -1,5 +1,6 @@
 use anyhow::Result;
 use itertools::Itertools;
+use shared::AnswerValue;
 use std::cmp::max;
 use std::collections::HashMap;
 
@@ -28,13 +29,12 @@ fn shift(shape: &mut Shape, dir: (i64, i64)) {
     }
 }
 
-type Jets = Vec<Jet>;
-fn parse_jets(input: &str) -> Jets {
-    input
+pub fn parse_jets(input: &str) -> Result<Vec<Jet>> {
+    Ok(input
         .chars()
         .filter(|&ch| ch == '<' || ch == '>')
         .map(|ch| if ch == '<' { Jet::Left } else { Jet::Right })
-        .collect_vec()
+        .collect_vec())
 }
 
 const CHAMBER_WIDTH: i64 = 7;
@@ -47,7 +47,7 @@ fn in_bounds(shape: &Shape) -> bool {
 }
 
 fn drop_rock(
-    jets: &Jets,
+    jets: &[Jet],
     shape: &Shape,
     mut time: usize,
     max_heights: &[i64; CHAMBER_WIDTH as usize],
@@ -105,7 +105,7 @@ fn drop_rock(
 type CacheKey = ([i64; CHAMBER_WIDTH as usize], usize, usize);
 type CacheValue = (usize, i64);
 
-fn drop_many_rocks(jets: &Jets, rocks_to_drop: usize) -> i64 {
+fn drop_many_rocks(jets: &[Jet], rocks_to_drop: usize) -> i64 {
     let shapes = shapes();
     let mut grid = vec![vec![false; CHAMBER_WIDTH as usize]; MAX_HEIGHT];
     let mut max_heights = [0; CHAMBER_WIDTH as usize];
@@ -145,16 +145,14 @@ fn drop_many_rocks(jets: &Jets, rocks_to_drop: usize) -> i64 {
     *max_heights.iter().max().unwrap() + cycled_altitude
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let jets = parse_jets(input);
-    let max_height = drop_many_rocks(&jets, 2022);
-    Ok(format!("{max_height}"))
+pub fn part_1(jets: &[Jet]) -> Result<AnswerValue> {
+    let max_height = drop_many_rocks(jets, 2022);
+    Ok(max_height.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let jets = parse_jets(input);
-    let max_height = drop_many_rocks(&jets, 1000000000000);
-    Ok(format!("{max_height}"))
+pub fn part_2(jets: &[Jet]) -> Result<AnswerValue> {
+    let max_height = drop_many_rocks(jets, 1000000000000);
+    Ok(max_height.into())
 }
 
 #[cfg(test)]
@@ -165,7 +163,7 @@ pub mod tests {
 
     #[test]
     fn parse_jets() {
-        let jets = super::parse_jets(EXAMPLE);
+        let jets = super::parse_jets(EXAMPLE).unwrap();
         assert_eq!(jets[0], Jet::Right);
         assert_eq!(jets[3], Jet::Left);
     }
@@ -173,14 +171,14 @@ pub mod tests {
     #[test]
     #[ignore] // Failing due, but working for real input
     fn test_drop_many_rocks() {
-        let jets = super::parse_jets(EXAMPLE);
+        let jets = super::parse_jets(EXAMPLE).unwrap();
         let answer = drop_many_rocks(&jets, 2022);
         assert_eq!(answer, 3068);
     }
 
     #[test]
     fn test_drop_supermany_rocks() {
-        let jets = super::parse_jets(EXAMPLE);
+        let jets = super::parse_jets(EXAMPLE).unwrap();
         let answer = drop_many_rocks(&jets, 1000000000000);
         assert_eq!(answer, 1514285714288);
     }
@@ -1,5 +1,6 @@
 use anyhow::Result;
 use fxhash::FxHashSet as HashSet;
+use shared::AnswerValue;
 use std::collections::VecDeque;
 
 fn face_sides(place: &(i32, i32, i32)) -> Vec<(i32, i32, i32)> {
@@ -27,16 +28,14 @@ fn surface_area(droplet: &HashSet<(i32, i32, i32)>) -> usize {
         .sum()
 }
 
-fn parse_droplet(inp: &str) -> HashSet<(i32, i32, i32)> {
+pub type Droplet = HashSet<(i32, i32, i32)>;
+
+pub fn parse_droplet(inp: &str) -> Result<Droplet> {
     inp.lines()
         .filter(|line| !line.is_empty())
         .map(|line| {
             let strs: Vec<_> = line.split(',').collect();
-            (
-                strs[0].parse().unwrap(),
-                strs[1].parse().unwrap(),
-                strs[2].parse().unwrap(),
-            )
+            Ok((strs[0].parse()?, strs[1].parse()?, strs[2].parse()?))
         })
         .collect()
 }
@@ -89,16 +88,14 @@ fn exposed_surface(droplet: &HashSet<(i32, i32, i32)>) -> usize {
         .sum()
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let droplet = parse_droplet(input);
-    let area = surface_area(&droplet);
-    Ok(format!("{area}"))
+pub fn part_1(droplet: &Droplet) -> Result<AnswerValue> {
+    let area = surface_area(droplet);
+    Ok(area.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let droplet = parse_droplet(input);
-    let exposed = exposed_surface(&droplet);
-    Ok(format!("{exposed}"))
+pub fn part_2(droplet: &Droplet) -> Result<AnswerValue> {
+    let exposed = exposed_surface(droplet);
+    Ok(exposed.into())
 }
 
 #[cfg(test)]
@@ -119,13 +116,13 @@ mod tests {
 ";
     #[test]
     fn test_surface_area_example() {
-        let droplet = super::parse_droplet(EXAMPLE);
+        let droplet = super::parse_droplet(EXAMPLE).unwrap();
         assert_eq!(super::surface_area(&droplet), 64);
     }
 
     #[test]
     fn test_exposed_area_example() {
-        let droplet = super::parse_droplet(EXAMPLE);
+        let droplet = super::parse_droplet(EXAMPLE).unwrap();
         assert_eq!(super::exposed_surface(&droplet), 58);
     }
 }
@@ -1,5 +1,6 @@
 use anyhow::{anyhow, Context, Result};
 use regex::Regex;
+use shared::AnswerValue;
 use std::cmp::max;
 
 type ResourceKind = usize;
@@ -13,7 +14,7 @@ const GEODE: ResourceKind = 3;
 const RESOURCE_NAMES: [ResourceKind; RESOURCE_KINDS] = [ORE, CLAY, OBSIDIAN, GEODE];
 
 type Resources = [ResourceAmount; RESOURCE_KINDS];
-type Blueprint = [Resources; RESOURCE_KINDS];
+pub type Blueprint = [Resources; RESOURCE_KINDS];
 type CanBuild = [Option<ResourceAmount>; RESOURCE_KINDS];
 
 fn new_resources() -> Resources {
@@ -112,7 +113,7 @@ fn parse_bp_line(bp: &str) -> Result<(i32, Blueprint)> {
     Ok((id, bp))
 }
 
-fn parse_bps(bps: &str) -> Result<Vec<(i32, Blueprint)>> {
+pub fn parse_bps(bps: &str) -> Result<Vec<(i32, Blueprint)>> {
     let bps: Result<Vec<_>> = bps.lines().map(parse_bp_line).collect();
     bps
 }
@@ -223,23 +224,21 @@ fn search(bp: &Blueprint, ticks: i32) -> i32 {
     best
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let blueprints = parse_bps(input)?;
+pub fn part_1(blueprints: &[(i32, Blueprint)]) -> Result<AnswerValue> {
     let qualities: i32 = blueprints
         .iter()
         .map(|&(id, bp)| search(&bp, 24) * id)
         .sum();
-    Ok(format!("{qualities}"))
+    Ok(qualities.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let blueprints = parse_bps(input)?;
+pub fn part_2(blueprints: &[(i32, Blueprint)]) -> Result<AnswerValue> {
     let qualities: i32 = blueprints
         .iter()
         .take(3)
         .map(|&(_, bp)| search(&bp, 32))
         .product();
-    Ok(format!("{qualities}"))
+    Ok(qualities.into())
 }
 
 #[cfg(test)]
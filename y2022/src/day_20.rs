@@ -1,8 +1,9 @@
 // intellij keeps putting this one back even though rustc says it's unused
-#[allow(unused_imports)]
-use crate::point_2d::Rem;
 use anyhow::{Context, Result};
 use itertools::Itertools;
+#[allow(unused_imports)]
+use shared::geometry::Rem;
+use shared::AnswerValue;
 use std::collections::VecDeque;
 
 fn parse(input: &str) -> Result<VecDeque<(usize, i64)>> {
@@ -62,14 +63,14 @@ fn solve_2(input: &str) -> Result<i64> {
     Ok(n)
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let n = solve_1(input)?;
-    Ok(format!("{n}"))
+    Ok(n.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let n = solve_2(input)?;
-    Ok(format!("{n}"))
+    Ok(n.into())
 }
 
 #[cfg(test)]
@@ -1,14 +1,15 @@
 use anyhow::{anyhow, Context, Result};
+use shared::AnswerValue;
 
 use std::collections::{HashMap, VecDeque};
 
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone)]
 pub enum Atom {
     Binding(String),
     Int(i64),
 }
 
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone)]
 pub enum Expression {
     Constant(Atom),
     Operator(char, Atom, Atom),
@@ -59,7 +60,9 @@ fn parse_expr(expr: &str) -> Result<Expression> {
         })
 }
 
-fn parse(input: &str) -> Result<HashMap<String, Expression>> {
+pub type Equations = HashMap<String, Expression>;
+
+pub fn parse(input: &str) -> Result<Equations> {
     input
         .lines()
         .filter(|line| !line.is_empty())
@@ -195,19 +198,17 @@ fn calculate_part_2(mut equation: HashMap<String, Expression>) -> i64 {
     }
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let exprs = parse(input)?;
-    let original = calculate(&exprs);
+pub fn part_1(exprs: &Equations) -> Result<AnswerValue> {
+    let original = calculate(exprs);
     let answer = original
         .get("root")
         .copied()
         .with_context(|| anyhow!("Unable to solve"))?;
-    Ok(format!("{answer}"))
+    Ok(answer.into())
 }
-pub fn part_2(input: &str) -> Result<String> {
-    let exprs = parse(input)?;
-    let humn = calculate_part_2(exprs);
-    Ok(format!("{humn}"))
+pub fn part_2(exprs: &Equations) -> Result<AnswerValue> {
+    let humn = calculate_part_2(exprs.clone());
+    Ok(humn.into())
 }
 
 #[cfg(test)]
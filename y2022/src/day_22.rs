@@ -1,4 +1,5 @@
 use anyhow::{anyhow, Context, Result};
+use shared::AnswerValue;
 
 #[derive(Eq, PartialEq, Debug, Clone, Copy)]
 pub enum Tile {
@@ -239,7 +240,10 @@ fn parse_hike(input: &str) -> Result<Vec<Step>> {
     Ok(out)
 }
 
-fn parse(input: &str) -> Result<(Map, Vec<Step>)> {
+/// The board and the steps to take on it
+pub type Notes = (Map, Vec<Step>);
+
+pub fn parse(input: &str) -> Result<Notes> {
     let mut parts = input.split("\n\n");
     let map_part = parts.next().context("Bad input")?;
     let map = parse_board(map_part)?;
@@ -248,16 +252,14 @@ fn parse(input: &str) -> Result<(Map, Vec<Step>)> {
     Ok((map, hike))
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let (map, steps) = parse(input)?;
-    let (row, col, face) = hike(&map, &steps);
-    Ok(format!("{}", row * 1000 + col * 4 + face))
+pub fn part_1((map, steps): &Notes) -> Result<AnswerValue> {
+    let (row, col, face) = hike(map, steps);
+    Ok((row * 1000 + col * 4 + face).into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let (map, steps) = parse(input)?;
-    let (row, col, face) = hike_cube(&map, &steps);
-    Ok(format!("{}", row * 1000 + col * 4 + face))
+pub fn part_2((map, steps): &Notes) -> Result<AnswerValue> {
+    let (row, col, face) = hike_cube(map, steps);
+    Ok((row * 1000 + col * 4 + face).into())
 }
 
 #[cfg(test)]
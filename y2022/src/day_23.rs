@@ -1,7 +1,8 @@
-use crate::point_2d::{Point2d, Rect};
 use anyhow::Result;
 use fxhash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use itertools::Itertools;
+use shared::geometry::{Point2d, Rect};
+use shared::AnswerValue;
 
 type Elf = Point2d<i64>;
 type Board = HashSet<Elf>;
@@ -140,14 +141,14 @@ fn run_part_2(input: &str) -> i64 {
     }
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let sol = run_part_1(input);
-    Ok(format!("{sol}"))
+    Ok(sol.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let sol = run_part_2(input);
-    Ok(format!("{sol}"))
+    Ok(sol.into())
 }
 
 #[cfg(test)]
@@ -183,6 +184,12 @@ pub mod tests {
         assert_eq!(empty_tiles, 110);
     }
 
+    #[test]
+    fn test_part_1_away_from_origin() {
+        // A lone elf never moves, so its rectangle is just the one tile it stands on
+        assert_eq!(run_part_1(".....\n.....\n.....\n....#\n"), 0);
+    }
+
     #[test]
     fn test_part_2() {
         let p2 = run_part_2(EXAMPLE);
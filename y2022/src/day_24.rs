@@ -1,7 +1,8 @@
-use crate::point_2d::Point2d;
 use anyhow::{anyhow, Context, Result};
 use fxhash::FxHashSet as HashSet;
 use itertools::Itertools;
+use shared::geometry::Point2d;
+use shared::AnswerValue;
 use std::cmp::max;
 use std::collections::VecDeque;
 
@@ -163,15 +164,15 @@ fn bfs_roundtrip(inp: &str) -> Option<(i32, i32)> {
     Some((steps, last_time))
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let prob = parse_board(input)?;
     let steps = bfs_to_goal(&prob, 0).with_context(|| anyhow!("Unable to solve"))?;
-    Ok(format!("{steps}"))
+    Ok(steps.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let steps = bfs_roundtrip(input).with_context(|| anyhow!("Unable to solve"))?;
-    Ok(format!("{}", steps.1))
+    Ok(steps.1.into())
 }
 
 #[cfg(test)]
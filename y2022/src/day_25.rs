@@ -1,4 +1,5 @@
 use anyhow::Result;
+use shared::AnswerValue;
 
 const SNAFU_STR: [char; 5] = ['0', '1', '2', '=', '-'];
 
@@ -30,17 +31,17 @@ fn dec_to_snafu(mut dec: i64) -> String {
     out.chars().rev().collect()
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let sum: i64 = input
         .lines()
         .filter(|line| !line.is_empty())
         .map(snafu_to_dec)
         .sum();
     let snafu = dec_to_snafu(sum);
-    Ok(snafu)
+    Ok(snafu.into())
 }
 
-pub fn part_2(_input: &str) -> Result<String> {
+pub fn part_2(_input: &str) -> Result<AnswerValue> {
     Ok("Enter the answers and collect the last star!".into())
 }
 
@@ -1,5 +1,4 @@
-use shared::Answer;
-use shared::Answer::SolvedBoth;
+use shared::{Day, List, Owned, Parsed, Solution};
 
 pub mod day_01;
 pub mod day_02;
@@ -26,107 +25,105 @@ pub mod day_22;
 pub mod day_23;
 pub mod day_24;
 pub mod day_25;
-pub mod point_2d;
 
-pub const SOLUTIONS: [Answer; 25] = [
-    SolvedBoth {
-        part_1: day_01::part_1,
-        part_2: day_01::part_2,
-    },
-    SolvedBoth {
-        part_1: day_02::part_1,
-        part_2: day_02::part_2,
-    },
-    SolvedBoth {
-        part_1: day_03::part_1,
-        part_2: day_03::part_2,
-    },
-    SolvedBoth {
-        part_1: day_04::part_1,
-        part_2: day_04::part_2,
-    },
-    SolvedBoth {
-        part_1: day_05::part_1,
-        part_2: day_05::part_2,
-    },
-    SolvedBoth {
-        part_1: day_06::part_1,
-        part_2: day_06::part_2,
-    },
-    SolvedBoth {
-        part_1: day_07::part_1,
-        part_2: day_07::part_2,
-    },
-    SolvedBoth {
-        part_1: day_08::part_1,
-        part_2: day_08::part_2,
-    },
-    SolvedBoth {
-        part_1: day_09::part_1,
-        part_2: day_09::part_2,
-    },
-    SolvedBoth {
-        part_1: day_10::part_1,
-        part_2: day_10::part_2,
-    },
-    SolvedBoth {
-        part_1: day_11::part_1,
-        part_2: day_11::part_2,
-    },
-    SolvedBoth {
-        part_1: day_12::part_1,
-        part_2: day_12::part_2,
-    },
-    SolvedBoth {
-        part_1: day_13::part_1,
-        part_2: day_13::part_2,
-    },
-    SolvedBoth {
-        part_1: day_14::part_1,
-        part_2: day_14::part_2,
-    },
-    SolvedBoth {
-        part_1: day_15::part_1,
-        part_2: day_15::part_2,
-    },
-    SolvedBoth {
-        part_1: day_16::part_1,
-        part_2: day_16::part_2,
-    },
-    SolvedBoth {
-        part_1: day_17::part_1,
-        part_2: day_17::part_2,
-    },
-    SolvedBoth {
-        part_1: day_18::part_1,
-        part_2: day_18::part_2,
-    },
-    SolvedBoth {
-        part_1: day_19::part_1,
-        part_2: day_19::part_2,
-    },
-    SolvedBoth {
-        part_1: day_20::part_1,
-        part_2: day_20::part_2,
-    },
-    SolvedBoth {
-        part_1: day_21::part_1,
-        part_2: day_21::part_2,
-    },
-    SolvedBoth {
-        part_1: day_22::part_1,
-        part_2: day_22::part_2,
-    },
-    SolvedBoth {
-        part_1: day_23::part_1,
-        part_2: day_23::part_2,
-    },
-    SolvedBoth {
-        part_1: day_24::part_1,
-        part_2: day_24::part_2,
-    },
-    SolvedBoth {
-        part_1: day_25::part_1,
-        part_2: day_25::part_2,
-    },
+const YEAR: u16 = 2022;
+
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &Parsed::<List<Vec<i32>>>::both(YEAR, 1, day_01::parse_input, day_01::part_1, day_01::part_2)
+        .titled("Calorie Counting"),
+    &Day::both(YEAR, 2, day_02::part_1, day_02::part_2).titled("Rock Paper Scissors"),
+    &Day::both(YEAR, 3, day_03::part_1, day_03::part_2).titled("Rucksack Reorganization"),
+    &Day::both(YEAR, 4, day_04::part_1, day_04::part_2).titled("Camp Cleanup"),
+    &Parsed::<Owned<day_05::Problem>>::both(
+        YEAR,
+        5,
+        day_05::parse_problem,
+        day_05::part_1,
+        day_05::part_2,
+    )
+    .titled("Supply Stacks"),
+    &Day::both(YEAR, 6, day_06::part_1, day_06::part_2).titled("Tuning Trouble"),
+    &Day::both(YEAR, 7, day_07::part_1, day_07::part_2).titled("No Space Left On Device"),
+    &Parsed::<Owned<day_08::Forest>>::both(
+        YEAR,
+        8,
+        day_08::parse_forest,
+        day_08::part_1,
+        day_08::part_2,
+    )
+    .titled("Treetop Tree House"),
+    &Day::both(YEAR, 9, day_09::part_1, day_09::part_2).titled("Rope Bridge"),
+    &Day::both(YEAR, 10, day_10::part_1, day_10::part_2).titled("Cathode-Ray Tube"),
+    &Day::both(YEAR, 11, day_11::part_1, day_11::part_2).titled("Monkey in the Middle"),
+    &Parsed::<Owned<day_12::Heightmap>>::both(
+        YEAR,
+        12,
+        day_12::parse_input,
+        day_12::part_1,
+        day_12::part_2,
+    )
+    .titled("Hill Climbing Algorithm"),
+    &Parsed::<List<(day_13::Packets, day_13::Packets)>>::both(
+        YEAR,
+        13,
+        day_13::line_pairs,
+        day_13::part_1,
+        day_13::part_2,
+    )
+    .titled("Distress Signal"),
+    &Day::both(YEAR, 14, day_14::part_1, day_14::part_2).titled("Regolith Reservoir"),
+    &Parsed::<List<day_15::Input>>::both(
+        YEAR,
+        15,
+        day_15::parse_lines,
+        day_15::part_1,
+        day_15::part_2,
+    )
+    .titled("Beacon Exclusion Zone"),
+    &Parsed::<Owned<day_16::Problem>>::both(
+        YEAR,
+        16,
+        day_16::parse,
+        day_16::part_1,
+        day_16::part_2,
+    )
+    .titled("Proboscidea Volcanium"),
+    &Parsed::<List<day_17::Jet>>::both(
+        YEAR,
+        17,
+        day_17::parse_jets,
+        day_17::part_1,
+        day_17::part_2,
+    )
+    .titled("Pyroclastic Flow"),
+    &Parsed::<Owned<day_18::Droplet>>::both(
+        YEAR,
+        18,
+        day_18::parse_droplet,
+        day_18::part_1,
+        day_18::part_2,
+    )
+    .titled("Boiling Boulders"),
+    &Parsed::<List<(i32, day_19::Blueprint)>>::both(
+        YEAR,
+        19,
+        day_19::parse_bps,
+        day_19::part_1,
+        day_19::part_2,
+    )
+    .titled("Not Enough Minerals"),
+    &Day::both(YEAR, 20, day_20::part_1, day_20::part_2).titled("Grove Positioning System"),
+    &Parsed::<Owned<day_21::Equations>>::both(
+        YEAR,
+        21,
+        day_21::parse,
+        day_21::part_1,
+        day_21::part_2,
+    )
+    .titled("Monkey Math"),
+    &Parsed::<Owned<day_22::Notes>>::both(YEAR, 22, day_22::parse, day_22::part_1, day_22::part_2)
+        .titled("Monkey Map"),
+    &Day::both(YEAR, 23, day_23::part_1, day_23::part_2).titled("Unstable Diffusion"),
+    &Day::both(YEAR, 24, day_24::part_1, day_24::part_2).titled("Blizzard Basin"),
+    &Day::both(YEAR, 25, day_25::part_1, day_25::part_2).titled("Full of Hot Air"),
 ];
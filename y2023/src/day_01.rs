@@ -1,5 +1,6 @@
 use anyhow::{Context, Result};
-pub fn part_1(input: &str) -> Result<String> {
+use shared::AnswerValue;
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let lines: Result<Vec<_>> = input
         .lines()
         .filter(|line| !line.is_empty())
@@ -12,7 +13,7 @@ pub fn part_1(input: &str) -> Result<String> {
         })
         .collect();
     let n: i32 = lines?.iter().sum();
-    Ok(n.to_string())
+    Ok(n.into())
 }
 
 const NUMBER_WORDS: [&[u8]; 9] = [
@@ -53,13 +54,13 @@ fn scan_number(s: &str, start: StartFrom) -> i32 {
     0
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let n: i32 = input
         .lines()
         .filter(|line| !line.is_empty())
         .map(|line| scan_number(line, StartFrom::Left) * 10 + scan_number(line, StartFrom::Right))
         .sum();
-    Ok(n.to_string())
+    Ok(n.into())
 }
 
 #[cfg(test)]
@@ -83,7 +84,7 @@ zoneight234
 
     #[test]
     fn test_part_1() {
-        assert_eq!(part_1(EX).unwrap(), "142".to_string());
+        assert_eq!(part_1(EX).unwrap(), AnswerValue::Integer(142));
     }
 
     #[test]
@@ -102,6 +103,6 @@ zoneight234
 
     #[test]
     fn test_part_2() {
-        assert_eq!(part_2(EX2).unwrap(), "281".to_string());
+        assert_eq!(part_2(EX2).unwrap(), AnswerValue::Integer(281));
     }
 }
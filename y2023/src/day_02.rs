@@ -6,9 +6,10 @@ use nom::combinator::{fail, map_res, recognize};
 use nom::multi::separated_list1;
 use nom::sequence::{preceded, separated_pair, terminated};
 use nom::IResult;
+use shared::AnswerValue;
 use std::str::FromStr;
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let (_, games) =
         separated_list1(tag("\n"), parse_game)(input).map_err(|err| anyhow!("{err}"))?;
     let n = games
@@ -20,10 +21,10 @@ pub fn part_1(input: &str) -> Result<String> {
         })
         .map(|g| g.id)
         .sum::<u32>();
-    Ok(n.to_string())
+    Ok(n.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let (_, games) =
         separated_list1(tag("\n"), parse_game)(input).map_err(|err| anyhow!("{err}"))?;
     let n: u32 = games
@@ -41,7 +42,7 @@ pub fn part_2(input: &str) -> Result<String> {
         })
         .map(|(red, green, blue)| red * green * blue)
         .sum::<u32>();
-    Ok(n.to_string())
+    Ok(n.into())
 }
 #[derive(Eq, PartialEq, Debug, Copy, Clone)]
 struct Attempt {
@@ -100,8 +101,8 @@ Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
 ";
     #[test]
     fn test_examples() {
-        assert_eq!(part_1(EX).unwrap(), "8");
-        assert_eq!(part_2(EX).unwrap(), "2286");
+        assert_eq!(part_1(EX).unwrap(), AnswerValue::Integer(8));
+        assert_eq!(part_2(EX).unwrap(), AnswerValue::Integer(2286));
     }
     #[test]
     fn test_parsers() {
@@ -1,6 +1,7 @@
 use anyhow::Result;
 use fxhash::FxHashSet as HashSet;
 use itertools::Itertools;
+use shared::AnswerValue;
 use std::ops::Range;
 
 fn to_grid(input: &str) -> Vec<&[u8]> {
@@ -80,7 +81,7 @@ fn number_locations(grid: &Vec<&[u8]>) -> Vec<(i32, HashSet<(i32, i32)>)> {
     numbers
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let grid = to_grid(input);
     let number_locs = number_locations(&grid);
     let part_number_sum = number_locs
@@ -88,10 +89,10 @@ pub fn part_1(input: &str) -> Result<String> {
         .filter(|(_, pos)| pos.iter().any(|(x, y)| grid.is_sym(*x, *y)))
         .map(|(n, _)| *n)
         .sum::<i32>();
-    Ok(part_number_sum.to_string())
+    Ok(part_number_sum.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let grid = to_grid(input);
     let number_locs = number_locations(&grid);
     let mut gear_ratio_sum = 0;
@@ -110,7 +111,7 @@ pub fn part_2(input: &str) -> Result<String> {
         }
     }
 
-    Ok(gear_ratio_sum.to_string())
+    Ok(gear_ratio_sum.into())
 }
 
 #[cfg(test)]
@@ -129,10 +130,10 @@ mod tests {
 ";
     #[test]
     fn test_p1() {
-        assert_eq!(part_1(EX).unwrap(), "4361".to_string());
+        assert_eq!(part_1(EX).unwrap(), AnswerValue::Integer(4361));
     }
     #[test]
     fn test_p2() {
-        assert_eq!(part_2(EX).unwrap(), "467835");
+        assert_eq!(part_2(EX).unwrap(), AnswerValue::Integer(467835));
     }
 }
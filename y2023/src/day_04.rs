@@ -7,6 +7,7 @@ use nom::multi::{many1, separated_list1};
 use nom::sequence::{pair, preceded, separated_pair};
 use nom::IResult;
 use rayon::prelude::*;
+use shared::AnswerValue;
 use std::str::FromStr;
 
 fn posint(s: &str) -> IResult<&str, i32> {
@@ -20,7 +21,7 @@ fn intlist(s: &str) -> IResult<&str, Vec<i32>> {
 fn card(s: &str) -> IResult<&str, i32> {
     preceded(preceded(tag("Card"), space1), posint)(s)
 }
-type CardLine = (i32, Vec<i32>, Vec<i32>);
+pub type CardLine = (i32, Vec<i32>, Vec<i32>);
 
 fn parse_card_line(s: &str) -> IResult<&str, CardLine> {
     let intlists = separated_pair(intlist, separated_pair(space1, tag("|"), space1), intlist);
@@ -28,7 +29,7 @@ fn parse_card_line(s: &str) -> IResult<&str, CardLine> {
     Ok((s, (card, n1, n2)))
 }
 
-fn parse(input: &str) -> Result<Vec<CardLine>> {
+pub fn parse(input: &str) -> Result<Vec<CardLine>> {
     let (_, lines) =
         separated_list1(tag("\n"), parse_card_line)(input).map_err(|err| anyhow!("{err}"))?;
     Ok(lines)
@@ -44,18 +45,16 @@ fn score(cards: &[CardLine]) -> Vec<i32> {
         })
         .collect()
 }
-pub fn part_1(input: &str) -> Result<String> {
-    let lines = parse(input)?;
-    Ok(score(&lines)
+pub fn part_1(lines: &[CardLine]) -> Result<AnswerValue> {
+    Ok(score(lines)
         .into_iter()
         .map(|n| if n > 0 { 2i32.pow((n - 1) as u32) } else { 0 })
         .sum::<i32>()
-        .to_string())
+        .into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let lines = parse(input)?;
-    let scores = score(&lines);
+pub fn part_2(lines: &[CardLine]) -> Result<AnswerValue> {
+    let scores = score(lines);
     let work: Vec<_> = lines.iter().map(|(cn, _, _)| *cn).collect();
     let scratch_found: i32 = work
         .into_par_iter()
@@ -72,7 +71,7 @@ pub fn part_2(input: &str) -> Result<String> {
             won
         })
         .sum();
-    Ok(scratch_found.to_string())
+    Ok(scratch_found.into())
 }
 
 #[cfg(test)]
@@ -87,12 +86,18 @@ Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
 ";
     #[test]
     fn test_p1() {
-        assert_eq!(part_1(EX).unwrap(), "13".to_string());
+        assert_eq!(
+            part_1(&parse(EX).unwrap()).unwrap(),
+            AnswerValue::Integer(13)
+        );
     }
 
     #[test]
     fn test_p2() {
-        assert_eq!(part_2(EX).unwrap(), "30".to_string());
+        assert_eq!(
+            part_2(&parse(EX).unwrap()).unwrap(),
+            AnswerValue::Integer(30)
+        );
     }
 
     #[test]
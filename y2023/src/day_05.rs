@@ -6,6 +6,7 @@ use nom::combinator::map_res;
 use nom::multi::separated_list1;
 use nom::sequence::{pair, preceded, separated_pair, terminated, tuple};
 use nom::IResult;
+use shared::{AnswerValue, Input};
 use std::ops::Range;
 use std::str::FromStr;
 
@@ -60,11 +61,16 @@ fn parse_map(s: &str) -> IResult<&str, Mapping<'_>> {
 }
 
 #[derive(Eq, PartialEq, Debug)]
-struct Task<'a> {
+pub struct Task<'a> {
     seeds: Vec<i64>,
     mappings: Vec<Mapping<'a>>,
 }
 
+impl Input for Task<'static> {
+    type Of<'a> = Task<'a>;
+    type View<'a> = Task<'a>;
+}
+
 fn parse(s: &str) -> IResult<&str, Task<'_>> {
     let (s, seeds) = terminated(seeds, tag("\n\n"))(s)?;
     let (s, mut mappings) = separated_list1(tag("\n\n"), parse_map)(s)?;
@@ -124,19 +130,22 @@ fn map_seed_range(range: &Range<i64>, mappings: &[Mapping]) -> Vec<Range<i64>> {
     vecs
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn parse_input(input: &str) -> Result<Task<'_>> {
     let (_, task) = parse(input).map_err(|err| anyhow!("{err}"))?;
+    Ok(task)
+}
+
+pub fn part_1(task: &Task) -> Result<AnswerValue> {
     let n = task
         .seeds
         .iter()
         .map(|seed| map_seed(*seed, &task.mappings))
         .min()
         .unwrap_or(0);
-    Ok(n.to_string())
+    Ok(n.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let (_, task) = parse(input).map_err(|err| anyhow!("{err}"))?;
+pub fn part_2(task: &Task) -> Result<AnswerValue> {
     let results: Result<Vec<_>> = task
         .seeds
         .iter()
@@ -154,7 +163,7 @@ pub fn part_2(input: &str) -> Result<String> {
         .collect();
     results
         .map(|n| n.iter().min().copied().unwrap_or(0))
-        .map(|n| n.to_string())
+        .map(AnswerValue::from)
 }
 
 #[cfg(test)]
@@ -197,14 +206,14 @@ humidity-to-location map:
 
     #[test]
     fn test_p1() {
-        let ans = part_1(EX).unwrap();
-        assert_eq!(ans, "35".to_string());
+        let ans = part_1(&parse_input(EX).unwrap()).unwrap();
+        assert_eq!(ans, AnswerValue::Integer(35));
     }
 
     #[test]
     fn test_p2() {
-        let ans = part_2(EX).unwrap();
-        assert_eq!(ans, "46".to_string());
+        let ans = part_2(&parse_input(EX).unwrap()).unwrap();
+        assert_eq!(ans, AnswerValue::Integer(46));
     }
 
     #[test]
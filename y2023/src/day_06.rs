@@ -6,6 +6,7 @@ use nom::multi::separated_list1;
 use nom::sequence::{preceded, tuple};
 use nom::IResult;
 use rayon::prelude::*;
+use shared::AnswerValue;
 use std::str::FromStr;
 
 fn posint(s: &str) -> IResult<&str, u64> {
@@ -16,26 +17,33 @@ fn intlist(s: &str) -> IResult<&str, Vec<u64>> {
     separated_list1(space1, posint)(s)
 }
 
-fn parse(s: &str) -> IResult<&str, (Vec<u64>, Vec<u64>)> {
+/// The times and record distances of each race
+pub type Races = (Vec<u64>, Vec<u64>);
+
+fn parse(s: &str) -> IResult<&str, Races> {
     tuple((
         preceded(preceded(tag("Time:"), space1), intlist),
         preceded(preceded(tag("\nDistance:"), space1), intlist),
     ))(s)
 }
 
-pub fn part_1(s: &str) -> Result<String> {
-    let (_, (time, dist)) = parse(s).map_err(|err| anyhow!("{err}"))?;
+pub fn parse_races(s: &str) -> Result<Races> {
+    let (_, races) = parse(s).map_err(|err| anyhow!("{err}"))?;
+    Ok(races)
+}
+
+pub fn part_1((time, dist): &Races) -> Result<AnswerValue> {
     let r = time
-        .into_iter()
+        .iter()
         .zip(dist)
-        .map(|(time, dist)| {
+        .map(|(&time, &dist)| {
             (0..=time)
                 .map(|hold| (time - hold) * hold)
                 .filter(|r| r > &dist)
                 .count()
         })
         .product::<usize>();
-    Ok(r.to_string())
+    Ok(r.into())
 }
 
 fn concat_nums(nums: &[u64]) -> u64 {
@@ -48,16 +56,15 @@ fn concat_nums(nums: &[u64]) -> u64 {
     })
 }
 
-pub fn part_2(s: &str) -> Result<String> {
-    let (_, (times, distances)) = parse(s).map_err(|err| anyhow!("{err}"))?;
-    let time = concat_nums(&times);
-    let dist = concat_nums(&distances);
+pub fn part_2((times, distances): &Races) -> Result<AnswerValue> {
+    let time = concat_nums(times);
+    let dist = concat_nums(distances);
     let r = (0..=time)
         .into_par_iter()
         .map(|hold| (time - hold) * hold)
         .filter(|r| *r > dist)
         .count();
-    Ok(format!("{r}"))
+    Ok(r.into())
 }
 
 #[cfg(test)]
@@ -68,11 +75,17 @@ Distance:  9  40  200
 ";
     #[test]
     fn test_p1() {
-        assert_eq!(part_1(EX).unwrap(), "288".to_string());
+        assert_eq!(
+            part_1(&parse_races(EX).unwrap()).unwrap(),
+            AnswerValue::Integer(288)
+        );
     }
     #[test]
     fn test_p2() {
-        assert_eq!(part_2(EX).unwrap(), "71503".to_string());
+        assert_eq!(
+            part_2(&parse_races(EX).unwrap()).unwrap(),
+            AnswerValue::Integer(71503)
+        );
     }
     #[test]
     fn test_parse() {
@@ -1,4 +1,5 @@
 use anyhow::{anyhow, Result};
+use shared::AnswerValue;
 use std::cmp::Ordering;
 
 const CARDS_BY_STRENGTH: &[u8; 13] = b"AKQJT98765432";
@@ -129,12 +130,12 @@ fn ranked_hands(
     Ok(n)
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    ranked_hands(input, &hand_type, &value_of).map(|n| n.to_string())
+pub fn part_1(input: &str) -> Result<AnswerValue> {
+    ranked_hands(input, &hand_type, &value_of).map(AnswerValue::from)
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    ranked_hands(input, &best_possible_hand_type, &joker_value_of).map(|n| n.to_string())
+pub fn part_2(input: &str) -> Result<AnswerValue> {
+    ranked_hands(input, &best_possible_hand_type, &joker_value_of).map(AnswerValue::from)
 }
 
 #[cfg(test)]
@@ -148,11 +149,11 @@ QQQJA 483
 ";
     #[test]
     fn test_part_1() {
-        assert_eq!(part_1(EX).unwrap().as_str(), "6440");
+        assert_eq!(part_1(EX).unwrap(), AnswerValue::Integer(6440));
     }
 
     #[test]
     fn test_part_2() {
-        assert_eq!(part_2(EX).unwrap().as_str(), "5905");
+        assert_eq!(part_2(EX).unwrap(), AnswerValue::Integer(5905));
     }
 }
@@ -7,6 +7,8 @@ use nom::combinator::map;
 use nom::multi::{many1, separated_list1};
 use nom::sequence::{delimited, pair, separated_pair, terminated};
 use nom::IResult;
+use shared::math::lcm_all;
+use shared::{AnswerValue, Input};
 
 #[derive(Eq, PartialEq, Debug, Copy, Clone)]
 enum Dir {
@@ -76,37 +78,41 @@ fn solve<'a>(
     unreachable!()
 }
 
-fn prepare(s: &str) -> Result<(CrossroadsMap, Vec<Dir>, Vec<Crossroads<'_>>)> {
-    let (_, (dirs, roads)) = parse(s).map_err(|err| anyhow!("{err}"))?;
-    let map = assemble_map(&roads);
-    Ok((map, dirs, roads))
+pub struct Network<'a> {
+    map: CrossroadsMap,
+    dirs: Vec<Dir>,
+    roads: Vec<Crossroads<'a>>,
 }
 
-pub fn part_1(s: &str) -> Result<String> {
-    let (map, dirs, _) = prepare(s)?;
-    solve("AAA", &map, &dirs, &|place| place == "ZZZ").map(|n| n.to_string())
+impl Input for Network<'static> {
+    type Of<'a> = Network<'a>;
+    type View<'a> = Network<'a>;
 }
 
-fn gcd(mut a: usize, mut b: usize) -> usize {
-    while b != 0 {
-        (a, b) = (b, a.rem_euclid(b))
-    }
-    a
+pub fn prepare(s: &str) -> Result<Network<'_>> {
+    let (_, (dirs, roads)) = parse(s).map_err(|err| anyhow!("{err}"))?;
+    let map = assemble_map(&roads);
+    Ok(Network { map, dirs, roads })
+}
+
+pub fn part_1(network: &Network) -> Result<AnswerValue> {
+    solve("AAA", &network.map, &network.dirs, &|place| place == "ZZZ").map(AnswerValue::from)
 }
 
-pub fn part_2(s: &str) -> Result<String> {
-    let (map, dirs, roads) = prepare(s)?;
-    let solutions: Result<Vec<_>> = roads
+pub fn part_2(network: &Network) -> Result<AnswerValue> {
+    let solutions: Result<Vec<_>> = network
+        .roads
         .iter()
         .map(|r| r.at)
         .filter(|r| r.ends_with('A'))
-        .map(|place| solve(place, &map, &dirs, &|place| place.ends_with('Z')))
+        .map(|place| {
+            solve(place, &network.map, &network.dirs, &|place| {
+                place.ends_with('Z')
+            })
+        })
         .collect();
-    let n = solutions?.iter().copied().fold(1usize, |a, b| {
-        let g = gcd(a, b);
-        a * b / g
-    });
-    Ok(n.to_string())
+    let n = lcm_all(solutions?).context("The answer doesn't fit in a usize")?;
+    Ok(n.into())
 }
 #[cfg(test)]
 mod tests {
@@ -119,7 +125,10 @@ ZZZ = (ZZZ, ZZZ)
 ";
     #[test]
     fn test_part_1() {
-        assert_eq!(part_1(EX).unwrap(), "6".to_string());
+        assert_eq!(
+            part_1(&prepare(EX).unwrap()).unwrap(),
+            AnswerValue::Integer(6)
+        );
     }
 
     const EX2: &str = "LR
@@ -135,6 +144,9 @@ XXX = (XXX, XXX)
 ";
     #[test]
     fn test_part_2() {
-        assert_eq!(part_2(EX2).unwrap(), "6".to_string());
+        assert_eq!(
+            part_2(&prepare(EX2).unwrap()).unwrap(),
+            AnswerValue::Integer(6)
+        );
     }
 }
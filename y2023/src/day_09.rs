@@ -7,6 +7,7 @@ use nom::character::complete::{digit1, space1};
 use nom::combinator::{map, map_res};
 use nom::multi::separated_list1;
 use nom::IResult;
+use shared::AnswerValue;
 use std::str::FromStr;
 
 use nom::sequence::preceded;
@@ -33,14 +34,17 @@ fn extrapolate(row: &[i64]) -> i64 {
     }
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn parse_rows(input: &str) -> Result<Vec<Vec<i64>>> {
     let (_, rows) = parse(input).map_err(|err| anyhow!("{err}"))?;
+    Ok(rows)
+}
+
+pub fn part_1(rows: &[Vec<i64>]) -> Result<AnswerValue> {
     let s = rows.iter().map(|r| extrapolate(r)).sum::<i64>();
-    Ok(s.to_string())
+    Ok(s.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let (_, rows) = parse(input).map_err(|err| anyhow!("{err}"))?;
+pub fn part_2(rows: &[Vec<i64>]) -> Result<AnswerValue> {
     let s = rows
         .iter()
         .map(|r| {
@@ -48,7 +52,7 @@ pub fn part_2(input: &str) -> Result<String> {
             extrapolate(&r)
         })
         .sum::<i64>();
-    Ok(s.to_string())
+    Ok(s.into())
 }
 
 #[cfg(test)]
@@ -67,11 +71,17 @@ mod tests {
 
     #[test]
     fn test_part_1() {
-        assert_eq!(part_1(EX).unwrap(), "114".to_string());
+        assert_eq!(
+            part_1(&parse_rows(EX).unwrap()).unwrap(),
+            AnswerValue::Integer(114)
+        );
     }
 
     #[test]
     fn test_part_2() {
-        assert_eq!(part_2(EX).unwrap(), "2".to_string());
+        assert_eq!(
+            part_2(&parse_rows(EX).unwrap()).unwrap(),
+            AnswerValue::Integer(2)
+        );
     }
 }
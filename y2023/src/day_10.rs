@@ -2,6 +2,7 @@ use anyhow::{Context, Result};
 use fxhash::FxHashMap as HashMap;
 use fxhash::FxHashSet as Set;
 use itertools::Itertools;
+use shared::AnswerValue;
 
 type Coord2 = [i32; 2];
 const NORTH: Coord2 = [0, -1];
@@ -18,12 +19,14 @@ const CONNECTORS: [(char, [Coord2; 2]); 6] = [
 ];
 
 type Pipes = HashMap<Coord2, Vec<Coord2>>;
+/// The start position and the pipes connected to each position
+pub type Maze = (Coord2, Pipes);
 
 fn add_coords(lhs: Coord2, rhs: Coord2) -> Coord2 {
     [lhs[0] + rhs[0], lhs[1] + rhs[1]]
 }
 
-fn parse_pipes(input: &str) -> Result<(Coord2, Pipes)> {
+pub fn parse_pipes(input: &str) -> Result<Maze> {
     let places_char = map_iterator(input)
         .filter(|(_, ch)| *ch != '.')
         .collect_vec();
@@ -93,9 +96,8 @@ fn map_iterator(input: &str) -> impl Iterator<Item = (Coord2, char)> + '_ {
                 .map(move |(x, ch)| ([x as i32, y as i32], ch))
         })
 }
-pub fn part_1(input: &str) -> Result<String> {
-    let (start, pipes) = parse_pipes(input)?;
-    Ok(visit_graph(start, &pipes).1.to_string())
+pub fn part_1((start, pipes): &Maze) -> Result<AnswerValue> {
+    Ok(visit_graph(*start, pipes).1.into())
 }
 
 fn shoelace_area(polygon: &[Coord2]) -> i32 {
@@ -112,11 +114,10 @@ fn shoelace_area(polygon: &[Coord2]) -> i32 {
     (s2 - s1).abs() / 2
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let (start, pipes) = parse_pipes(input)?;
+pub fn part_2((start, pipes): &Maze) -> Result<AnswerValue> {
     // In order vertices of the path
-    let the_loop = graph_path(start, &pipes);
+    let the_loop = graph_path(*start, pipes);
     let area_including_loop = shoelace_area(&the_loop);
     let interior_points_by_picks_theorem = area_including_loop - ((the_loop.len() / 2) as i32 - 1);
-    Ok(interior_points_by_picks_theorem.to_string())
+    Ok(interior_points_by_picks_theorem.into())
 }
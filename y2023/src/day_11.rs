@@ -1,6 +1,7 @@
 use anyhow::Result;
 use fxhash::FxHashSet as Set;
 use shared::grid_parser;
+use shared::AnswerValue;
 
 type Coord2 = [i64; 2];
 
@@ -64,12 +65,12 @@ fn all_distances(input: &str, expanding_space_factor: i64) -> i64 {
     s
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    Ok(all_distances(input, 1).to_string())
+pub fn part_1(input: &str) -> Result<AnswerValue> {
+    Ok(all_distances(input, 1).into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    Ok(all_distances(input, 1_000_000 - 1).to_string())
+pub fn part_2(input: &str) -> Result<AnswerValue> {
+    Ok(all_distances(input, 1_000_000 - 1).into())
 }
 #[cfg(test)]
 mod tests {
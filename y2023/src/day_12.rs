@@ -1,8 +1,9 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use fxhash::FxHashMap;
 use itertools::Itertools;
+use shared::AnswerValue;
 
-fn calculate(examples: &Vec<(Vec<char>, Vec<usize>)>) -> usize {
+fn calculate(examples: &[(Vec<char>, Vec<usize>)]) -> usize {
     let mut cache = Cache::default();
     let mut s = 0;
     for (cs, groups) in examples {
@@ -11,32 +12,34 @@ fn calculate(examples: &Vec<(Vec<char>, Vec<usize>)>) -> usize {
     s
 }
 
-fn parse(input: &str) -> Vec<(Vec<char>, Vec<usize>)> {
+pub fn parse(input: &str) -> Result<Vec<(Vec<char>, Vec<usize>)>> {
     input
         .lines()
         .filter(|line| !line.is_empty())
         .map(|line| {
-            let (first, rest) = line.split_once(' ').unwrap();
+            let (first, rest) = line
+                .split_once(' ')
+                .with_context(|| format!("Missing space in {line}"))?;
             let mut char_str = vec![];
             char_str.extend(first.chars());
             let mut group = vec![];
             for n in rest.split(',') {
-                group.push(n.parse().unwrap());
+                group.push(n.parse()?);
             }
-            (char_str, group)
+            Ok((char_str, group))
         })
         .collect()
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    Ok(calculate(&parse(input)).to_string())
+pub fn part_1(records: &[(Vec<char>, Vec<usize>)]) -> Result<AnswerValue> {
+    Ok(calculate(records).into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let inp = parse(input)
-        .into_iter()
+pub fn part_2(records: &[(Vec<char>, Vec<usize>)]) -> Result<AnswerValue> {
+    let inp: Vec<_> = records
+        .iter()
         .map(|(ch, g)| {
-            let s: String = ch.into_iter().collect();
+            let s: String = ch.iter().collect();
             let vs = (0..5).map(|_| s.clone()).join("?");
             let ch: Vec<_> = vs.chars().collect();
             let mut new_g = Vec::new();
@@ -46,7 +49,7 @@ pub fn part_2(input: &str) -> Result<String> {
             (ch, new_g)
         })
         .collect();
-    Ok(calculate(&inp).to_string())
+    Ok(calculate(&inp).into())
 }
 type Cache<'a> = FxHashMap<(&'a [char], &'a [usize]), usize>;
 
@@ -125,11 +128,17 @@ mod tests {
 
     #[test]
     fn test_part_1() {
-        assert_eq!(part_1(EX).unwrap(), "21".to_string());
+        assert_eq!(
+            part_1(&parse(EX).unwrap()).unwrap(),
+            AnswerValue::Integer(21)
+        );
     }
 
     #[test]
     fn test_part_2() {
-        assert_eq!(part_2(EX).unwrap(), "525152".to_string());
+        assert_eq!(
+            part_2(&parse(EX).unwrap()).unwrap(),
+            AnswerValue::Integer(525152)
+        );
     }
 }
@@ -2,6 +2,7 @@ use crate::day_13::Reflection::{Horizontal, Vertical};
 use anyhow::{anyhow, Context, Result};
 use itertools::Itertools;
 use shared::grid_parser;
+use shared::AnswerValue;
 
 type Map = Vec<Vec<bool>>;
 fn parse(input: &str) -> Map {
@@ -93,7 +94,7 @@ fn find_smudge(map: &mut Map) -> Option<usize> {
         .map(|reflection| reflection.value())
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let mut s = 0;
     for block in input.split("\n\n") {
         let map = parse(block);
@@ -105,16 +106,16 @@ pub fn part_1(input: &str) -> Result<String> {
             .unwrap_or(0)
             * 100;
     }
-    Ok(s.to_string())
+    Ok(s.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let mut s = 0;
     for block in input.split("\n\n") {
         let mut map = parse(block);
         s += find_smudge(&mut map).with_context(|| anyhow!("Unable to find smudge:\n{block}"))?;
     }
-    Ok(s.to_string())
+    Ok(s.into())
 }
 
 #[cfg(test)]
@@ -160,7 +161,7 @@ mod tests {
         ex.push_str(VERT_EX);
         ex.push_str("\n\n");
         ex.push_str(HORZ_EX);
-        assert_eq!(part_1(ex.as_str()).unwrap(), "405".to_string());
+        assert_eq!(part_1(ex.as_str()).unwrap(), AnswerValue::Integer(405));
     }
     #[test]
     fn test_part_two() {
@@ -168,6 +169,6 @@ mod tests {
         ex.push_str(VERT_EX);
         ex.push_str("\n\n");
         ex.push_str(HORZ_EX);
-        assert_eq!(part_2(ex.as_str()).unwrap(), "400".to_string());
+        assert_eq!(part_2(ex.as_str()).unwrap(), AnswerValue::Integer(400));
     }
 }
@@ -1,6 +1,7 @@
 use anyhow::Result;
 use fxhash::FxHashMap as Map;
 use itertools::Itertools;
+use shared::AnswerValue;
 
 #[derive(Hash, Eq, PartialEq, Debug, Copy, Clone)]
 enum Tile {
@@ -113,8 +114,8 @@ fn tilt_north(input: &str) -> i32 {
     grid.tilt_board((0, -1));
     count_load(&grid)
 }
-pub fn part_1(input: &str) -> Result<String> {
-    Ok(tilt_north(input).to_string())
+pub fn part_1(input: &str) -> Result<AnswerValue> {
+    Ok(tilt_north(input).into())
 }
 
 fn spin_cycle(grid: &mut Tiles) {
@@ -150,8 +151,8 @@ fn find_cycle_in_spin_cycle(input: &str) -> i32 {
     unreachable!()
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    Ok(find_cycle_in_spin_cycle(input).to_string())
+pub fn part_2(input: &str) -> Result<AnswerValue> {
+    Ok(find_cycle_in_spin_cycle(input).into())
 }
 
 #[cfg(test)]
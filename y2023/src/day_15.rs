@@ -1,5 +1,6 @@
 use anyhow::Result;
 use itertools::Itertools;
+use shared::AnswerValue;
 
 fn hash_a1(input: &str) -> i64 {
     input
@@ -9,11 +10,8 @@ fn hash_a1(input: &str) -> i64 {
         .filter(|b| *b != b'\n')
         .fold(0, |h, ch| ((h + (ch as i64)) * 17) % 256)
 }
-pub fn part_1(input: &str) -> Result<String> {
-    Ok(input
-        .split(',')
-        .fold(0, |acc, s| acc + hash_a1(s))
-        .to_string())
+pub fn part_1(input: &str) -> Result<AnswerValue> {
+    Ok(input.split(',').fold(0, |acc, s| acc + hash_a1(s)).into())
 }
 
 fn hashmap_a1(input: &str) -> Vec<Vec<(&str, i64)>> {
@@ -63,8 +61,8 @@ pub fn focusing_power(boxes: &[Vec<(&str, i64)>]) -> i64 {
         .sum()
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    Ok(focusing_power(&hashmap_a1(input)).to_string())
+pub fn part_2(input: &str) -> Result<AnswerValue> {
+    Ok(focusing_power(&hashmap_a1(input)).into())
 }
 
 #[cfg(test)]
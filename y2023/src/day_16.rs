@@ -3,6 +3,7 @@ use anyhow::Result;
 use fxhash::FxHashSet as Set;
 use itertools::Itertools;
 use rayon::prelude::*;
+use shared::AnswerValue;
 
 #[derive(Eq, PartialEq, Debug, Hash, Copy, Clone)]
 enum Tile {
@@ -180,7 +181,7 @@ fn find_maximal_grid_energy(grid: &VecGrid) -> usize {
         .unwrap_or(0)
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let grid = VecGrid::new(input);
     Ok(energize_grid(
         &grid,
@@ -189,12 +190,12 @@ pub fn part_1(input: &str) -> Result<String> {
             dir: East,
         },
     )
-    .to_string())
+    .into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let grid = VecGrid::new(input);
-    Ok(find_maximal_grid_energy(&grid).to_string())
+    Ok(find_maximal_grid_energy(&grid).into())
 }
 
 #[cfg(test)]
@@ -213,11 +214,11 @@ mod tests {
 ";
     #[test]
     fn test_part_1() {
-        assert_eq!(part_1(EX).unwrap(), "46".to_string());
+        assert_eq!(part_1(EX).unwrap(), AnswerValue::Integer(46));
     }
 
     #[test]
     fn test_part_2() {
-        assert_eq!(part_2(EX).unwrap(), "51".to_string());
+        assert_eq!(part_2(EX).unwrap(), AnswerValue::Integer(51));
     }
 }
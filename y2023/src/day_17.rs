@@ -1,6 +1,7 @@
 use anyhow::Result;
 use fxhash::FxHashMap as Map;
 use itertools::Itertools;
+use shared::AnswerValue;
 use std::cmp::Reverse;
 use std::collections::BinaryHeap;
 
@@ -195,13 +196,13 @@ fn min_heat_loss(grid: &dyn Grid, is_ultra: bool) -> i32 {
     panic!("Unable to solve")
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let g = VecGrid::new(input);
-    Ok(min_heat_loss(&g, false).to_string())
+    Ok(min_heat_loss(&g, false).into())
 }
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let g = VecGrid::new(input);
-    Ok(min_heat_loss(&g, true).to_string())
+    Ok(min_heat_loss(&g, true).into())
 }
 
 #[cfg(test)]
@@ -4,6 +4,7 @@ use nom::combinator::map_res;
 use nom::multi::separated_list1;
 use nom::sequence::{delimited, preceded};
 use nom::IResult;
+use shared::AnswerValue;
 use std::str::FromStr;
 
 #[derive(Eq, PartialEq, Copy, Clone, Debug, Hash)]
@@ -132,12 +133,12 @@ fn cubic_meters_of_lava(s: &str, use_hex: bool) -> Result<i64> {
     Ok(inner_by_picks_theorem(&p) + circumference(&p))
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    Ok(cubic_meters_of_lava(input, false)?.to_string())
+pub fn part_1(input: &str) -> Result<AnswerValue> {
+    Ok(cubic_meters_of_lava(input, false)?.into())
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    Ok(cubic_meters_of_lava(input, true)?.to_string())
+pub fn part_2(input: &str) -> Result<AnswerValue> {
+    Ok(cubic_meters_of_lava(input, true)?.into())
 }
 
 #[cfg(test)]
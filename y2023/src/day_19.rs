@@ -7,6 +7,7 @@ use nom::combinator::{map, map_res};
 use nom::multi::separated_list1;
 use nom::sequence::{delimited, preceded, separated_pair, tuple};
 use nom::IResult;
+use shared::AnswerValue;
 use std::str::FromStr;
 
 #[derive(Eq, PartialEq, Debug, Copy, Clone, Hash)]
@@ -321,12 +322,12 @@ fn add_range_combinations(input: &str) -> Result<u64> {
     Ok(range_splitting(&wf))
 }
 
-pub fn part_1(s: &str) -> Result<String> {
-    add_accepted_parts(s).map(|n| n.to_string())
+pub fn part_1(s: &str) -> Result<AnswerValue> {
+    add_accepted_parts(s).map(AnswerValue::from)
 }
 
-pub fn part_2(s: &str) -> Result<String> {
-    add_range_combinations(s).map(|n| n.to_string())
+pub fn part_2(s: &str) -> Result<AnswerValue> {
+    add_range_combinations(s).map(AnswerValue::from)
 }
 
 #[cfg(test)]
@@ -9,6 +9,7 @@ use nom::multi::separated_list1;
 use nom::sequence::{pair, separated_pair};
 use nom::IResult;
 use rayon::prelude::*;
+use shared::AnswerValue;
 use std::collections::VecDeque;
 
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
@@ -157,10 +158,10 @@ fn push_button(s: &str) -> Result<(i32, i32, i32)> {
     }))
 }
 
-pub fn part_1(s: &str) -> Result<String> {
+pub fn part_1(s: &str) -> Result<AnswerValue> {
     push_button(s)
         .map(|(lo, hi, _)| (lo as i64) * (hi as i64))
-        .map(|n| n.to_string())
+        .map(AnswerValue::from)
 }
 
 fn find_period(s: &str, node: &str, high: bool) -> Result<i64> {
@@ -193,8 +194,8 @@ fn find_connectors_to_rx(s: &str) -> Result<Vec<i64>> {
         .collect()
 }
 
-pub fn part_2(s: &str) -> Result<String> {
-    find_connectors_to_rx(s).map(|n| n.iter().product::<i64>().to_string())
+pub fn part_2(s: &str) -> Result<AnswerValue> {
+    find_connectors_to_rx(s).map(|n| n.iter().product::<i64>().into())
 }
 
 #[cfg(test)]
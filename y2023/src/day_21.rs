@@ -1,6 +1,7 @@
 use anyhow::{Context, Result};
 use fxhash::FxHashMap as Map;
 use itertools::Itertools;
+use shared::AnswerValue;
 use std::collections::VecDeque;
 
 #[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
@@ -108,13 +109,13 @@ fn walk_garden(grid: &VecGrid, start: (i32, i32), time: usize) -> Vec<usize> {
         .collect_vec()
 }
 
-pub fn part_1(s: &str) -> Result<String> {
+pub fn part_1(s: &str) -> Result<AnswerValue> {
     let g = VecGrid::new(s);
     let s = find_start(&g).context("No start location")?;
-    Ok(walk_garden(&g, s, 64)[64].to_string())
+    Ok(walk_garden(&g, s, 64)[64].into())
 }
 
-pub fn part_2(s: &str) -> Result<String> {
+pub fn part_2(s: &str) -> Result<AnswerValue> {
     let g = VecGrid::new(s);
     assert_eq!(g.width, g.height);
     let s = find_start(&g).context("No start location")?;
@@ -139,7 +140,7 @@ pub fn part_2(s: &str) -> Result<String> {
     // f_1 = a + b + c => a = f_1 - b - c
     let a = f_1 - b - c;
     let ans = a * grid_radius * grid_radius + b * grid_radius + c;
-    Ok(ans.to_string())
+    Ok(ans.into())
 }
 
 #[cfg(test)]
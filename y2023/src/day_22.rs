@@ -6,10 +6,11 @@ use nom::character::complete::{char, i32};
 use nom::multi::separated_list1;
 use nom::sequence::terminated;
 use nom::IResult;
+use shared::AnswerValue;
 use std::collections::VecDeque;
 
 #[derive(Debug, Eq, PartialEq, Copy, Clone, Hash)]
-struct Brick {
+pub struct Brick {
     x: (i32, i32),
     y: (i32, i32),
     z: (i32, i32),
@@ -42,7 +43,7 @@ impl Brick {
     }
 }
 
-fn parse(s: &str) -> Result<Vec<Brick>> {
+pub fn parse(s: &str) -> Result<Vec<Brick>> {
     Ok(separated_list1(char('\n'), Brick::parse)(s)
         .map_err(|err| anyhow!("{err}"))?
         .1)
@@ -137,8 +138,8 @@ fn brick_dependencies(bricks: &[Brick]) -> (BrickDependency<'_>, BrickDependency
     }
     (rests_on, supported_by)
 }
-pub fn part_1(s: &str) -> Result<String> {
-    let mut bricks = parse(s)?;
+pub fn part_1(bricks: &[Brick]) -> Result<AnswerValue> {
+    let mut bricks = bricks.to_owned();
     bricks.sort_by_key(|b| b.z.0);
     settle(&mut bricks);
     let (rests_on, supported_by) = brick_dependencies(&bricks);
@@ -148,7 +149,7 @@ pub fn part_1(s: &str) -> Result<String> {
             movable += 1;
         }
     }
-    Ok(movable.to_string())
+    Ok(movable.into())
 }
 
 fn would_destroy<'a>(
@@ -174,8 +175,8 @@ fn would_destroy<'a>(
     fell.len() - 1
 }
 
-pub fn part_2(s: &str) -> Result<String> {
-    let mut bricks = parse(s)?;
+pub fn part_2(bricks: &[Brick]) -> Result<AnswerValue> {
+    let mut bricks = bricks.to_owned();
     bricks.sort_by_key(|b| b.z.0);
     settle(&mut bricks);
     let (rests_on, supported_by) = brick_dependencies(&bricks);
@@ -183,7 +184,7 @@ pub fn part_2(s: &str) -> Result<String> {
         .iter()
         .map(|b| would_destroy(b, &rests_on, &supported_by))
         .sum();
-    Ok(n.to_string())
+    Ok(n.into())
 }
 
 #[cfg(test)]
@@ -211,10 +212,16 @@ mod tests {
 
     #[test]
     fn test_part_1() {
-        assert_eq!(part_1(EX).unwrap(), "5".to_string());
+        assert_eq!(
+            part_1(&parse(EX).unwrap()).unwrap(),
+            AnswerValue::Integer(5)
+        );
     }
     #[test]
     fn test_part_2() {
-        assert_eq!(part_2(EX).unwrap(), "7".to_string());
+        assert_eq!(
+            part_2(&parse(EX).unwrap()).unwrap(),
+            AnswerValue::Integer(7)
+        );
     }
 }
@@ -2,6 +2,7 @@ use anyhow::{Context, Result};
 use fxhash::FxHashMap as Map;
 use fxhash::FxHashSet as Set;
 use itertools::Itertools;
+use shared::AnswerValue;
 use std::collections::VecDeque;
 
 #[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
@@ -202,17 +203,17 @@ fn longest_path(grid: &VecGrid) -> Option<usize> {
     Some(max_path)
 }
 
-pub fn part_1(s: &str) -> Result<String> {
+pub fn part_1(s: &str) -> Result<AnswerValue> {
     let v = VecGrid::new(s, true);
     longest_path(&v)
         .context("Unable to find start/end")
-        .map(|n| n.to_string())
+        .map(AnswerValue::from)
 }
 
-pub fn part_2(s: &str) -> Result<String> {
+pub fn part_2(s: &str) -> Result<AnswerValue> {
     let v = VecGrid::new(s, false);
     let map = make_graph(&v);
-    Ok(longest_path_using_graph(&map).to_string())
+    Ok(longest_path_using_graph(&map).into())
 }
 
 #[cfg(test)]
@@ -8,64 +8,47 @@ use nom::combinator::map;
 use nom::multi::separated_list1;
 use nom::sequence::{pair, separated_pair, terminated, tuple};
 use nom::IResult;
-use std::ops::Sub;
+use shared::geometry::Point3d;
+use shared::AnswerValue;
 
-fn parse_vec3(s: &str) -> IResult<&str, Vec3<i64>> {
+fn parse_vec3(s: &str) -> IResult<&str, Point3d<i64>> {
     map(
         tuple((
             terminated(parse_i64, pair(char(','), space1)),
             terminated(parse_i64, pair(char(','), space1)),
             parse_i64,
         )),
-        |(x, y, z)| Vec3 { x, y, z },
+        |(x, y, z)| Point3d { x, y, z },
     )(s)
 }
 
-#[derive(PartialEq, Debug, Copy, Clone)]
-struct Vec3<T> {
-    x: T,
-    y: T,
-    z: T,
-}
-
-impl<T: Sub<Output = T>> Vec3<T> {
-    fn sub(self, other: Self) -> Self {
-        Self {
-            x: self.x - other.x,
-            y: self.y - other.y,
-            z: self.z - other.z,
-        }
-    }
-}
-impl From<Vec3<i64>> for Vec3<f64> {
-    fn from(value: Vec3<i64>) -> Self {
-        Vec3 {
-            x: value.x as f64,
-            y: value.y as f64,
-            z: value.z as f64,
-        }
+fn to_f64(value: Point3d<i64>) -> Point3d<f64> {
+    Point3d {
+        x: value.x as f64,
+        y: value.y as f64,
+        z: value.z as f64,
     }
 }
 
 impl From<Hailstone<i64>> for Hailstone<f64> {
     fn from(value: Hailstone<i64>) -> Self {
         Hailstone {
-            pos: value.pos.into(),
-            vel: value.vel.into(),
+            pos: to_f64(value.pos),
+            vel: to_f64(value.vel),
         }
     }
 }
 #[derive(PartialEq, Debug, Copy, Clone)]
-struct Hailstone<T> {
-    pos: Vec3<T>,
-    vel: Vec3<T>,
+pub struct Hailstone<T> {
+    pos: Point3d<T>,
+    vel: Point3d<T>,
 }
 fn parse_hailstone(s: &str) -> IResult<&str, Hailstone<i64>> {
     let (s, (pos, vel)) = separated_pair(parse_vec3, tag(" @ "), parse_vec3)(s)?;
     Ok((s, Hailstone { pos, vel }))
 }
 
-fn parse_hailstones(s: &str) -> Result<Vec<Hailstone<i64>>> {
+pub fn parse_hailstones(s: &str) -> Result<Vec<Hailstone<i64>>> {
     Ok(separated_list1(char('\n'), parse_hailstone)(s)
         .map_err(|err| anyhow!("{err}"))?
         .1)
@@ -108,12 +91,8 @@ fn count_intersections_within_area(hailstones: &[Hailstone<i64>], amin: f64, ama
     }
     count
 }
-pub fn part_1(s: &str) -> Result<String> {
-    let hailstones = parse_hailstones(s)?;
-    Ok(
-        count_intersections_within_area(&hailstones, 200000000000000f64, 400000000000000f64)
-            .to_string(),
-    )
+pub fn part_1(hailstones: &[Hailstone<i64>]) -> Result<AnswerValue> {
+    Ok(count_intersections_within_area(hailstones, 200000000000000f64, 400000000000000f64).into())
 }
 
 // Make a number of assumptions that are possibly correct and could constrain the velocities the rock
@@ -127,7 +106,7 @@ fn possible_velocities<F>(
     max_abs_vel: i64,
 ) -> impl Iterator<Item = i64>
 where
-    F: Fn(Vec3<i64>) -> i64,
+    F: Fn(Point3d<i64>) -> i64,
 {
     let mut possible = Set::default();
     for i in 0..hailstones.len() {
@@ -156,30 +135,29 @@ where
     possible.into_iter()
 }
 
-fn find_rock_velocity_vector(hailstones: &[Hailstone<i64>], max_abs_vel: i64) -> Vec3<i64> {
+fn find_rock_velocity_vector(hailstones: &[Hailstone<i64>], max_abs_vel: i64) -> Point3d<i64> {
     let x = possible_velocities(hailstones, |v| v.x, max_abs_vel);
     let y = possible_velocities(hailstones, |v| v.y, max_abs_vel);
     let z = possible_velocities(hailstones, |v| v.z, max_abs_vel);
     let poss = x.zip(y).zip(z).collect_vec();
     assert_eq!(poss.len(), 1);
     let ((x, y), z) = poss[0];
-    Vec3 { x, y, z }
+    Point3d { x, y, z }
 }
 
-pub fn part_2(s: &str) -> Result<String> {
-    let hailstones = parse_hailstones(s)?;
-    let rock_vel = find_rock_velocity_vector(&hailstones, 500);
+pub fn part_2(hailstones: &[Hailstone<i64>]) -> Result<AnswerValue> {
+    let rock_vel = find_rock_velocity_vector(hailstones, 500);
     // The velocity of the rock is known. Choose any 2 hailstones:
     let a = hailstones[0];
     let b = hailstones[1];
     // These two _must_ intersect with the origin of the rock:
     let a_diff = Hailstone {
         pos: a.pos,
-        vel: a.vel.sub(rock_vel),
+        vel: a.vel - rock_vel,
     };
     let b_diff = Hailstone {
         pos: b.pos,
-        vel: b.vel.sub(rock_vel),
+        vel: b.vel - rock_vel,
     };
     // Let's first just intersect them in xy:
     if let Some((x, y)) = intersection(a_diff.into(), b_diff.into()) {
@@ -188,7 +166,7 @@ pub fn part_2(s: &str) -> Result<String> {
         // t = (x - a_diff.pos.x) / a_diff.vel.x
         let t = ((x as i64) - a_diff.pos.x) / a_diff.vel.x;
         let z = a_diff.pos.z + t * a_diff.vel.z;
-        Ok(((x as i64) + (y as i64) + z).to_string())
+        Ok(((x as i64) + (y as i64) + z).into())
     } else {
         Err(anyhow!("No intersection found"))
     }
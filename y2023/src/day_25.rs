@@ -2,6 +2,7 @@ use anyhow::{Context, Result};
 use fxhash::{FxHashMap as Map, FxHashSet as Set};
 use itertools::Itertools;
 use rand::{thread_rng, Rng};
+use shared::AnswerValue;
 use std::cmp::Reverse;
 use std::collections::VecDeque;
 
@@ -77,7 +78,7 @@ fn reachable_vertices(graph: &Graph, start: &str) -> usize {
     cache.len()
 }
 
-pub fn part_1(s: &str) -> Result<String> {
+pub fn part_1(s: &str) -> Result<AnswerValue> {
     let graph = parse_graph(s).context("Unparseable graph")?;
     let stats = shortest_path_stats(&graph, 200)
         .into_iter()
@@ -96,9 +97,9 @@ pub fn part_1(s: &str) -> Result<String> {
     }
     let left_side = reachable_vertices(&split_graph, stats[0].0);
     let right_side = reachable_vertices(&split_graph, stats[0].1);
-    Ok(format!("{}", left_side * right_side))
+    Ok((left_side * right_side).into())
 }
 
-pub fn part_2(_: &str) -> Result<String> {
-    Ok("Enter the solutions, collect stars".to_string())
+pub fn part_2(_: &str) -> Result<AnswerValue> {
+    Ok("Enter the solutions, collect stars".into())
 }
@@ -24,106 +24,66 @@ mod day_23;
 mod day_24;
 mod day_25;
 
-use shared::{Answer, Answer::SolvedBoth};
-pub const SOLUTIONS: [Answer; 25] = [
-    SolvedBoth {
-        part_1: day_01::part_1,
-        part_2: day_01::part_2,
-    },
-    SolvedBoth {
-        part_1: day_02::part_1,
-        part_2: day_02::part_2,
-    },
-    SolvedBoth {
-        part_1: day_03::part_1,
-        part_2: day_03::part_2,
-    },
-    SolvedBoth {
-        part_1: day_04::part_1,
-        part_2: day_04::part_2,
-    },
-    SolvedBoth {
-        part_1: day_05::part_1,
-        part_2: day_05::part_2,
-    },
-    SolvedBoth {
-        part_1: day_06::part_1,
-        part_2: day_06::part_2,
-    },
-    SolvedBoth {
-        part_1: day_07::part_1,
-        part_2: day_07::part_2,
-    },
-    SolvedBoth {
-        part_1: day_08::part_1,
-        part_2: day_08::part_2,
-    },
-    SolvedBoth {
-        part_1: day_09::part_1,
-        part_2: day_09::part_2,
-    },
-    SolvedBoth {
-        part_1: day_10::part_1,
-        part_2: day_10::part_2,
-    },
-    SolvedBoth {
-        part_1: day_11::part_1,
-        part_2: day_11::part_2,
-    },
-    SolvedBoth {
-        part_1: day_12::part_1,
-        part_2: day_12::part_2,
-    },
-    SolvedBoth {
-        part_1: day_13::part_1,
-        part_2: day_13::part_2,
-    },
-    SolvedBoth {
-        part_1: day_14::part_1,
-        part_2: day_14::part_2,
-    },
-    SolvedBoth {
-        part_1: day_15::part_1,
-        part_2: day_15::part_2,
-    },
-    SolvedBoth {
-        part_1: day_16::part_1,
-        part_2: day_16::part_2,
-    },
-    SolvedBoth {
-        part_1: day_17::part_1,
-        part_2: day_17::part_2,
-    },
-    SolvedBoth {
-        part_1: day_18::part_1,
-        part_2: day_18::part_2,
-    },
-    SolvedBoth {
-        part_1: day_19::part_1,
-        part_2: day_19::part_2,
-    },
-    SolvedBoth {
-        part_1: day_20::part_1,
-        part_2: day_20::part_2,
-    },
-    SolvedBoth {
-        part_1: day_21::part_1,
-        part_2: day_21::part_2,
-    },
-    SolvedBoth {
-        part_1: day_22::part_1,
-        part_2: day_22::part_2,
-    },
-    SolvedBoth {
-        part_1: day_23::part_1,
-        part_2: day_23::part_2,
-    },
-    SolvedBoth {
-        part_1: day_24::part_1,
-        part_2: day_24::part_2,
-    },
-    SolvedBoth {
-        part_1: day_25::part_1,
-        part_2: day_25::part_2,
-    },
+use shared::{Day, List, Owned, Parsed, Solution};
+const YEAR: u16 = 2023;
+
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &Day::both(YEAR, 1, day_01::part_1, day_01::part_2).titled("Trebuchet?!"),
+    &Day::both(YEAR, 2, day_02::part_1, day_02::part_2).titled("Cube Conundrum"),
+    &Day::both(YEAR, 3, day_03::part_1, day_03::part_2).titled("Gear Ratios"),
+    &Parsed::<List<day_04::CardLine>>::both(YEAR, 4, day_04::parse, day_04::part_1, day_04::part_2)
+        .titled("Scratchcards"),
+    &Parsed::<day_05::Task>::both(YEAR, 5, day_05::parse_input, day_05::part_1, day_05::part_2)
+        .titled("If You Give A Seed A Fertilizer"),
+    &Parsed::<Owned<day_06::Races>>::both(
+        YEAR,
+        6,
+        day_06::parse_races,
+        day_06::part_1,
+        day_06::part_2,
+    )
+    .titled("Wait For It"),
+    &Day::both(YEAR, 7, day_07::part_1, day_07::part_2).titled("Camel Cards"),
+    &Parsed::<day_08::Network>::both(YEAR, 8, day_08::prepare, day_08::part_1, day_08::part_2)
+        .titled("Haunted Wasteland"),
+    &Parsed::<List<Vec<i64>>>::both(YEAR, 9, day_09::parse_rows, day_09::part_1, day_09::part_2)
+        .titled("Mirage Maintenance"),
+    &Parsed::<Owned<day_10::Maze>>::both(
+        YEAR,
+        10,
+        day_10::parse_pipes,
+        day_10::part_1,
+        day_10::part_2,
+    )
+    .titled("Pipe Maze"),
+    &Day::both(YEAR, 11, day_11::part_1, day_11::part_2).titled("Cosmic Expansion"),
+    &Parsed::<List<(Vec<char>, Vec<usize>)>>::both(
+        YEAR,
+        12,
+        day_12::parse,
+        day_12::part_1,
+        day_12::part_2,
+    )
+    .titled("Hot Springs"),
+    &Day::both(YEAR, 13, day_13::part_1, day_13::part_2).titled("Point of Incidence"),
+    &Day::both(YEAR, 14, day_14::part_1, day_14::part_2).titled("Parabolic Reflector Dish"),
+    &Day::both(YEAR, 15, day_15::part_1, day_15::part_2).titled("Lens Library"),
+    &Day::both(YEAR, 16, day_16::part_1, day_16::part_2).titled("The Floor Will Be Lava"),
+    &Day::both(YEAR, 17, day_17::part_1, day_17::part_2).titled("Clumsy Crucible"),
+    &Day::both(YEAR, 18, day_18::part_1, day_18::part_2).titled("Lavaduct Lagoon"),
+    &Day::both(YEAR, 19, day_19::part_1, day_19::part_2).titled("Aplenty"),
+    &Day::both(YEAR, 20, day_20::part_1, day_20::part_2).titled("Pulse Propagation"),
+    &Day::both(YEAR, 21, day_21::part_1, day_21::part_2).titled("Step Counter"),
+    &Parsed::<List<day_22::Brick>>::both(YEAR, 22, day_22::parse, day_22::part_1, day_22::part_2)
+        .titled("Sand Slabs"),
+    &Day::both(YEAR, 23, day_23::part_1, day_23::part_2).titled("A Long Walk"),
+    &Parsed::<List<day_24::Hailstone<i64>>>::both(
+        YEAR,
+        24,
+        day_24::parse_hailstones,
+        day_24::part_1,
+        day_24::part_2,
+    )
+    .titled("Never Tell Me The Odds"),
+    &Day::both(YEAR, 25, day_25::part_1, day_25::part_2).titled("Snowverload"),
 ];
@@ -1,7 +1,8 @@
 use fxhash::FxHashMap;
+use shared::AnswerValue;
 use std::num::ParseIntError;
 
-fn parse_lists(input: &str) -> Result<(Vec<i32>, Vec<i32>), ParseIntError> {
+pub fn parse_lists(input: &str) -> anyhow::Result<(Vec<i32>, Vec<i32>)> {
     let parsed: Result<Vec<i32>, ParseIntError> =
         input.split_ascii_whitespace().map(|n| n.parse()).collect();
     let mut left = Vec::new();
@@ -16,8 +17,8 @@ fn parse_lists(input: &str) -> Result<(Vec<i32>, Vec<i32>), ParseIntError> {
     Ok((left, right))
 }
 
-pub fn part_1(input: &str) -> anyhow::Result<String> {
-    let (mut left, mut right) = parse_lists(input)?;
+pub fn part_1((left, right): &(Vec<i32>, Vec<i32>)) -> anyhow::Result<AnswerValue> {
+    let (mut left, mut right) = (left.clone(), right.clone());
     left.sort();
     right.sort();
     let dist = left
@@ -26,11 +27,10 @@ pub fn part_1(input: &str) -> anyhow::Result<String> {
         .map(|(l, r)| (l - r).abs())
         .sum::<i32>();
 
-    Ok(format!("{dist}"))
+    Ok(dist.into())
 }
 
-pub fn part_2(input: &str) -> anyhow::Result<String> {
-    let (left, right) = parse_lists(input)?;
+pub fn part_2((left, right): &(Vec<i32>, Vec<i32>)) -> anyhow::Result<AnswerValue> {
     let mut counter: FxHashMap<i32, i32> = FxHashMap::default();
     right.iter().for_each(|n| {
         *counter.entry(*n).or_insert(0) += 1;
@@ -39,7 +39,7 @@ pub fn part_2(input: &str) -> anyhow::Result<String> {
         .iter()
         .map(|n| n * counter.get(n).unwrap_or(&0))
         .sum::<i32>();
-    Ok(format!("{similarity_score}"))
+    Ok(similarity_score.into())
 }
 
 #[cfg(test)]
@@ -62,11 +62,17 @@ mod tests {
 
     #[test]
     fn test_part_1() {
-        assert_eq!("11", part_1(EXAMPLE).unwrap());
+        assert_eq!(
+            part_1(&parse_lists(EXAMPLE).unwrap()).unwrap(),
+            AnswerValue::Integer(11)
+        );
     }
 
     #[test]
     fn test_part_2() {
-        assert_eq!("31", part_2(EXAMPLE).unwrap());
+        assert_eq!(
+            part_2(&parse_lists(EXAMPLE).unwrap()).unwrap(),
+            AnswerValue::Integer(31)
+        );
     }
 }
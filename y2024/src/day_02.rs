@@ -1,17 +1,19 @@
 use fxhash::FxHashSet;
 use itertools::Itertools;
+use shared::AnswerValue;
 use std::num::ParseIntError;
 
 fn parse_line(input: &str) -> Result<Vec<i32>, ParseIntError> {
     input.split_whitespace().map(|n| n.parse::<i32>()).collect()
 }
 
-fn parse(input: &str) -> Result<Vec<Vec<i32>>, ParseIntError> {
-    input
+pub fn parse(input: &str) -> anyhow::Result<Vec<Vec<i32>>> {
+    let reports: Result<_, ParseIntError> = input
         .lines()
         .filter(|n| !n.is_empty())
         .map(parse_line)
-        .collect()
+        .collect();
+    Ok(reports?)
 }
 
 fn signum(n: i32) -> i32 {
@@ -37,19 +39,17 @@ fn safe_with_one_drop(report: &[i32]) -> bool {
     })
 }
 
-pub fn part_1(input: &str) -> anyhow::Result<String> {
-    let reports = parse(input)?;
+pub fn part_1(reports: &[Vec<i32>]) -> anyhow::Result<AnswerValue> {
     let n = reports.iter().filter(|report| safe(report)).count();
-    Ok(format!("{n}"))
+    Ok(n.into())
 }
 
-pub fn part_2(input: &str) -> anyhow::Result<String> {
-    let reports = parse(input)?;
+pub fn part_2(reports: &[Vec<i32>]) -> anyhow::Result<AnswerValue> {
     let n = reports
         .iter()
         .filter(|report| safe_with_one_drop(report))
         .count();
-    Ok(format!("{n}"))
+    Ok(n.into())
 }
 
 #[cfg(test)]
@@ -66,7 +66,13 @@ mod tests {
 
     #[test]
     fn test_safe_reports() {
-        assert_eq!(part_1(EXAMPLE).unwrap().as_str(), "2");
-        assert_eq!(part_2(EXAMPLE).unwrap().as_str(), "4");
+        assert_eq!(
+            part_1(&parse(EXAMPLE).unwrap()).unwrap(),
+            AnswerValue::Integer(2)
+        );
+        assert_eq!(
+            part_2(&parse(EXAMPLE).unwrap()).unwrap(),
+            AnswerValue::Integer(4)
+        );
     }
 }
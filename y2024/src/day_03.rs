@@ -1,4 +1,5 @@
 use regex::Regex;
+use shared::AnswerValue;
 
 fn sum_muls(input: &str) -> anyhow::Result<(i32, i32)> {
     let mul_re = Regex::new(r#"(mul\((\d+),(\d+)\))|(do\(\))|(don't\(\))"#)?;
@@ -17,14 +18,14 @@ fn sum_muls(input: &str) -> anyhow::Result<(i32, i32)> {
     Ok((p1, p2))
 }
 
-pub fn part_1(input: &str) -> anyhow::Result<String> {
+pub fn part_1(input: &str) -> anyhow::Result<AnswerValue> {
     let (p1, _) = sum_muls(input)?;
-    Ok(format!("{p1}"))
+    Ok(p1.into())
 }
 
-pub fn part_2(input: &str) -> anyhow::Result<String> {
+pub fn part_2(input: &str) -> anyhow::Result<AnswerValue> {
     let (_, p2) = sum_muls(input)?;
-    Ok(format!("{p2}"))
+    Ok(p2.into())
 }
 
 #[cfg(test)]
@@ -1,11 +1,12 @@
 use itertools::Itertools;
+use shared::AnswerValue;
 
-fn parse_grid(input: &str) -> Vec<Vec<u8>> {
-    input
+pub fn parse_grid(input: &str) -> anyhow::Result<Vec<Vec<u8>>> {
+    Ok(input
         .lines()
         .filter(|l| !l.is_empty())
         .map(|line| line.trim().as_bytes().to_vec())
-        .collect()
+        .collect())
 }
 
 const DIRS: [[i32; 2]; 8] = [
@@ -67,16 +68,14 @@ fn count_mas(grid: &[Vec<u8>]) -> usize {
     count
 }
 
-pub fn part_1(input: &str) -> anyhow::Result<String> {
-    let grid = parse_grid(input);
-    let n = count_xmas(&grid);
-    Ok(format!("{n}"))
+pub fn part_1(grid: &[Vec<u8>]) -> anyhow::Result<AnswerValue> {
+    let n = count_xmas(grid);
+    Ok(n.into())
 }
 
-pub fn part_2(input: &str) -> anyhow::Result<String> {
-    let grid = parse_grid(input);
-    let n = count_mas(&grid);
-    Ok(format!("{n}"))
+pub fn part_2(grid: &[Vec<u8>]) -> anyhow::Result<AnswerValue> {
+    let n = count_mas(grid);
+    Ok(n.into())
 }
 
 #[cfg(test)]
@@ -95,12 +94,12 @@ MAMMMXMMMM
 MXMXAXMASX";
     #[test]
     fn test_p1() {
-        let grid = parse_grid(EXAMPLE);
+        let grid = parse_grid(EXAMPLE).unwrap();
         assert_eq!(count_xmas(&grid), 18);
     }
     #[test]
     fn test_p2() {
-        let grid = parse_grid(EXAMPLE);
+        let grid = parse_grid(EXAMPLE).unwrap();
         assert_eq!(count_mas(&grid), 9);
     }
 }
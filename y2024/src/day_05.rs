@@ -1,6 +1,7 @@
 use anyhow::Context;
 use fxhash::FxHashSet;
 use itertools::Itertools;
+use shared::AnswerValue;
 use std::cmp::Ordering;
 
 #[derive(Debug)]
@@ -67,12 +68,12 @@ fn solve(input: &str, part: Part) -> anyhow::Result<usize> {
     Ok(total)
 }
 
-pub fn part_1(input: &str) -> anyhow::Result<String> {
-    Ok(format!("{}", solve(input, Part::Part1)?))
+pub fn part_1(input: &str) -> anyhow::Result<AnswerValue> {
+    Ok(solve(input, Part::Part1)?.into())
 }
 
-pub fn part_2(input: &str) -> anyhow::Result<String> {
-    Ok(format!("{}", solve(input, Part::Part2)?))
+pub fn part_2(input: &str) -> anyhow::Result<AnswerValue> {
+    Ok(solve(input, Part::Part2)?.into())
 }
 
 #[cfg(test)]
@@ -1,6 +1,7 @@
 use anyhow::Context;
 use fxhash::{FxHashMap, FxHashSet};
 use rayon::prelude::*;
+use shared::AnswerValue;
 
 const DIRS: [(i32, i32); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
 
@@ -87,14 +88,14 @@ fn build_path_cache(grid: &Grid, mut pos: (i32, i32)) -> PathCache {
     }
 }
 
-pub fn part_1(input: &str) -> anyhow::Result<String> {
+pub fn part_1(input: &str) -> anyhow::Result<AnswerValue> {
     let grid = Grid::from(input);
     let origin = find_in_grid(input, '^')
         .next()
         .context("Origin not found")?;
     let cache = build_path_cache(&grid, origin);
     let unique_positions: FxHashSet<_> = positions_in_grid(&cache, &grid).collect();
-    Ok(format!("{}", unique_positions.len()))
+    Ok(unique_positions.len().into())
 }
 
 fn would_loop(grid: &Grid, mut pos: (i32, i32), cache: &PathCache, obs: (i32, i32)) -> bool {
@@ -123,7 +124,7 @@ fn would_loop(grid: &Grid, mut pos: (i32, i32), cache: &PathCache, obs: (i32, i3
     }
 }
 
-pub fn part_2(input: &str) -> anyhow::Result<String> {
+pub fn part_2(input: &str) -> anyhow::Result<AnswerValue> {
     let grid = Grid::from(input);
     let origin = find_in_grid(input, '^')
         .next()
@@ -136,7 +137,7 @@ pub fn part_2(input: &str) -> anyhow::Result<String> {
         .par_iter()
         .filter(|obs| would_loop(&grid, origin, &cache, **obs))
         .count();
-    Ok(format!("{}", p2))
+    Ok(p2.into())
 }
 
 #[cfg(test)]
@@ -156,11 +157,11 @@ mod tests {
 
     #[test]
     fn test_p1() {
-        assert_eq!(part_1(EXAMPLE).unwrap().as_str(), "41");
+        assert_eq!(part_1(EXAMPLE).unwrap(), AnswerValue::Integer(41));
     }
 
     #[test]
     fn test_p2() {
-        assert_eq!(part_2(EXAMPLE).unwrap().as_str(), "6");
+        assert_eq!(part_2(EXAMPLE).unwrap(), AnswerValue::Integer(6));
     }
 }
@@ -1,12 +1,13 @@
 use anyhow::Context;
+use shared::AnswerValue;
 
 #[derive(Debug, Eq, PartialEq)]
-struct Equation {
+pub struct Equation {
     equals: u64,
     operands: Vec<u64>,
 }
 
-fn parse_equations(input: &str) -> anyhow::Result<Vec<Equation>> {
+pub fn parse_equations(input: &str) -> anyhow::Result<Vec<Equation>> {
     input
         .lines()
         .filter(|line| !line.is_empty())
@@ -61,21 +62,19 @@ fn concat(left: u64, right: u64) -> u64 {
     10u64.pow(exp) * left + right
 }
 
-pub fn part_1(input: &str) -> anyhow::Result<String> {
-    let equations = parse_equations(input)?;
+pub fn part_1(equations: &[Equation]) -> anyhow::Result<AnswerValue> {
     #[allow(clippy::useless_vec)] // does not actually compile if you remove vec!
-    let n = sum_reducible(&equations, &vec![|x, y| x * y, (|x, y| x + y)]);
-    Ok(format!("{n}"))
+    let n = sum_reducible(equations, &vec![|x, y| x * y, (|x, y| x + y)]);
+    Ok(n.into())
 }
 
-pub fn part_2(input: &str) -> anyhow::Result<String> {
-    let equations = parse_equations(input)?;
+pub fn part_2(equations: &[Equation]) -> anyhow::Result<AnswerValue> {
     #[allow(clippy::useless_vec)] // does not actually compile if you remove vec!
     let n = sum_reducible(
-        &equations,
+        equations,
         &vec![|x, y| x * y, |x, y| x + y, |x, y| concat(x, y)],
     );
-    Ok(format!("{n}"))
+    Ok(n.into())
 }
 
 #[cfg(test)]
@@ -95,12 +94,18 @@ mod tests {
 
     #[test]
     fn test_p1() {
-        assert_eq!(part_1(EXAMPLE).unwrap().as_str(), "3749");
+        assert_eq!(
+            part_1(&parse_equations(EXAMPLE).unwrap()).unwrap(),
+            AnswerValue::Integer(3749)
+        );
     }
 
     #[test]
     fn test_p2() {
-        assert_eq!(part_2(EXAMPLE).unwrap().as_str(), "11387");
+        assert_eq!(
+            part_2(&parse_equations(EXAMPLE).unwrap()).unwrap(),
+            AnswerValue::Integer(11387)
+        );
     }
 
     #[test]
@@ -1,4 +1,5 @@
 use fxhash::{FxHashMap, FxHashSet};
+use shared::AnswerValue;
 
 struct Grid {
     height: i32,
@@ -72,22 +73,22 @@ fn p2_antinodes(
     gen_antinodes(a_1, a_2, grid, -1).chain(gen_antinodes(a_1, a_2, grid, 1))
 }
 
-pub fn part_1(input: &str) -> anyhow::Result<String> {
+pub fn part_1(input: &str) -> anyhow::Result<AnswerValue> {
     let grid = Grid::parse(input);
     let antinodes: FxHashSet<_> = grid
         .pairs()
         .flat_map(|(a, b)| p1_antinodes(a, b, &grid))
         .collect();
-    Ok(format!("{}", antinodes.len()))
+    Ok(antinodes.len().into())
 }
 
-pub fn part_2(input: &str) -> anyhow::Result<String> {
+pub fn part_2(input: &str) -> anyhow::Result<AnswerValue> {
     let grid = Grid::parse(input);
     let antinodes: FxHashSet<_> = grid
         .pairs()
         .flat_map(|(a, b)| p2_antinodes(a, b, &grid))
         .collect();
-    Ok(format!("{}", antinodes.len()))
+    Ok(antinodes.len().into())
 }
 
 #[cfg(test)]
@@ -108,11 +109,11 @@ mod tests {
 ";
     #[test]
     fn test_p1() {
-        assert_eq!(part_1(EXAMPLE).unwrap().as_str(), "14");
+        assert_eq!(part_1(EXAMPLE).unwrap(), AnswerValue::Integer(14));
     }
 
     #[test]
     fn test_p2() {
-        assert_eq!(part_2(EXAMPLE).unwrap().as_str(), "34");
+        assert_eq!(part_2(EXAMPLE).unwrap(), AnswerValue::Integer(34));
     }
 }
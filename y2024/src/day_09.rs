@@ -1,3 +1,4 @@
+use shared::AnswerValue;
 use std::cmp::{Ordering, Reverse};
 use std::collections::BinaryHeap;
 
@@ -82,7 +83,7 @@ fn partition(input: &str) -> (BinaryHeap<Block>, BinaryHeap<Reverse<Block>>) {
     (files, empty)
 }
 
-pub fn part_1(input: &str) -> anyhow::Result<String> {
+pub fn part_1(input: &str) -> anyhow::Result<AnswerValue> {
     let (mut files, mut empty) = partition(input);
     let mut diskmap = Vec::new();
 
@@ -130,10 +131,10 @@ pub fn part_1(input: &str) -> anyhow::Result<String> {
         }
     }
     let checksum = checksum_diskmap(&diskmap);
-    Ok(format!("{checksum}"))
+    Ok(checksum.into())
 }
 
-pub fn part_2(input: &str) -> anyhow::Result<String> {
+pub fn part_2(input: &str) -> anyhow::Result<AnswerValue> {
     let mut diskmap = vec![];
     let (mut files, empty): (Vec<_>, Vec<_>) =
         parse(input).partition(|block| matches!(block.state, NodeState::Contains(_)));
@@ -174,7 +175,7 @@ pub fn part_2(input: &str) -> anyhow::Result<String> {
         }
     }
     let checksum = checksum_diskmap(&diskmap);
-    Ok(format!("{checksum}"))
+    Ok(checksum.into())
 }
 
 #[cfg(test)]
@@ -199,12 +200,12 @@ mod tests {
     #[test]
     fn test_part_1() {
         let example = "2333133121414131402";
-        assert_eq!(part_1(example).unwrap().as_str(), "1928");
+        assert_eq!(part_1(example).unwrap(), AnswerValue::Integer(1928));
     }
 
     #[test]
     fn test_part_2() {
         let example = "2333133121414131402";
-        assert_eq!(part_2(example).unwrap().as_str(), "2858");
+        assert_eq!(part_2(example).unwrap(), AnswerValue::Integer(2858));
     }
 }
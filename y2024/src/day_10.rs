@@ -1,94 +1,45 @@
+use anyhow::Result;
 use fxhash::FxHashSet;
-use itertools::Itertools;
+use shared::AnswerValue;
+use shared::Grid;
 use std::collections::VecDeque;
 
-struct Grid {
-    height: i32,
-    width: i32,
-    grid: Vec<u8>,
+pub fn parse(input: &str) -> Result<Grid<u8>> {
+    Grid::parse(input, |ch| ch.to_digit(10).map(|d| d as u8))
 }
 
-impl Grid {
-    fn parse(input: &str) -> Grid {
-        let lines = input.lines().filter(|l| !l.is_empty());
-        let height = lines.clone().count() as i32;
-        let width = lines.clone().next().map(|l| l.chars().count()).unwrap_or(0) as i32;
-        let grid = input
-            .lines()
-            .flat_map(|line| line.trim().as_bytes().iter().map(|b| *b - b'0'))
-            .collect();
-        Grid {
-            height,
-            width,
-            grid,
-        }
-    }
-    fn contains(&self, x: i32, y: i32) -> bool {
-        (0..self.width).contains(&x) && (0..self.height).contains(&y)
-    }
-    fn at(&self, x: i32, y: i32) -> Option<u8> {
-        if self.contains(x, y) {
-            Some(self.grid[(x + y * self.width) as usize])
-        } else {
-            None
-        }
-    }
-
-    fn trailheads(&self) -> impl Iterator<Item = (i32, i32)> + '_ {
-        (0..self.width)
-            .cartesian_product(0..self.height)
-            .filter(|(x, y)| self.at(*x, *y) == Some(0))
-    }
-}
-
-const DIRS: [(i32, i32); 4] = [(0, 1), (0, -1), (1, 0), (-1, 0)];
-
-pub fn part_1(input: &str) -> anyhow::Result<String> {
-    let map = Grid::parse(input);
+pub fn part_1(map: &Grid<u8>) -> Result<AnswerValue> {
     let mut score = 0;
     let mut visited = FxHashSet::default();
     let mut work = VecDeque::new();
-    for (x, y) in map.trailheads() {
+    for (x, y) in map.find_all(&0) {
         work.push_back((x, y));
         while let Some((x, y)) = work.pop_front() {
-            if map.contains(x, y) && visited.insert((x, y)) {
-                let height = map.at(x, y).unwrap();
-                for (dx, dy) in DIRS {
-                    if map.at(x + dx, y + dy) == Some(height + 1) {
-                        work.push_back((x + dx, y + dy));
-                    }
-                }
+            if visited.insert((x, y)) {
+                let height = map[(x, y)];
+                work.extend(map.neighbours_4(x, y).filter(|&n| map[n] == height + 1));
             }
         }
-        score += visited
-            .iter()
-            .filter(|(x, y)| map.at(*x, *y) == Some(9))
-            .count();
+        score += visited.iter().filter(|&&pos| map[pos] == 9).count();
         visited.clear();
     }
-    Ok(format!("{score}"))
+    Ok(score.into())
 }
 
-pub fn part_2(input: &str) -> anyhow::Result<String> {
-    let map = Grid::parse(input);
+pub fn part_2(map: &Grid<u8>) -> Result<AnswerValue> {
     let mut score = 0;
     let mut work = VecDeque::new();
-    for (x, y) in map.trailheads() {
+    for (x, y) in map.find_all(&0) {
         work.push_back((x, y));
         while let Some((x, y)) = work.pop_front() {
-            if map.at(x, y) == Some(9) {
+            if map[(x, y)] == 9 {
                 score += 1;
             }
-            if let Some(height) = map.at(x, y) {
-                for (dx, dy) in DIRS {
-                    if map.at(x + dx, y + dy) == Some(height + 1) {
-                        work.push_back((x + dx, y + dy));
-                    }
-                }
-            }
+            let height = map[(x, y)];
+            work.extend(map.neighbours_4(x, y).filter(|&n| map[n] == height + 1));
         }
     }
-    Ok(format!("{score}"))
+    Ok(score.into())
 }
 
 #[cfg(test)]
@@ -105,10 +56,16 @@ mod tests {
 ";
     #[test]
     fn test_p1() {
-        assert_eq!(part_1(EXAMPLE).unwrap(), "36");
+        assert_eq!(
+            part_1(&parse(EXAMPLE).unwrap()).unwrap(),
+            AnswerValue::Integer(36)
+        );
     }
     #[test]
     fn test_p2() {
-        assert_eq!(part_2(EXAMPLE).unwrap(), "81");
+        assert_eq!(
+            part_2(&parse(EXAMPLE).unwrap()).unwrap(),
+            AnswerValue::Integer(81)
+        );
     }
 }
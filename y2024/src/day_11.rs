@@ -1,5 +1,6 @@
 use anyhow::Result;
 use fxhash::FxHashMap;
+use shared::AnswerValue;
 use std::mem;
 
 fn digits(n: u64) -> impl Iterator<Item = u8> {
@@ -60,12 +61,12 @@ fn blinks(input: &str, rounds: u64) -> Result<u64> {
     Ok(stones.values().sum())
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    blinks(input, 25).map(|n| format!("{n}"))
+pub fn part_1(input: &str) -> Result<AnswerValue> {
+    blinks(input, 25).map(AnswerValue::from)
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    blinks(input, 75).map(|n| format!("{n}"))
+pub fn part_2(input: &str) -> Result<AnswerValue> {
+    blinks(input, 75).map(AnswerValue::from)
 }
 
 #[cfg(test)]
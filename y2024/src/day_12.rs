@@ -1,5 +1,6 @@
 use fxhash::FxHashSet;
 use itertools::Itertools;
+use shared::AnswerValue;
 
 struct Grid {
     height: i32,
@@ -33,7 +34,7 @@ impl Grid {
 
 const DIRS: [(i32, i32); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
 
-pub fn part_1(input: &str) -> anyhow::Result<String> {
+pub fn part_1(input: &str) -> anyhow::Result<AnswerValue> {
     let grid = Grid::parse(input);
     let mut claimed: FxHashSet<(i32, i32)> = FxHashSet::default();
     let mut work: Vec<_> = (0..grid.width)
@@ -67,10 +68,10 @@ pub fn part_1(input: &str) -> anyhow::Result<String> {
         price += perimeter * area;
     }
 
-    Ok(format!("{price}"))
+    Ok(price.into())
 }
 
-pub fn part_2(input: &str) -> anyhow::Result<String> {
+pub fn part_2(input: &str) -> anyhow::Result<AnswerValue> {
     let grid = Grid::parse(input);
     let mut claimed: FxHashSet<(i32, i32)> = FxHashSet::default();
     let mut work: Vec<_> = (0..grid.width)
@@ -113,7 +114,7 @@ pub fn part_2(input: &str) -> anyhow::Result<String> {
         price += corners * area;
     }
 
-    Ok(format!("{price}"))
+    Ok(price.into())
 }
 
 #[cfg(test)]
@@ -126,11 +127,11 @@ EEEC
 ";
     #[test]
     fn test_p1() {
-        assert_eq!(part_1(EXAMPLE).unwrap().as_str(), "140");
+        assert_eq!(part_1(EXAMPLE).unwrap(), AnswerValue::Integer(140));
     }
 
     #[test]
     fn test_p2() {
-        assert_eq!(part_2(EXAMPLE).unwrap().as_str(), "80");
+        assert_eq!(part_2(EXAMPLE).unwrap(), AnswerValue::Integer(80));
     }
 }
@@ -4,6 +4,7 @@ use nom::combinator::map_res;
 use nom::multi::separated_list1;
 use nom::sequence::{preceded, tuple};
 use nom::IResult;
+use shared::AnswerValue;
 use std::str::FromStr;
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -87,12 +88,12 @@ fn solve(input: &str, p2: bool) -> anyhow::Result<i64> {
         .sum())
 }
 
-pub fn part_1(input: &str) -> anyhow::Result<String> {
-    solve(input, false).map(|n| format!("{n}"))
+pub fn part_1(input: &str) -> anyhow::Result<AnswerValue> {
+    solve(input, false).map(AnswerValue::from)
 }
 
-pub fn part_2(input: &str) -> anyhow::Result<String> {
-    solve(input, true).map(|n| format!("{n}"))
+pub fn part_2(input: &str) -> anyhow::Result<AnswerValue> {
+    solve(input, true).map(AnswerValue::from)
 }
 
 #[cfg(test)]
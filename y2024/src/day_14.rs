@@ -6,9 +6,10 @@ use nom::combinator::{map, map_res};
 use nom::multi::separated_list1;
 use nom::sequence::{preceded, separated_pair};
 use nom::IResult;
+use shared::AnswerValue;
 
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
-struct Bot {
+pub struct Bot {
     p_x: i32,
     v_x: i32,
     p_y: i32,
@@ -29,7 +30,7 @@ fn parse_bot(input: &str) -> IResult<&str, Bot> {
     Ok((input, Bot { p_x, p_y, v_x, v_y }))
 }
 
-fn parse(input: &str) -> anyhow::Result<Vec<Bot>> {
+pub fn parse(input: &str) -> anyhow::Result<Vec<Bot>> {
     separated_list1(char('\n'), parse_bot)(input)
         .map_err(|e| anyhow::anyhow!("{}", e))
         .map(|(_, bots)| bots)
@@ -67,23 +68,21 @@ fn quadrants(pos: &[(i32, i32)], height: i32, width: i32) -> [i32; 4] {
     counts
 }
 
-pub fn part_1(input: &str) -> anyhow::Result<String> {
-    let bots = parse(input)?;
-    let pos = bot_positions(&bots, 100, 103, 101).collect_vec();
+pub fn part_1(bots: &[Bot]) -> anyhow::Result<AnswerValue> {
+    let pos = bot_positions(bots, 100, 103, 101).collect_vec();
     let counts = quadrants(&pos, 103, 101);
     let score: i32 = counts.iter().product();
-    Ok(format!("{score}"))
+    Ok(score.into())
 }
 
-pub fn part_2(input: &str) -> anyhow::Result<String> {
-    let bots = parse(input)?;
+pub fn part_2(bots: &[Bot]) -> anyhow::Result<AnswerValue> {
     let (height, width) = (103, 101);
     let (mut max_x, mut max_y, mut x_t, mut y_t) = (0, 0, 0, 0);
     for time in 0..height.max(width) {
         let mut x_freq = vec![0; width];
         let mut y_freq = vec![0; height];
 
-        for (x, y) in bot_positions(&bots, time as i32, height as i32, width as i32) {
+        for (x, y) in bot_positions(bots, time as i32, height as i32, width as i32) {
             x_freq[x as usize] += 1;
             y_freq[y as usize] += 1;
         }
@@ -106,7 +105,7 @@ pub fn part_2(input: &str) -> anyhow::Result<String> {
         time += width;
     }
     // Now time % width = x_t and time % height = y_t, so both cycles are aligned
-    Ok(format!("{time}"))
+    Ok(time.into())
 }
 
 #[cfg(test)]
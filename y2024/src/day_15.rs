@@ -1,8 +1,9 @@
 use anyhow::{anyhow, Context};
 use fxhash::FxHashSet;
+use shared::AnswerValue;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
-enum Dir {
+pub enum Dir {
     Left,
     Right,
     Up,
@@ -40,7 +41,8 @@ trait Warehouse {
     }
 }
 
-struct Map {
+#[derive(Clone)]
+pub struct Map {
     boxes: FxHashSet<(i32, i32)>,
     walls: FxHashSet<(i32, i32)>,
     bot: (i32, i32),
@@ -193,7 +195,10 @@ impl TryFrom<&str> for Map {
     }
 }
 
-fn parse(input: &str) -> anyhow::Result<(Map, Vec<Dir>)> {
+/// The warehouse map and the moves the robot attempts.
+pub type Problem = (Map, Vec<Dir>);
+
+pub fn parse(input: &str) -> anyhow::Result<Problem> {
     let (map, instr) = input.split_once("\n\n").context("Malformed input")?;
     let map = map.try_into()?;
     let instr: anyhow::Result<Vec<_>> = instr
@@ -243,17 +248,16 @@ impl From<Map> for SupersizedMap {
     }
 }
 
-pub fn part_1(input: &str) -> anyhow::Result<String> {
-    let (mut map, instr) = parse(input)?;
-    let score = map.moves(&instr);
-    Ok(format!("{score}"))
+pub fn part_1((map, instr): &Problem) -> anyhow::Result<AnswerValue> {
+    let mut map = map.clone();
+    let score = map.moves(instr);
+    Ok(score.into())
 }
 
-pub fn part_2(input: &str) -> anyhow::Result<String> {
-    let (map, instr) = parse(input)?;
-    let mut map: SupersizedMap = map.into();
-    let score = map.moves(&instr);
-    Ok(format!("{score}"))
+pub fn part_2((map, instr): &Problem) -> anyhow::Result<AnswerValue> {
+    let mut map: SupersizedMap = map.clone().into();
+    let score = map.moves(instr);
+    Ok(score.into())
 }
 
 #[cfg(test)]
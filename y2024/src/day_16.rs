@@ -1,11 +1,11 @@
-use anyhow::{anyhow, Context};
-use fxhash::{FxHashMap, FxHashSet};
-use std::cmp::Reverse;
-use std::collections::BinaryHeap;
+use anyhow::Context;
+use fxhash::FxHashSet;
+use shared::search::{all_shortest_paths, ShortestPaths};
+use shared::AnswerValue;
 
-type Pos = (i32, i32);
+pub type Pos = (i32, i32);
 
-struct Maze {
+pub struct Maze {
     walls: FxHashSet<Pos>,
 }
 
@@ -46,19 +46,12 @@ impl Direction {
             West => (x - 1, y),
         }
     }
-    fn backward(&self, pos: Pos) -> Pos {
-        self.clockwise().clockwise().step(pos)
-    }
 }
 
-#[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Copy, Clone)]
-struct State {
-    cost: i64,
-    pos: Pos,
-    dir: Direction,
-}
+/// The maze with its start and end tiles.
+pub type Problem = (Maze, Pos, Pos);
 
-fn parse(input: &str) -> anyhow::Result<(Maze, Pos, Pos)> {
+pub fn parse(input: &str) -> anyhow::Result<Problem> {
     let by_idx = input.lines().enumerate().flat_map(|(y, line)| {
         line.chars()
             .enumerate()
@@ -78,86 +71,40 @@ fn parse(input: &str) -> anyhow::Result<(Maze, Pos, Pos)> {
     Ok((Maze { walls }, start, end))
 }
 
-type Cache = FxHashMap<(Pos, Direction), i64>;
+type State = (Pos, Direction);
 
-fn solve(maze: &Maze, start: Pos, end: Pos) -> anyhow::Result<(i64, Cache)> {
-    let mut work = BinaryHeap::default();
-    let mut cache: Cache = FxHashMap::default();
-    work.push(Reverse(State {
-        cost: 0,
-        pos: start,
-        dir: Direction::East,
-    }));
+fn successors<'a>(maze: &'a Maze, &(pos, dir): &State) -> impl Iterator<Item = (State, i64)> + 'a {
+    [
+        ((dir.step(pos), dir), 1),
+        ((pos, dir.clockwise()), 1000),
+        ((pos, dir.counterclockwise()), 1000),
+    ]
+    .into_iter()
+    .filter(|((pos, _), _)| !maze.walls.contains(pos))
+}
 
-    while let Some(Reverse(State { cost, pos, dir })) = work.pop() {
-        if pos == end {
-            return Ok((cost, cache));
-        }
-        let next = [
-            State {
-                dir,
-                cost: cost + 1,
-                pos: dir.step(pos),
-            },
-            State {
-                pos,
-                dir: dir.clockwise(),
-                cost: cost + 1000,
-            },
-            State {
-                pos,
-                dir: dir.counterclockwise(),
-                cost: cost + 1000,
-            },
-        ];
-        for state in next {
-            if &state.cost < cache.get(&(state.pos, state.dir)).unwrap_or(&i64::MAX)
-                && !maze.walls.contains(&state.pos)
-            {
-                work.push(Reverse(state));
-                cache.insert((state.pos, state.dir), state.cost);
-            }
-        }
-    }
-    Err(anyhow!("Unable to solve maze"))
+fn solve(maze: &Maze, start: Pos, end: Pos) -> anyhow::Result<ShortestPaths<State, i64>> {
+    all_shortest_paths(
+        (start, Direction::East),
+        |state| successors(maze, state),
+        |&(pos, _)| pos == end,
+    )
+    .context("Unable to solve maze")
 }
 
-pub fn part_1(input: &str) -> anyhow::Result<String> {
-    let (maze, start, end) = parse(input)?;
-    let (cost, _) = solve(&maze, start, end)?;
-    Ok(format!("{cost}"))
+pub fn part_1(&(ref maze, start, end): &Problem) -> anyhow::Result<AnswerValue> {
+    let paths = solve(maze, start, end)?;
+    Ok(paths.cost.into())
 }
 
-pub fn part_2(input: &str) -> anyhow::Result<String> {
-    use Direction::*;
-    let (maze, start, end) = parse(input)?;
-    // costs contain the cheapest way to reach the (pos, dir) pair
-    let (cost, costs) = solve(&maze, start, end)?;
-    // Locate the ending location / orientation
-    let dir = [North, West, East, South]
+pub fn part_2(&(ref maze, start, end): &Problem) -> anyhow::Result<AnswerValue> {
+    // Any tile on any of the cheapest paths, no matter which way we were facing
+    let tiles: FxHashSet<_> = solve(maze, start, end)?
+        .nodes()
         .into_iter()
-        .find(|dir| costs.contains_key(&(end, *dir)))
-        .context("Apparently didn't solve maze?")?;
-    let mut visited = FxHashSet::default();
-    // We can only push locations that are part of the solution here
-    let mut work = vec![(cost, end, dir)];
-
-    while let Some((cost, loc, dir)) = work.pop() {
-        visited.insert(loc);
-        let possible_parents = [
-            (dir.backward(loc), dir, cost - 1),
-            (loc, dir.clockwise(), cost - 1000),
-            (loc, dir.counterclockwise(), cost - 1000),
-        ];
-        for (parent_loc, parent_dir, parent_cost) in possible_parents {
-            if costs.get(&(parent_loc, parent_dir)) == Some(&parent_cost) {
-                // Could've come from here
-                work.push((parent_cost, parent_loc, parent_dir));
-            }
-        }
-    }
-
-    Ok(format!("{}", visited.len()))
+        .map(|(pos, _)| *pos)
+        .collect();
+    Ok(tiles.len().into())
 }
 
 #[cfg(test)]
@@ -183,13 +130,13 @@ mod tests {
     #[test]
     fn test_solve_1() {
         let (maze, start, end) = parse(EXAMPLE).unwrap();
-        let (cost, _) = solve(&maze, start, end).unwrap();
-        assert_eq!(cost, 7036);
+        let paths = solve(&maze, start, end).unwrap();
+        assert_eq!(paths.cost, 7036);
     }
 
     #[test]
     fn test_solve_2() {
-        let ans = part_2(EXAMPLE).unwrap();
-        assert_eq!(ans.as_str(), "45");
+        let ans = part_2(&parse(EXAMPLE).unwrap()).unwrap();
+        assert_eq!(ans, AnswerValue::Integer(45));
     }
 }
@@ -8,6 +8,7 @@ use nom::combinator::{map, map_res, success};
 use nom::multi::separated_list1;
 use nom::sequence::preceded;
 use nom::IResult;
+use shared::AnswerValue;
 use std::collections::VecDeque;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -244,17 +245,13 @@ fn run_program_with_a_reg(computer: &mut Computer, a_reg: i64) -> anyhow::Result
     Ok(output)
 }
 
-pub fn part_1(input: &str) -> anyhow::Result<String> {
+pub fn part_1(input: &str) -> anyhow::Result<AnswerValue> {
     let mut computer = Computer::try_from(input)?;
     let output = run_program(&mut computer)?;
-    Ok(output
-        .into_iter()
-        .map(|n| format!("{n}"))
-        .join(",")
-        .to_string())
+    Ok(output.into_iter().join(",").into())
 }
 
-pub fn part_2(input: &str) -> anyhow::Result<String> {
+pub fn part_2(input: &str) -> anyhow::Result<AnswerValue> {
     let mut computer = Computer::try_from(input)?;
     let mut work = VecDeque::from([0]);
     let prog = computer.program.clone().into_iter().rev().collect_vec();
@@ -274,7 +271,7 @@ pub fn part_2(input: &str) -> anyhow::Result<String> {
         }
     }
     let sol = work.pop_front().context("Unable to solve")?;
-    Ok(format!("{sol}"))
+    Ok(sol.into())
 }
 
 #[cfg(test)]
@@ -1,5 +1,6 @@
 use anyhow::{anyhow, Context, Result};
 use fxhash::FxHashSet;
+use shared::AnswerValue;
 use std::cmp::Reverse;
 use std::collections::BinaryHeap;
 
@@ -49,10 +50,10 @@ fn shortest_path(
     Err(anyhow!("No path found"))
 }
 
-pub fn part_1(input: &str) -> Result<String> {
+pub fn part_1(input: &str) -> Result<AnswerValue> {
     let memory = parse_falling_memory(input)?;
     let path = shortest_path(&memory, 1024, (0, 0), (70, 70))?;
-    Ok(path.to_string())
+    Ok(path.into())
 }
 
 fn find_guilty_block(input: &str, start: (i32, i32), end: (i32, i32)) -> Result<(i32, i32)> {
@@ -75,9 +76,9 @@ fn find_guilty_block(input: &str, start: (i32, i32), end: (i32, i32)) -> Result<
     Ok(memory[bot - 1])
 }
 
-pub fn part_2(input: &str) -> Result<String> {
+pub fn part_2(input: &str) -> Result<AnswerValue> {
     let guilty_block = find_guilty_block(input, (0, 0), (70, 70))?;
-    Ok(format!("{},{}", guilty_block.0, guilty_block.1))
+    Ok(format!("{},{}", guilty_block.0, guilty_block.1).into())
 }
 
 #[cfg(test)]
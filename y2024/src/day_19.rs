@@ -1,11 +1,23 @@
 use anyhow::{Context, Result};
 use fxhash::FxHashMap;
+use shared::{AnswerValue, Input};
 
-fn parse(input: &str) -> Result<(Vec<&str>, Vec<&str>)> {
+/// The available towel patterns and the designs we're asked to make
+pub struct Onsen<'a> {
+    towels: Vec<&'a str>,
+    orders: Vec<&'a str>,
+}
+
+impl Input for Onsen<'static> {
+    type Of<'a> = Onsen<'a>;
+    type View<'a> = Onsen<'a>;
+}
+
+pub fn parse(input: &str) -> Result<Onsen<'_>> {
     let (towels, orders) = input.split_once("\n\n").context("No orders")?;
     let towels = towels.split(", ").collect();
     let orders = orders.lines().filter(|line| !line.is_empty()).collect();
-    Ok((towels, orders))
+    Ok(Onsen { towels, orders })
 }
 
 fn can_make<'a>(
@@ -26,14 +38,14 @@ fn can_make<'a>(
     }
 }
 
-pub fn part_1(input: &str) -> Result<String> {
-    let (towels, orders) = parse(input)?;
+pub fn part_1(onsen: &Onsen) -> Result<AnswerValue> {
     let mut cache = FxHashMap::default();
-    let p1 = orders
+    let p1 = onsen
+        .orders
         .iter()
-        .filter(|order| can_make(order, &towels, &mut cache))
+        .filter(|order| can_make(order, &onsen.towels, &mut cache))
         .count();
-    Ok(format!("{p1}"))
+    Ok(p1.into())
 }
 
 fn possible_designs<'a>(
@@ -56,14 +68,14 @@ fn possible_designs<'a>(
     }
 }
 
-pub fn part_2(input: &str) -> Result<String> {
-    let (towels, orders) = parse(input)?;
+pub fn part_2(onsen: &Onsen) -> Result<AnswerValue> {
     let mut cache = FxHashMap::default();
-    let p2: usize = orders
+    let p2: usize = onsen
+        .orders
         .iter()
-        .map(|order| possible_designs(order, &towels, &mut cache))
+        .map(|order| possible_designs(order, &onsen.towels, &mut cache))
         .sum();
-    Ok(format!("{p2}"))
+    Ok(p2.into())
 }
 
 #[cfg(test)]
@@ -83,10 +95,13 @@ bbrgwb";
 
     #[test]
     fn test_parse() {
-        let (towels, orders) = parse(EXAMPLE).unwrap();
-        assert_eq!(towels, vec!["r", "wr", "b", "g", "bwu", "rb", "gb", "br"]);
+        let onsen = parse(EXAMPLE).unwrap();
+        assert_eq!(
+            onsen.towels,
+            vec!["r", "wr", "b", "g", "bwu", "rb", "gb", "br"]
+        );
         assert_eq!(
-            orders,
+            onsen.orders,
             vec!["brwrr", "bggr", "gbbr", "rrbgbr", "ubwu", "bwurrg", "brgr", "bbrgwb"]
         );
     }
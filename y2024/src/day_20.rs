@@ -1,5 +1,6 @@
 use anyhow::Context;
 use fxhash::FxHashSet;
+use shared::AnswerValue;
 use std::collections::VecDeque;
 
 type Pos = (i32, i32);
@@ -133,12 +134,12 @@ fn count_cheats_of_size(
     ))
 }
 
-pub fn part_1(input: &str) -> anyhow::Result<String> {
+pub fn part_1(input: &str) -> anyhow::Result<AnswerValue> {
     let great_cheats = count_cheats_of_size(input, 2, 100)?;
-    Ok(format!("{}", great_cheats))
+    Ok(great_cheats.into())
 }
 
-pub fn part_2(input: &str) -> anyhow::Result<String> {
+pub fn part_2(input: &str) -> anyhow::Result<AnswerValue> {
     let great_cheats = count_cheats_of_size(input, 20, 100)?;
-    Ok(format!("{}", great_cheats))
+    Ok(great_cheats.into())
 }
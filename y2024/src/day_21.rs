@@ -1,5 +1,6 @@
 use fxhash::FxHashMap;
 use itertools::Itertools;
+use shared::AnswerValue;
 use std::collections::VecDeque;
 use std::iter::once;
 
@@ -148,12 +149,12 @@ fn calc(inp: &str, depth: usize) -> anyhow::Result<usize> {
         .try_fold(0, |a, b| Ok(a + b?))
 }
 
-pub fn part_1(inp: &str) -> anyhow::Result<String> {
-    Ok(format!("{}", calc(inp, 2)?))
+pub fn part_1(inp: &str) -> anyhow::Result<AnswerValue> {
+    Ok(calc(inp, 2)?.into())
 }
 
-pub fn part_2(inp: &str) -> anyhow::Result<String> {
-    Ok(format!("{}", calc(inp, 25)?))
+pub fn part_2(inp: &str) -> anyhow::Result<AnswerValue> {
+    Ok(calc(inp, 25)?.into())
 }
 
 #[cfg(test)]
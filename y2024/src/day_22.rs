@@ -1,4 +1,5 @@
 use bit_set::BitSet;
+use shared::AnswerValue;
 use std::sync::Mutex;
 use std::thread;
 
@@ -17,12 +18,12 @@ fn gen_secrets(secrets: &mut [u64], rounds: usize) {
     }
 }
 
-pub fn part_1(inp: &str) -> anyhow::Result<String> {
+pub fn part_1(inp: &str) -> anyhow::Result<AnswerValue> {
     let secrets: anyhow::Result<Vec<u64>> = inp.lines().map(|n| Ok(n.parse::<u64>()?)).collect();
     let mut secrets = secrets?;
     gen_secrets(&mut secrets, 2000);
     let n: u64 = secrets.into_iter().sum();
-    Ok(format!("{n}"))
+    Ok(n.into())
 }
 
 fn monkey_business(monkeys: &[i32], counter: &Mutex<Vec<i32>>) {
@@ -71,9 +72,9 @@ fn maximize_bananas(monkeys: &[i32]) -> i32 {
     *inner.iter().max().unwrap()
 }
 
-pub fn part_2(inp: &str) -> anyhow::Result<String> {
+pub fn part_2(inp: &str) -> anyhow::Result<AnswerValue> {
     let monkeys: anyhow::Result<Vec<i32>> = inp.lines().map(|n| Ok(n.parse::<i32>()?)).collect();
-    Ok(format!("{}", maximize_bananas(&(monkeys?))))
+    Ok(maximize_bananas(&(monkeys?)).into())
 }
 
 #[cfg(test)]
@@ -1,15 +1,24 @@
 use anyhow::Context;
 use fxhash::{FxHashMap, FxHashSet};
 use itertools::Itertools;
+use shared::{AnswerValue, Input};
 
-fn parse(inp: &str) -> anyhow::Result<FxHashMap<&str, FxHashSet<&str>>> {
+/// Maps each computer to the computers it is connected to
+pub struct Network<'a>(FxHashMap<&'a str, FxHashSet<&'a str>>);
+
+impl Input for Network<'static> {
+    type Of<'a> = Network<'a>;
+    type View<'a> = Network<'a>;
+}
+
+pub fn parse(inp: &str) -> anyhow::Result<Network<'_>> {
     let mut m: FxHashMap<_, FxHashSet<_>> = FxHashMap::default();
     for l in inp.lines() {
         let (a, b) = l.split_once('-').context("Bad input")?;
         m.entry(a).or_default().insert(b);
         m.entry(b).or_default().insert(a);
     }
-    Ok(m)
+    Ok(Network(m))
 }
 
 fn max_clique<'a>(
@@ -40,8 +49,7 @@ fn max_clique<'a>(
     }
 }
 
-pub fn part_1(inp: &str) -> anyhow::Result<String> {
-    let graph = parse(inp)?;
+pub fn part_1(Network(graph): &Network) -> anyhow::Result<AnswerValue> {
     let mut found = FxHashSet::default();
     for (&k, v) in graph.iter() {
         if k.starts_with("t") {
@@ -53,12 +61,11 @@ pub fn part_1(inp: &str) -> anyhow::Result<String> {
             }
         }
     }
-    Ok(format!("{}", found.len()))
+    Ok(found.len().into())
 }
 
-pub fn part_2(inp: &str) -> anyhow::Result<String> {
-    let graph = parse(inp)?;
-    let clique = max_clique(&graph.keys().copied().collect::<FxHashSet<_>>(), &graph);
+pub fn part_2(Network(graph): &Network) -> anyhow::Result<AnswerValue> {
+    let clique = max_clique(&graph.keys().copied().collect::<FxHashSet<_>>(), graph);
     let s = clique.into_iter().sorted().join(",");
-    Ok(s)
+    Ok(s.into())
 }
@@ -8,6 +8,7 @@ use nom::combinator::{map, recognize};
 use nom::multi::{many1, separated_list1};
 use nom::sequence::{separated_pair, terminated};
 use nom::IResult;
+use shared::{AnswerValue, Input};
 use std::collections::VecDeque;
 use BinaryOp::*;
 
@@ -82,14 +83,28 @@ fn parse_calculated_value(s: &str) -> IResult<&str, CalculatedValue<'_>> {
     ))
 }
 
-fn parse(s: &str) -> Result<(Vec<ProvidedValue<'_>>, Vec<CalculatedValue<'_>>)> {
-    let (_, r) = separated_pair(
+/// The initial wire values and the gates of the device
+pub struct Device<'a> {
+    provided: Vec<ProvidedValue<'a>>,
+    calculated: Vec<CalculatedValue<'a>>,
+}
+
+impl Input for Device<'static> {
+    type Of<'a> = Device<'a>;
+    type View<'a> = Device<'a>;
+}
+
+pub fn parse(s: &str) -> Result<Device<'_>> {
+    let (_, (provided, calculated)) = separated_pair(
         separated_list1(tag("\n"), parse_provided_value),
         tag("\n\n"),
         separated_list1(tag("\n"), parse_calculated_value),
     )(s)
     .map_err(|err| anyhow!("{err}"))?;
-    Ok(r)
+    Ok(Device {
+        provided,
+        calculated,
+    })
 }
 
 fn dependencies<'a>(
@@ -138,24 +153,23 @@ fn calculate<'a>(
     z
 }
 
-pub fn part_1(s: &str) -> Result<String> {
-    let (provided, calculated) = parse(s)?;
-    check_assumptions(&provided);
-    let z = calculate(&provided, &calculated);
+pub fn part_1(device: &Device) -> Result<AnswerValue> {
+    check_assumptions(&device.provided);
+    let z = calculate(&device.provided, &device.calculated);
     let z: usize = z
         .into_iter()
         .map(|(ix, v)| if v { 1 << ix } else { 0 })
         .sum();
 
-    Ok(z.to_string())
+    Ok(z.into())
 }
 
 fn check_assumptions(provided: &[ProvidedValue]) {
     assert!(provided.iter().all(|p| p.reg == 'y' || p.reg == 'x'));
 }
 
-pub fn part_2(s: &str) -> Result<String> {
-    let (_, calculated) = parse(s)?;
+pub fn part_2(device: &Device) -> Result<AnswerValue> {
+    let calculated = &device.calculated;
     // Half adder on LSB
     // takes x and y and outputs sum and carry
     // made with sum = x ^ y and carry x & y
@@ -169,7 +183,7 @@ pub fn part_2(s: &str) -> Result<String> {
 
     let in_out = |s: &str| s.starts_with("x") || s.starts_with("y") || s.starts_with("z");
 
-    for c in &calculated {
+    for c in calculated {
         // z should be all XOR except the highest one
         if c.z_index.is_some() && c.z_index != Some(45) && c.op != Xor {
             sus.push(c.wire);
@@ -199,7 +213,7 @@ pub fn part_2(s: &str) -> Result<String> {
     }
 
     sus.sort();
-    Ok(sus.into_iter().unique().join(","))
+    Ok(sus.into_iter().unique().join(",").into())
 }
 
 #[cfg(test)]
@@ -257,8 +271,8 @@ tnw OR pbm -> gnj
 
     #[test]
     fn test_calc() -> Result<()> {
-        let (prov, calc) = parse(EX)?;
-        let z = calculate(&prov, &calc);
+        let device = parse(EX)?;
+        let z = calculate(&device.provided, &device.calculated);
         let z: usize = z
             .into_iter()
             .map(|(ix, v)| if v { 1 << ix } else { 0 })
@@ -1,7 +1,8 @@
 use fxhash::FxHashSet;
 use itertools::Itertools;
+use shared::AnswerValue;
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
+pub fn part_1(s: &str) -> anyhow::Result<AnswerValue> {
     let locks_keys = s
         .split("\n\n")
         .map(|l| {
@@ -28,5 +29,5 @@ pub fn part_1(s: &str) -> anyhow::Result<String> {
         .cartesian_product(keys.iter())
         .filter(|(l, k)| l.intersection(k).count() == 0)
         .count();
-    Ok(n.to_string())
+    Ok(n.into())
 }
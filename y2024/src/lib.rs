@@ -1,7 +1,6 @@
 extern crate core;
 
-use shared::{Answer, Answer::SolvedBoth};
-use std::string::ToString;
+use shared::{Day, List, Owned, Parsed, Solution};
 
 mod day_01;
 mod day_02;
@@ -29,105 +28,76 @@ mod day_23;
 mod day_24;
 mod day_25;
 
-pub const SOLUTIONS: [Answer; 25] = [
-    SolvedBoth {
-        part_1: day_01::part_1,
-        part_2: day_01::part_2,
-    },
-    SolvedBoth {
-        part_1: day_02::part_1,
-        part_2: day_02::part_2,
-    },
-    SolvedBoth {
-        part_1: day_03::part_1,
-        part_2: day_03::part_2,
-    },
-    SolvedBoth {
-        part_1: day_04::part_1,
-        part_2: day_04::part_2,
-    },
-    SolvedBoth {
-        part_1: day_05::part_1,
-        part_2: day_05::part_2,
-    },
-    SolvedBoth {
-        part_1: day_06::part_1,
-        part_2: day_06::part_2,
-    },
-    SolvedBoth {
-        part_1: day_07::part_1,
-        part_2: day_07::part_2,
-    },
-    SolvedBoth {
-        part_1: day_08::part_1,
-        part_2: day_08::part_2,
-    },
-    SolvedBoth {
-        part_1: day_09::part_1,
-        part_2: day_09::part_2,
-    },
-    SolvedBoth {
-        part_1: day_10::part_1,
-        part_2: day_10::part_2,
-    },
-    SolvedBoth {
-        part_1: day_11::part_1,
-        part_2: day_11::part_2,
-    },
-    SolvedBoth {
-        part_1: day_12::part_1,
-        part_2: day_12::part_2,
-    },
-    SolvedBoth {
-        part_1: day_13::part_1,
-        part_2: day_13::part_2,
-    },
-    SolvedBoth {
-        part_1: day_14::part_1,
-        part_2: day_14::part_2,
-    },
-    SolvedBoth {
-        part_1: day_15::part_1,
-        part_2: day_15::part_2,
-    },
-    SolvedBoth {
-        part_1: day_16::part_1,
-        part_2: day_16::part_2,
-    },
-    SolvedBoth {
-        part_1: day_17::part_1,
-        part_2: day_17::part_2,
-    },
-    SolvedBoth {
-        part_1: day_18::part_1,
-        part_2: day_18::part_2,
-    },
-    SolvedBoth {
-        part_1: day_19::part_1,
-        part_2: day_19::part_2,
-    },
-    SolvedBoth {
-        part_1: day_20::part_1,
-        part_2: day_20::part_2,
-    },
-    SolvedBoth {
-        part_1: day_21::part_1,
-        part_2: day_21::part_2,
-    },
-    SolvedBoth {
-        part_1: day_22::part_1,
-        part_2: day_22::part_2,
-    },
-    SolvedBoth {
-        part_1: day_23::part_1,
-        part_2: day_23::part_2,
-    },
-    SolvedBoth {
-        part_1: day_24::part_1,
-        part_2: day_24::part_2,
-    },
-    SolvedBoth {
-        part_1: day_25::part_1,
-        part_2: |_: &str| Ok("Collect stars".to_string()),
-    },
+const YEAR: u16 = 2024;
+
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &Parsed::<Owned<(Vec<i32>, Vec<i32>)>>::both(
+        YEAR,
+        1,
+        day_01::parse_lists,
+        day_01::part_1,
+        day_01::part_2,
+    )
+    .titled("Historian Hysteria"),
+    &Parsed::<List<Vec<i32>>>::both(YEAR, 2, day_02::parse, day_02::part_1, day_02::part_2)
+        .titled("Red-Nosed Reports"),
+    &Day::both(YEAR, 3, day_03::part_1, day_03::part_2).titled("Mull It Over"),
+    &Parsed::<List<Vec<u8>>>::both(YEAR, 4, day_04::parse_grid, day_04::part_1, day_04::part_2)
+        .titled("Ceres Search"),
+    &Day::both(YEAR, 5, day_05::part_1, day_05::part_2).titled("Print Queue"),
+    &Day::both(YEAR, 6, day_06::part_1, day_06::part_2).titled("Guard Gallivant"),
+    &Parsed::<List<day_07::Equation>>::both(
+        YEAR,
+        7,
+        day_07::parse_equations,
+        day_07::part_1,
+        day_07::part_2,
+    )
+    .titled("Bridge Repair"),
+    &Day::both(YEAR, 8, day_08::part_1, day_08::part_2).titled("Resonant Collinearity"),
+    &Day::both(YEAR, 9, day_09::part_1, day_09::part_2).titled("Disk Fragmenter"),
+    &Parsed::<Owned<shared::Grid<u8>>>::both(
+        YEAR,
+        10,
+        day_10::parse,
+        day_10::part_1,
+        day_10::part_2,
+    )
+    .titled("Hoof It"),
+    &Day::both(YEAR, 11, day_11::part_1, day_11::part_2).titled("Plutonian Pebbles"),
+    &Day::both(YEAR, 12, day_12::part_1, day_12::part_2).titled("Garden Groups"),
+    &Day::both(YEAR, 13, day_13::part_1, day_13::part_2).titled("Claw Contraption"),
+    &Parsed::<List<day_14::Bot>>::both(YEAR, 14, day_14::parse, day_14::part_1, day_14::part_2)
+        .titled("Restroom Redoubt"),
+    &Parsed::<Owned<day_15::Problem>>::both(
+        YEAR,
+        15,
+        day_15::parse,
+        day_15::part_1,
+        day_15::part_2,
+    )
+    .titled("Warehouse Woes"),
+    &Parsed::<Owned<day_16::Problem>>::both(
+        YEAR,
+        16,
+        day_16::parse,
+        day_16::part_1,
+        day_16::part_2,
+    )
+    .titled("Reindeer Maze"),
+    &Day::both(YEAR, 17, day_17::part_1, day_17::part_2).titled("Chronospatial Computer"),
+    &Day::both(YEAR, 18, day_18::part_1, day_18::part_2).titled("RAM Run"),
+    &Parsed::<day_19::Onsen>::both(YEAR, 19, day_19::parse, day_19::part_1, day_19::part_2)
+        .titled("Linen Layout"),
+    &Day::both(YEAR, 20, day_20::part_1, day_20::part_2).titled("Race Condition"),
+    &Day::both(YEAR, 21, day_21::part_1, day_21::part_2).titled("Keypad Conundrum"),
+    &Day::both(YEAR, 22, day_22::part_1, day_22::part_2).titled("Monkey Market"),
+    &Parsed::<day_23::Network>::both(YEAR, 23, day_23::parse, day_23::part_1, day_23::part_2)
+        .titled("LAN Party"),
+    &Parsed::<day_24::Device>::both(YEAR, 24, day_24::parse, day_24::part_1, day_24::part_2)
+        .titled("Crossed Wires"),
+    &Day::both(YEAR, 25, day_25::part_1, |_: &str| {
+        Ok("Collect stars".into())
+    })
+    .titled("Code Chronicle"),
 ];
@@ -1,3 +1,5 @@
+use shared::AnswerValue;
+
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 enum Dir {
     L,
@@ -50,8 +52,8 @@ fn count_zeros(input: &str) -> usize {
         .count()
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
-    Ok(format!("{}", count_zeros(s)))
+pub fn part_1(s: &str) -> anyhow::Result<AnswerValue> {
+    Ok(count_zeros(s).into())
 }
 
 fn count_passing_zeros(input: &str) -> usize {
@@ -72,8 +74,8 @@ fn count_passing_zeros(input: &str) -> usize {
     .count()
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
-    Ok(format!("{}", count_passing_zeros(s)))
+pub fn part_2(s: &str) -> anyhow::Result<AnswerValue> {
+    Ok(count_passing_zeros(s).into())
 }
 
 #[cfg(test)]
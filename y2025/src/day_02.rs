@@ -1,3 +1,5 @@
+use shared::AnswerValue;
+
 struct IdRange {
     start: u64,
     end: u64,
@@ -66,8 +68,8 @@ fn sum_invalid_ids(s: &str) -> u64 {
     ids(&ranges).filter(|id| repeated_digits_twice(*id)).sum()
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
-    Ok(format!("{}", sum_invalid_ids(s)))
+pub fn part_1(s: &str) -> anyhow::Result<AnswerValue> {
+    Ok(sum_invalid_ids(s).into())
 }
 
 fn sum_invalid_p2_ids(s: &str) -> u64 {
@@ -75,8 +77,8 @@ fn sum_invalid_p2_ids(s: &str) -> u64 {
     ids(&ranges).filter(|id| repeated_at_least_twice(*id)).sum()
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
-    Ok(format!("{}", sum_invalid_p2_ids(s)))
+pub fn part_2(s: &str) -> anyhow::Result<AnswerValue> {
+    Ok(sum_invalid_p2_ids(s).into())
 }
 
 #[cfg(test)]
@@ -1,3 +1,4 @@
+use shared::AnswerValue;
 use std::cmp::Reverse;
 
 // Obviously correct, but also very slow for 12 digits because it tries every possible combination
@@ -35,12 +36,12 @@ fn total_joltage(s: &str, digits: usize) -> u128 {
         .sum()
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
-    Ok(format!("{}", total_joltage(s, 2)))
+pub fn part_1(s: &str) -> anyhow::Result<AnswerValue> {
+    Ok(total_joltage(s, 2).into())
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
-    Ok(format!("{}", total_joltage(s, 12)))
+pub fn part_2(s: &str) -> anyhow::Result<AnswerValue> {
+    Ok(total_joltage(s, 12).into())
 }
 
 #[cfg(test)]
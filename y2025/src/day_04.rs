@@ -1,4 +1,5 @@
 use fxhash::FxHashSet;
+use shared::AnswerValue;
 
 fn parse(s: &str) -> FxHashSet<(i32, i32)> {
     s.lines()
@@ -45,8 +46,8 @@ fn movable_rolls(s: &str) -> usize {
     can_be_removed(&map).count()
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
-    Ok(format!("{}", movable_rolls(s)))
+pub fn part_1(s: &str) -> anyhow::Result<AnswerValue> {
+    Ok(movable_rolls(s).into())
 }
 
 fn total_removable_rolls(s: &str) -> usize {
@@ -62,8 +63,8 @@ fn total_removable_rolls(s: &str) -> usize {
     start - map.len()
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
-    Ok(format!("{}", total_removable_rolls(s)))
+pub fn part_2(s: &str) -> anyhow::Result<AnswerValue> {
+    Ok(total_removable_rolls(s).into())
 }
 
 #[cfg(test)]
@@ -1,14 +1,15 @@
 use anyhow::Context;
 use itertools::Itertools;
+use shared::AnswerValue;
 
 #[derive(Debug)]
-struct ElfInventorySystem {
+pub struct ElfInventorySystem {
     fresh_ranges: Vec<Range>,
     available_ids: Vec<u64>,
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug, Ord, PartialOrd)]
-struct Range(u64, u64);
+pub struct Range(u64, u64);
 
 impl Range {
     fn contains(&self, x: &u64) -> bool {
@@ -25,7 +26,7 @@ impl Range {
     }
 }
 
-fn parse(s: &str) -> anyhow::Result<ElfInventorySystem> {
+pub fn parse(s: &str) -> anyhow::Result<ElfInventorySystem> {
     let (ranges, ids) = s.split_once("\n\n").context("Invalid input")?;
     let fresh_ranges: Result<Vec<_>, anyhow::Error> = ranges
         .lines()
@@ -56,9 +57,8 @@ fn count_fresh_ids(input: &ElfInventorySystem) -> usize {
         .count()
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
-    let inventory = parse(s)?;
-    Ok(format!("{}", count_fresh_ids(&inventory)))
+pub fn part_1(inventory: &ElfInventorySystem) -> anyhow::Result<AnswerValue> {
+    Ok(count_fresh_ids(inventory).into())
 }
 
 fn merge_adjacent_ranges(ranges: &[Range]) -> Vec<Range> {
@@ -82,11 +82,10 @@ fn merge_adjacent_ranges(ranges: &[Range]) -> Vec<Range> {
     out
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
-    let inventory = parse(s)?;
+pub fn part_2(inventory: &ElfInventorySystem) -> anyhow::Result<AnswerValue> {
     let ranges = merge_adjacent_ranges(&inventory.fresh_ranges);
     let fresh: u64 = ranges.into_iter().map(|range| range.len()).sum();
-    Ok(format!("{fresh}"))
+    Ok(fresh.into())
 }
 
 #[cfg(test)]
@@ -1,4 +1,5 @@
 use itertools::Itertools;
+use shared::AnswerValue;
 
 // No points for thinking ahead and parsing the problem today
 struct Input {
@@ -56,11 +57,11 @@ fn grand_total(s: &str) -> i64 {
     calc(&input).into_iter().sum()
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
-    Ok(format!("{}", grand_total(s)))
+pub fn part_1(s: &str) -> anyhow::Result<AnswerValue> {
+    Ok(grand_total(s).into())
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
+pub fn part_2(s: &str) -> anyhow::Result<AnswerValue> {
     let m: Vec<_> = s
         .lines()
         .filter(|line| !line.is_empty())
@@ -114,7 +115,7 @@ pub fn part_2(s: &str) -> anyhow::Result<String> {
         total += n;
     }
 
-    Ok(total.to_string())
+    Ok(total.into())
 }
 
 #[cfg(test)]
@@ -128,6 +129,6 @@ mod tests {
     }
     #[test]
     fn test_ex_p2() {
-        assert_eq!(part_2(EX).unwrap().as_str(), "3263827");
+        assert_eq!(part_2(EX).unwrap(), AnswerValue::Integer(3263827));
     }
 }
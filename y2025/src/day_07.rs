@@ -1,14 +1,16 @@
+use anyhow::Context;
 use fxhash::FxHashSet;
+use shared::AnswerValue;
 
-struct State {
+pub struct State {
     splitters: FxHashSet<(i32, i32)>,
     source: (i32, i32),
     height: i32,
     width: usize,
 }
 
-fn parse(s: &str) -> State {
-    let width = s.lines().next().expect("Empty input").len();
+pub fn parse(s: &str) -> anyhow::Result<State> {
+    let width = s.lines().next().context("Empty input")?.len();
 
     let with_coordinates = s
         .lines()
@@ -24,7 +26,7 @@ fn parse(s: &str) -> State {
         .clone()
         .find(|(_, _, ch)| *ch == b'S')
         .map(|(x, y, _)| (x as i32, y as i32))
-        .expect("Must have a source");
+        .context("Must have a source")?;
 
     let splitters = with_coordinates
         .filter(|(_, _, ch)| *ch == b'^')
@@ -33,12 +35,12 @@ fn parse(s: &str) -> State {
 
     let height = s.lines().count() as i32;
 
-    State {
+    Ok(State {
         splitters,
         source,
         height,
         width,
-    }
+    })
 }
 
 fn split_beams(state: &State) -> (usize, usize) {
@@ -71,16 +73,14 @@ fn split_beams(state: &State) -> (usize, usize) {
     (splitters_hit, beams.into_iter().sum())
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
-    let state = parse(s);
-    let (splitters_hit, _) = split_beams(&state);
-    Ok(format!("{splitters_hit}"))
+pub fn part_1(state: &State) -> anyhow::Result<AnswerValue> {
+    let (splitters_hit, _) = split_beams(state);
+    Ok(splitters_hit.into())
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
-    let state = parse(s);
-    let (_, timelines) = split_beams(&state);
-    Ok(format!("{timelines}"))
+pub fn part_2(state: &State) -> anyhow::Result<AnswerValue> {
+    let (_, timelines) = split_beams(state);
+    Ok(timelines.into())
 }
 
 #[cfg(test)]
@@ -105,13 +105,13 @@ mod tests {
 ";
     #[test]
     fn test_parse() {
-        let state = parse(EX);
+        let state = parse(EX).unwrap();
         assert_eq!(state.splitters.len(), 22);
     }
 
     #[test]
     fn test_split_beams() {
-        let state = parse(EX);
+        let state = parse(EX).unwrap();
         let (hit, timelines) = split_beams(&state);
         assert_eq!(hit, 21);
         assert_eq!(timelines, 40);
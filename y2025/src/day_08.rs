@@ -1,20 +1,17 @@
 use fxhash::FxHashSet;
 use itertools::Itertools;
+use shared::AnswerValue;
 use std::cmp::Reverse;
 
-type Vertex = (i64, i64, i64);
+pub type Vertex = (i64, i64, i64);
 
-fn parse(s: &str) -> Vec<Vertex> {
+pub fn parse(s: &str) -> anyhow::Result<Vec<Vertex>> {
     s.lines()
         .map(|l| l.trim())
         .filter(|l| !l.is_empty())
         .map(|line| {
             let v: Vec<_> = line.splitn(3, ',').collect();
-            (
-                v[0].parse().unwrap(),
-                v[1].parse().unwrap(),
-                v[2].parse().unwrap(),
-            )
+            Ok((v[0].parse()?, v[1].parse()?, v[2].parse()?))
         })
         .collect()
 }
@@ -85,19 +82,17 @@ fn connect_circuits(
     (len_product(&circuits), (0, 0))
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
-    let v = parse(s);
-    let d = distances(&v);
+pub fn part_1(v: &[Vertex]) -> anyhow::Result<AnswerValue> {
+    let d = distances(v);
     let (prod, _) = connect_circuits(v.len(), &d[..1000]);
-    Ok(format!("{prod}"))
+    Ok(prod.into())
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
-    let v = parse(s);
-    let d = distances(&v);
+pub fn part_2(v: &[Vertex]) -> anyhow::Result<AnswerValue> {
+    let d = distances(v);
     let (_, (src, dst)) = connect_circuits(v.len(), &d);
     let prod = v[src].0 * v[dst].0;
-    Ok(format!("{prod}"))
+    Ok(prod.into())
 }
 
 #[cfg(test)]
@@ -128,14 +123,14 @@ mod tests {
 
     #[test]
     fn test_p1() {
-        let v = parse(EX);
+        let v = parse(EX).unwrap();
         let d = distances(&v);
         assert_eq!(40, connect_circuits(v.len(), &d[..10]).0);
     }
 
     #[test]
     fn test_p2() {
-        let v = parse(EX);
+        let v = parse(EX).unwrap();
         let d = distances(&v);
         let (_, (src, dst)) = connect_circuits(v.len(), &d);
         let prod = v[src].0 * v[dst].0;
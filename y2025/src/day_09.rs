@@ -1,4 +1,5 @@
 use itertools::Itertools;
+use shared::AnswerValue;
 use std::cmp::Reverse;
 
 type Tile = (i64, i64);
@@ -27,8 +28,8 @@ fn biggest_area(s: &str) -> i64 {
     rects.into_iter().max().unwrap().0
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
-    Ok(format!("{}", biggest_area(s)))
+pub fn part_1(s: &str) -> anyhow::Result<AnswerValue> {
+    Ok(biggest_area(s).into())
 }
 
 fn rectangles(poly: &[Tile]) -> Vec<(i64, Tile, Tile)> {
@@ -38,7 +39,7 @@ fn rectangles(poly: &[Tile]) -> Vec<(i64, Tile, Tile)> {
         .collect()
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
+pub fn part_2(s: &str) -> anyhow::Result<AnswerValue> {
     let poly = parse(s);
     let mut edges = vec![];
     for (i, (ax, ay)) in poly.iter().copied().enumerate() {
@@ -56,7 +57,7 @@ pub fn part_2(s: &str) -> anyhow::Result<String> {
             .iter()
             .any(|((cx, cy), (dx, dy))| *dx > ax && *cx < bx && *dy > ay && *cy < by)
         {
-            return Ok(format!("{size}"));
+            return Ok(size.into());
         }
     }
 
@@ -8,10 +8,11 @@ use nom::multi::{many1, separated_list1};
 use nom::sequence::delimited;
 use nom::IResult;
 use rayon::prelude::*;
+use shared::AnswerValue;
 use std::collections::VecDeque;
 
 #[derive(Debug, Clone, Eq, PartialEq)]
-struct Machine {
+pub struct Machine {
     // bitmask
     indicator_lights: u16,
     // bitmasks
@@ -66,7 +67,7 @@ fn parse_machine(s: &str) -> IResult<&str, Machine> {
     ))
 }
 
-fn parse(s: &str) -> anyhow::Result<Vec<Machine>> {
+pub fn parse(s: &str) -> anyhow::Result<Vec<Machine>> {
     separated_list1(tag("\n"), parse_machine)(s)
         .map_err(|err| anyhow!("{err}"))
         .map(|(_, m)| m)
@@ -90,10 +91,9 @@ fn configure_machine(m: &Machine) -> usize {
     unreachable!()
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
-    let machines = parse(s)?;
-    let presses: usize = machines.into_iter().map(|m| configure_machine(&m)).sum();
-    Ok(format!("{presses}"))
+pub fn part_1(machines: &[Machine]) -> anyhow::Result<AnswerValue> {
+    let presses: usize = machines.iter().map(configure_machine).sum();
+    Ok(presses.into())
 }
 
 fn configure_machine_joltage(m: &Machine) -> usize {
@@ -129,13 +129,9 @@ fn configure_machine_joltage(m: &Machine) -> usize {
     solution.columns().iter().map(|n| n.round() as usize).sum()
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
-    let machines = parse(s)?;
-    let sum: usize = machines
-        .into_par_iter()
-        .map(|m| configure_machine_joltage(&m))
-        .sum();
-    Ok(format!("{sum}"))
+pub fn part_2(machines: &[Machine]) -> anyhow::Result<AnswerValue> {
+    let sum: usize = machines.par_iter().map(configure_machine_joltage).sum();
+    Ok(sum.into())
 }
 
 #[cfg(test)]
@@ -1,14 +1,24 @@
+use anyhow::Context;
 use fxhash::FxHashMap;
+use shared::{AnswerValue, Input};
 
-fn parse(s: &str) -> FxHashMap<&str, Vec<&str>> {
+/// Maps each device to the devices its outputs are connected to
+pub struct Devices<'a>(FxHashMap<&'a str, Vec<&'a str>>);
+
+impl Input for Devices<'static> {
+    type Of<'a> = Devices<'a>;
+    type View<'a> = Devices<'a>;
+}
+
+pub fn parse(s: &str) -> anyhow::Result<Devices<'_>> {
     let mut out: FxHashMap<_, Vec<_>> = FxHashMap::default();
     for l in s.lines().filter(|l| !l.is_empty()) {
-        let (key, rest) = l.split_once(": ").unwrap();
+        let (key, rest) = l.split_once(": ").context("Missing colon")?;
         for next in rest.split(' ') {
             out.entry(key).or_default().push(next);
         }
     }
-    out
+    Ok(Devices(out))
 }
 
 fn count_paths<'a>(
@@ -31,21 +41,19 @@ fn count_paths<'a>(
     }
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
-    let g = parse(s);
-    let paths = count_paths(&g, "you", "out", &mut FxHashMap::default());
-    Ok(format!("{paths}"))
+pub fn part_1(Devices(g): &Devices) -> anyhow::Result<AnswerValue> {
+    let paths = count_paths(g, "you", "out", &mut FxHashMap::default());
+    Ok(paths.into())
 }
 
-pub fn part_2(s: &str) -> anyhow::Result<String> {
-    let g = parse(s);
+pub fn part_2(Devices(g): &Devices) -> anyhow::Result<AnswerValue> {
     // This was initially i32 which cost a lot of time since the answer doesn't fit in it.
     // When will I learn?
-    let to_fft = count_paths(&g, "svr", "fft", &mut FxHashMap::default());
-    let to_dac = count_paths(&g, "fft", "dac", &mut FxHashMap::default());
-    let to_out = count_paths(&g, "dac", "out", &mut FxHashMap::default());
+    let to_fft = count_paths(g, "svr", "fft", &mut FxHashMap::default());
+    let to_dac = count_paths(g, "fft", "dac", &mut FxHashMap::default());
+    let to_out = count_paths(g, "dac", "out", &mut FxHashMap::default());
     let total = to_fft * to_dac * to_out;
-    Ok(format!("{total}"))
+    Ok(total.into())
 }
 
 #[cfg(test)]
@@ -65,7 +73,7 @@ iii: out
 
     #[test]
     fn test_count() {
-        let g = parse(EX);
+        let Devices(g) = parse(EX).unwrap();
         let mut cache = FxHashMap::default();
         let r = count_paths(&g, "you", "out", &mut cache);
         assert_eq!(5, r);
@@ -1,3 +1,5 @@
+use shared::AnswerValue;
+
 struct Region {
     height: usize,
     width: usize,
@@ -29,8 +31,8 @@ fn boxes_fit(region: &Region) -> bool {
     region.boxes <= vertical_boxes * horizontal_boxes
 }
 
-pub fn part_1(s: &str) -> anyhow::Result<String> {
+pub fn part_1(s: &str) -> anyhow::Result<AnswerValue> {
     let regions = parse(s);
     let fits = regions.iter().filter(|r| boxes_fit(r)).count();
-    Ok(format!("{fits}"))
+    Ok(fits.into())
 }
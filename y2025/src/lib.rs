@@ -1,4 +1,4 @@
-use shared::Answer;
+use shared::{Day, List, Owned, Parsed, Solution};
 
 mod day_01;
 mod day_02;
@@ -13,52 +13,30 @@ mod day_10;
 mod day_11;
 mod day_12;
 
-pub const SOLUTIONS: &[Answer] = &[
-    Answer::SolvedBoth {
-        part_1: day_01::part_1,
-        part_2: day_01::part_2,
-    },
-    Answer::SolvedBoth {
-        part_1: day_02::part_1,
-        part_2: day_02::part_2,
-    },
-    Answer::SolvedBoth {
-        part_1: day_03::part_1,
-        part_2: day_03::part_2,
-    },
-    Answer::SolvedBoth {
-        part_1: day_04::part_1,
-        part_2: day_04::part_2,
-    },
-    Answer::SolvedBoth {
-        part_1: day_05::part_1,
-        part_2: day_05::part_2,
-    },
-    Answer::SolvedBoth {
-        part_1: day_06::part_1,
-        part_2: day_06::part_2,
-    },
-    Answer::SolvedBoth {
-        part_1: day_07::part_1,
-        part_2: day_07::part_2,
-    },
-    Answer::SolvedBoth {
-        part_1: day_08::part_1,
-        part_2: day_08::part_2,
-    },
-    Answer::SolvedBoth {
-        part_1: day_09::part_1,
-        part_2: day_09::part_2,
-    },
-    Answer::SolvedBoth {
-        part_1: day_10::part_1,
-        part_2: day_10::part_2,
-    },
-    Answer::SolvedBoth {
-        part_1: day_11::part_1,
-        part_2: day_11::part_2,
-    },
-    Answer::SolvedOne {
-        part_1: day_12::part_1,
-    },
+const YEAR: u16 = 2025;
+
+pub const SOLUTIONS: &[&dyn Solution] = &[
+    &Day::both(YEAR, 1, day_01::part_1, day_01::part_2).titled("Secret Entrance"),
+    &Day::both(YEAR, 2, day_02::part_1, day_02::part_2).titled("Gift Shop"),
+    &Day::both(YEAR, 3, day_03::part_1, day_03::part_2).titled("Lobby"),
+    &Day::both(YEAR, 4, day_04::part_1, day_04::part_2).titled("Printing Department"),
+    &Parsed::<Owned<day_05::ElfInventorySystem>>::both(
+        YEAR,
+        5,
+        day_05::parse,
+        day_05::part_1,
+        day_05::part_2,
+    )
+    .titled("Cafeteria"),
+    &Day::both(YEAR, 6, day_06::part_1, day_06::part_2).titled("Trash Compactor"),
+    &Parsed::<Owned<day_07::State>>::both(YEAR, 7, day_07::parse, day_07::part_1, day_07::part_2)
+        .titled("Laboratories"),
+    &Parsed::<List<day_08::Vertex>>::both(YEAR, 8, day_08::parse, day_08::part_1, day_08::part_2)
+        .titled("Playground"),
+    &Day::both(YEAR, 9, day_09::part_1, day_09::part_2).titled("Movie Theater"),
+    &Parsed::<List<day_10::Machine>>::both(YEAR, 10, day_10::parse, day_10::part_1, day_10::part_2)
+        .titled("Factory"),
+    &Parsed::<day_11::Devices>::both(YEAR, 11, day_11::parse, day_11::part_1, day_11::part_2)
+        .titled("Reactor"),
+    &Day::one(YEAR, 12, day_12::part_1).titled("Christmas Tree Farm"),
 ];